@@ -0,0 +1,30 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static GLOBAL: AtomicU8 = AtomicU8::new(ErrorFormat::Laravel as u8);
+
+/// How validation errors are written in `422` responses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// Laravel's `{"message": ..., "errors": {"field": ["message"]}}`.
+    #[default]
+    Laravel = 0,
+    /// RFC 9457 `application/problem+json`, listing the field, rule,
+    /// parameters and message of each error so clients can localize them.
+    Problem = 1,
+}
+
+impl ErrorFormat {
+    /// The format of [`Response::json_validation_error`](crate::Response::json_validation_error).
+    pub fn global() -> Self {
+        match GLOBAL.load(Ordering::Relaxed) {
+            1 => Self::Problem,
+            _ => Self::Laravel,
+        }
+    }
+
+    /// Makes this the process-wide format. The server applies the format of
+    /// its [`ServerConfig`](super::ServerConfig) on start.
+    pub fn set_global(self) {
+        GLOBAL.store(self as u8, Ordering::Relaxed);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod error_format;
 pub mod server_config;
 
+pub use error_format::ErrorFormat;
 pub use server_config::ServerConfig;
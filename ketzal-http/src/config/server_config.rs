@@ -1,14 +1,26 @@
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
+use super::ErrorFormat;
+
 #[derive(Debug, Clone)]
 pub struct ServerConfig {
     pub host: IpAddr,
     pub port: u16,
+    /// Expect a PROXY protocol (v1 or v2) header on every connection.
+    /// Only enable behind a load balancer that always sends one.
+    pub proxy_protocol: bool,
+    /// Body of `422` responses to failed validation.
+    pub error_format: ErrorFormat,
 }
 
 impl Default for ServerConfig {
     fn default() -> Self {
-        Self { host: IpAddr::V4(Ipv4Addr::LOCALHOST), port: 5002 }
+        Self {
+            host: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            port: 5002,
+            proxy_protocol: false,
+            error_format: ErrorFormat::Laravel,
+        }
     }
 }
 
@@ -15,3 +15,11 @@ pub const BEARER_PREFIX: &str = "Bearer ";
 pub const GZIP_ENCODING: &str = "gzip";
 pub const NO_CACHE: &str = "no-cache";
 pub const KEEP_ALIVE: &str = "keep-alive";
+pub const BROTLI_ENCODING: &str = "br";
+pub const BYTES_UNIT: &str = "bytes";
+
+// Rate limiting
+pub const RETRY_AFTER: &str = "Retry-After";
+pub const RATE_LIMIT_LIMIT: &str = "X-RateLimit-Limit";
+pub const RATE_LIMIT_REMAINING: &str = "X-RateLimit-Remaining";
+pub const RATE_LIMIT_RESET: &str = "X-RateLimit-Reset";
@@ -4,3 +4,7 @@ pub const MAX_HEADERS_SIZE_BYTES: usize = 8 * 1024;
 pub const GZIP_MIN_SIZE_BYTES: usize = 1024;
 pub const INITIAL_LINE_BUFFER_CAPACITY: usize = 256;
 pub const INITIAL_HEADERS_CAPACITY: usize = 16;
+pub const MAX_FILE_SIZE_BYTES: u64 = 2 * 1024 * 1024;
+pub const MAX_FIELD_SIZE_BYTES: u64 = 64 * 1024;
+pub const MAX_MULTIPART_PARTS: usize = 1000;
+pub const MAX_JSON_DEPTH: usize = 32;
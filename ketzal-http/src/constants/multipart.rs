@@ -2,6 +2,6 @@ pub const BOUNDARY_PREFIX: &str = "--";
 pub const BOUNDARY_PARAM: &str = "boundary=";
 pub const CONTENT_DISPOSITION: &str = "content-disposition:";
 pub const CONTENT_TYPE_HEADER: &str = "content-type:";
-pub const NAME_PARAM: &str = "name=\"";
-pub const FILENAME_PARAM: &str = "filename=\"";
+pub const NAME_PARAM: &str = "name=";
+pub const FILENAME_PARAM: &str = "filename=";
 pub const DEFAULT_CONTENT_TYPE: &str = "text/plain";
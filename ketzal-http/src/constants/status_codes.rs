@@ -47,6 +47,7 @@ pub mod status_code {
     pub const RANGE_NOT_SATISFIABLE: u16 = 416;
     pub const EXPECTATION_FAILED: u16 = 417;
     pub const IM_A_TEAPOT: u16 = 418;
+    pub const PAGE_EXPIRED: u16 = 419;
     pub const MISDIRECTED_REQUEST: u16 = 421;
     pub const UNPROCESSABLE_ENTITY: u16 = 422;
     pub const LOCKED: u16 = 423;
@@ -114,6 +115,7 @@ pub mod status_code {
             RANGE_NOT_SATISFIABLE => "Range Not Satisfiable",
             EXPECTATION_FAILED => "Expectation Failed",
             IM_A_TEAPOT => "I'm a teapot",
+            PAGE_EXPIRED => "Page Expired",
             MISDIRECTED_REQUEST => "Misdirected Request",
             UNPROCESSABLE_ENTITY => "Unprocessable Entity",
             LOCKED => "Locked",
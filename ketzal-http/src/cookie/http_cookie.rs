@@ -0,0 +1,177 @@
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+/// The `SameSite` attribute of a cookie.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl fmt::Display for SameSite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Strict => f.write_str("Strict"),
+            Self::Lax => f.write_str("Lax"),
+            Self::None => f.write_str("None"),
+        }
+    }
+}
+
+/// A cookie to be sent with `Set-Cookie`.
+///
+/// # Example
+///
+/// ```ignore
+/// let cookie = Cookie::new("theme", "dark")
+///     .path("/")
+///     .max_age(Duration::from_secs(3600))
+///     .http_only(true)
+///     .same_site(SameSite::Lax);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cookie {
+    name: String,
+    value: String,
+    path: Option<String>,
+    domain: Option<String>,
+    max_age: Option<Duration>,
+    expires: Option<SystemTime>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+}
+
+impl Cookie {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            path: None,
+            domain: None,
+            max_age: None,
+            expires: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        }
+    }
+
+    /// A cookie that tells the browser to delete `name`.
+    pub fn removal(name: impl Into<String>) -> Self {
+        Self::new(name, "").path("/").max_age(Duration::ZERO).expires(SystemTime::UNIX_EPOCH)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    pub fn expires(mut self, expires: SystemTime) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.http_only = http_only;
+        self
+    }
+
+    /// Sets `SameSite`. `SameSite::None` implies `Secure`, as browsers require.
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        if same_site == SameSite::None {
+            self.secure = true;
+        }
+        self
+    }
+
+    /// Whether this cookie deletes the browser's copy.
+    pub fn is_removal(&self) -> bool {
+        self.max_age == Some(Duration::ZERO)
+    }
+
+    /// Renders the `Set-Cookie` header value. The value is percent-encoded.
+    pub fn to_header_value(&self) -> String {
+        let mut out = format!("{}={}", self.name, urlencoding::encode(&self.value));
+
+        if let Some(path) = &self.path {
+            out.push_str(&format!("; Path={path}"));
+        }
+        if let Some(domain) = &self.domain {
+            out.push_str(&format!("; Domain={domain}"));
+        }
+        if let Some(max_age) = self.max_age {
+            out.push_str(&format!("; Max-Age={}", max_age.as_secs()));
+        }
+        if let Some(expires) = self.expires {
+            out.push_str(&format!("; Expires={}", httpdate::fmt_http_date(expires)));
+        }
+        if self.secure {
+            out.push_str("; Secure");
+        }
+        if self.http_only {
+            out.push_str("; HttpOnly");
+        }
+        if let Some(same_site) = self.same_site {
+            out.push_str(&format!("; SameSite={same_site}"));
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for Cookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_header_value())
+    }
+}
+
+/// Parses a `Cookie` request header into name/value pairs.
+///
+/// Values are percent-decoded and surrounding quotes are removed. Malformed
+/// pairs are skipped.
+pub fn parse_cookie_header(header: &str) -> Vec<(String, String)> {
+    header
+        .split(';')
+        .filter_map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+
+            let value = value.trim().trim_matches('"');
+            let value = urlencoding::decode(value).map(|v| v.into_owned()).ok()?;
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
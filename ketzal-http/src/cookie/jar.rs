@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use aes_gcm::aead::{Aead, AeadCore, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use http::header::COOKIE;
+use http::HeaderMap;
+use sha2::Sha256;
+
+use super::{parse_cookie_header, Cookie, Key, Keyring};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const NONCE_LEN: usize = 12;
+
+/// The cookies of a request plus the changes to send back.
+///
+/// Reads see cookies added or removed during the request. Only the changes
+/// (the *delta*) are written to the response, see
+/// [`Response::with_cookies`](crate::Response::with_cookies).
+#[derive(Clone, Debug, Default)]
+pub struct CookieJar {
+    incoming: HashMap<String, String>,
+    delta: Vec<Cookie>,
+}
+
+impl CookieJar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a jar from every `Cookie` header in `headers`.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let incoming = headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(parse_cookie_header)
+            .collect();
+
+        Self { incoming, delta: Vec::new() }
+    }
+
+    /// Current value of a cookie, taking pending changes into account.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        match self.delta.iter().rev().find(|c| c.name() == name) {
+            Some(cookie) if cookie.is_removal() => None,
+            Some(cookie) => Some(cookie.value()),
+            None => self.incoming.get(name).map(String::as_str),
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Queues a cookie to be set on the response.
+    pub fn add(&mut self, cookie: Cookie) {
+        self.delta.retain(|c| c.name() != cookie.name());
+        self.delta.push(cookie);
+    }
+
+    /// Queues the deletion of a cookie.
+    pub fn remove(&mut self, name: &str) {
+        self.add(Cookie::removal(name));
+    }
+
+    /// Cookies to send back with `Set-Cookie`.
+    pub fn delta(&self) -> &[Cookie] {
+        &self.delta
+    }
+
+    /// A view that signs values, so clients can read but not forge them.
+    pub fn signed<'a>(&'a mut self, keys: &'a Keyring) -> SignedJar<'a> {
+        SignedJar { jar: self, keys }
+    }
+
+    /// A view that encrypts values, so clients can neither read nor forge them.
+    pub fn encrypted<'a>(&'a mut self, keys: &'a Keyring) -> EncryptedJar<'a> {
+        EncryptedJar { jar: self, keys }
+    }
+}
+
+/// Cookies authenticated with HMAC-SHA256. See [`CookieJar::signed`].
+pub struct SignedJar<'a> {
+    jar: &'a mut CookieJar,
+    keys: &'a Keyring,
+}
+
+impl SignedJar<'_> {
+    /// The verified value, or `None` if missing or tampered with.
+    pub fn get(&self, name: &str) -> Option<String> {
+        verify(self.keys, name, self.jar.get(name)?)
+    }
+
+    /// Signs the cookie value with the current key and queues it.
+    pub fn add(&mut self, mut cookie: Cookie) {
+        let signed = sign(self.keys.current(), cookie.name(), cookie.value());
+        cookie.set_value(signed);
+        self.jar.add(cookie);
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.jar.remove(name);
+    }
+}
+
+/// Cookies encrypted with AES-256-GCM. See [`CookieJar::encrypted`].
+pub struct EncryptedJar<'a> {
+    jar: &'a mut CookieJar,
+    keys: &'a Keyring,
+}
+
+impl EncryptedJar<'_> {
+    /// The decrypted value, or `None` if missing or tampered with.
+    pub fn get(&self, name: &str) -> Option<String> {
+        decrypt(self.keys, name, self.jar.get(name)?)
+    }
+
+    /// Encrypts the cookie value with the current key and queues it.
+    pub fn add(&mut self, mut cookie: Cookie) {
+        let sealed = encrypt(self.keys.current(), cookie.name(), cookie.value());
+        cookie.set_value(sealed);
+        self.jar.add(cookie);
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.jar.remove(name);
+    }
+}
+
+/// Signs `value`, binding it to the cookie `name`. Output: `value.mac`.
+pub fn sign(key: &Key, name: &str, value: &str) -> String {
+    let mac = mac(key, name, value).finalize().into_bytes();
+    format!("{value}.{}", URL_SAFE_NO_PAD.encode(mac))
+}
+
+/// Verifies a value produced by [`sign`] against every key in `keys`.
+pub fn verify(keys: &Keyring, name: &str, signed: &str) -> Option<String> {
+    let (value, tag) = signed.rsplit_once('.')?;
+    let tag = URL_SAFE_NO_PAD.decode(tag).ok()?;
+
+    keys.keys()
+        .any(|key| mac(key, name, value).verify_slice(&tag).is_ok())
+        .then(|| value.to_string())
+}
+
+/// Encrypts `value`, binding it to the cookie `name` as associated data.
+pub fn encrypt(key: &Key, name: &str, value: &str) -> String {
+    let cipher = Aes256Gcm::new(key.encryption().into());
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, Payload { msg: value.as_bytes(), aad: name.as_bytes() })
+        .expect("AES-GCM encryption of in-memory data cannot fail");
+
+    let mut out = nonce.to_vec();
+    out.extend_from_slice(&ciphertext);
+    URL_SAFE_NO_PAD.encode(out)
+}
+
+/// Decrypts a value produced by [`encrypt`] with any key in `keys`.
+pub fn decrypt(keys: &Keyring, name: &str, sealed: &str) -> Option<String> {
+    let bytes = URL_SAFE_NO_PAD.decode(sealed).ok()?;
+    if bytes.len() <= NONCE_LEN {
+        return None;
+    }
+
+    let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+    let nonce = Nonce::from_slice(nonce);
+
+    keys.keys().find_map(|key| {
+        let cipher = Aes256Gcm::new(key.encryption().into());
+        let plain =
+            cipher.decrypt(nonce, Payload { msg: ciphertext, aad: name.as_bytes() }).ok()?;
+        String::from_utf8(plain).ok()
+    })
+}
+
+fn mac(key: &Key, name: &str, value: &str) -> HmacSha256 {
+    let mut mac = <HmacSha256 as Mac>::new_from_slice(key.signing())
+        .expect("HMAC accepts keys of any length");
+    mac.update(name.as_bytes());
+    mac.update(b"=");
+    mac.update(value.as_bytes());
+    mac
+}
@@ -0,0 +1,107 @@
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::OsRng;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Prefix used by Laravel-style `APP_KEY` values.
+pub const BASE64_KEY_PREFIX: &str = "base64:";
+
+const SIGNING_CONTEXT: &[u8] = b"ketzal.cookie.signing";
+const ENCRYPTION_CONTEXT: &[u8] = b"ketzal.cookie.encryption";
+
+/// Key material for signed and encrypted cookies, derived from an app key.
+///
+/// Separate sub-keys are derived for signing (HMAC-SHA256) and encryption
+/// (AES-256-GCM), so one app key can safely serve both.
+#[derive(Clone)]
+pub struct Key {
+    signing: [u8; 32],
+    encryption: [u8; 32],
+}
+
+impl Key {
+    /// Derives a key from raw app key bytes.
+    pub fn derive(app_key: &[u8]) -> Self {
+        Self {
+            signing: derive(app_key, SIGNING_CONTEXT),
+            encryption: derive(app_key, ENCRYPTION_CONTEXT),
+        }
+    }
+
+    /// Derives a key from an `APP_KEY` string, decoding `base64:` values.
+    pub fn from_app_key(app_key: &str) -> Self {
+        match app_key.strip_prefix(BASE64_KEY_PREFIX).map(|b64| STANDARD.decode(b64)) {
+            Some(Ok(bytes)) => Self::derive(&bytes),
+            _ => Self::derive(app_key.as_bytes()),
+        }
+    }
+
+    /// Generates a random `base64:` app key suitable for `from_app_key`.
+    pub fn generate_app_key() -> String {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        format!("{BASE64_KEY_PREFIX}{}", STANDARD.encode(bytes))
+    }
+
+    pub(crate) fn signing(&self) -> &[u8; 32] {
+        &self.signing
+    }
+
+    pub(crate) fn encryption(&self) -> &[u8; 32] {
+        &self.encryption
+    }
+}
+
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+/// The current key plus previous keys that are still accepted.
+///
+/// New cookies are always signed or encrypted with the current key; cookies
+/// produced with a previous key keep verifying until that key is dropped,
+/// which allows rotating `APP_KEY` without logging everyone out.
+#[derive(Clone, Debug)]
+pub struct Keyring {
+    current: Key,
+    previous: Vec<Key>,
+}
+
+impl Keyring {
+    pub fn new(current: Key) -> Self {
+        Self { current, previous: Vec::new() }
+    }
+
+    /// Adds a retired key that is still accepted when reading cookies.
+    pub fn with_previous(mut self, key: Key) -> Self {
+        self.previous.push(key);
+        self
+    }
+
+    pub fn current(&self) -> &Key {
+        &self.current
+    }
+
+    /// All accepted keys, current first.
+    pub fn keys(&self) -> impl Iterator<Item = &Key> {
+        std::iter::once(&self.current).chain(self.previous.iter())
+    }
+}
+
+impl From<Key> for Keyring {
+    fn from(key: Key) -> Self {
+        Self::new(key)
+    }
+}
+
+fn derive(app_key: &[u8], context: &[u8]) -> [u8; 32] {
+    let mut mac = HmacSha256::new_from_slice(app_key).expect("HMAC accepts keys of any length");
+    mac.update(context);
+    mac.finalize().into_bytes().into()
+}
@@ -0,0 +1,9 @@
+//! Cookie parsing, `Set-Cookie` rendering and signed/encrypted cookie jars.
+
+mod http_cookie;
+mod jar;
+mod key;
+
+pub use http_cookie::{parse_cookie_header, Cookie, SameSite};
+pub use jar::{decrypt, encrypt, sign, verify, CookieJar, EncryptedJar, SignedJar};
+pub use key::{Key, Keyring, BASE64_KEY_PREFIX};
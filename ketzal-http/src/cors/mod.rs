@@ -0,0 +1,277 @@
+//! Cross-origin resource sharing.
+//!
+//! [`Cors`] adds `Access-Control-*` headers to responses for allowed origins
+//! and answers preflight requests itself, using the methods the router has
+//! registered for the path ([`AllowedMethods`]).
+//!
+//! # Example
+//!
+//! ```ignore
+//! let cors = Cors::new()
+//!     .allow_origins(["https://app.example.com", "https://*.preview.example.com"])
+//!     .allow_headers(["content-type", "authorization"])
+//!     .expose_headers(["x-request-id"])
+//!     .allow_credentials(true)
+//!     .max_age(Duration::from_secs(600));
+//!
+//! register_api_middleware(cors);
+//! ```
+
+mod origin;
+
+pub use origin::OriginPattern;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::header::{
+    ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS, ACCESS_CONTROL_MAX_AGE,
+    ACCESS_CONTROL_REQUEST_HEADERS, ACCESS_CONTROL_REQUEST_METHOD, ORIGIN, VARY,
+};
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+
+use crate::constants::{ALLOW_ALL_ORIGINS, ALLOW_HEADERS, ALLOW_METHODS};
+use crate::middleware::{AllowedMethods, Middleware, Next, ResponseFuture};
+use crate::{Request, Response};
+
+/// CORS middleware.
+///
+/// [`Cors::default`] follows the `constants::cors` values: any origin,
+/// [`ALLOW_METHODS`] and [`ALLOW_HEADERS`]. [`Cors::new`] allows nothing
+/// until configured.
+#[derive(Clone, Debug)]
+pub struct Cors {
+    config: Arc<Config>,
+}
+
+#[derive(Clone, Debug)]
+struct Config {
+    origins: Vec<OriginPattern>,
+    methods: Vec<Method>,
+    /// `None` mirrors whatever the preflight asks for.
+    headers: Option<Vec<String>>,
+    expose_headers: Vec<String>,
+    credentials: bool,
+    max_age: Option<Duration>,
+}
+
+impl Cors {
+    pub fn new() -> Self {
+        let config = Config {
+            origins: Vec::new(),
+            methods: Vec::new(),
+            headers: Some(Vec::new()),
+            expose_headers: Vec::new(),
+            credentials: false,
+            max_age: None,
+        };
+        Self { config: Arc::new(config) }
+    }
+
+    fn config(&mut self) -> &mut Config {
+        Arc::make_mut(&mut self.config)
+    }
+
+    /// Origins allowed to call, e.g. `https://app.example.com`, `*` or
+    /// `https://*.example.com`.
+    pub fn allow_origins<I, O>(mut self, origins: I) -> Self
+    where
+        I: IntoIterator<Item = O>,
+        O: Into<OriginPattern>,
+    {
+        self.config().origins = origins.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Methods allowed cross-origin. Preflights only advertise the ones the
+    /// router also has registered for the path.
+    pub fn allow_methods<I: IntoIterator<Item = Method>>(mut self, methods: I) -> Self {
+        self.config().methods = methods.into_iter().collect();
+        self
+    }
+
+    /// Request headers allowed cross-origin.
+    pub fn allow_headers<I, S>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config().headers = Some(headers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Allows any request header by mirroring the preflight request.
+    pub fn allow_any_header(mut self) -> Self {
+        self.config().headers = None;
+        self
+    }
+
+    /// Response headers scripts may read.
+    pub fn expose_headers<I, S>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config().expose_headers = headers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Allows cookies and `Authorization` for the listed origins. Origins
+    /// only let in by `*` stay anonymous: they get `*` back and no
+    /// `Access-Control-Allow-Credentials`, as reflecting them would hand
+    /// credentialed access to any site.
+    pub fn allow_credentials(mut self, enabled: bool) -> Self {
+        self.config().credentials = enabled;
+        self
+    }
+
+    /// How long browsers may cache a preflight answer.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.config().max_age = Some(max_age);
+        self
+    }
+
+    fn allows_any_origin(&self) -> bool {
+        self.config.origins.iter().any(OriginPattern::is_any)
+    }
+
+    /// The `Access-Control-Allow-Origin` value for `origin`, if allowed.
+    fn allow_origin(&self, origin: &str) -> Option<String> {
+        let listed = self.config.origins.iter().any(|p| !p.is_any() && p.matches(origin));
+        if self.allows_any_origin() && !(listed && self.config.credentials) {
+            return Some(ALLOW_ALL_ORIGINS.to_string());
+        }
+        listed.then(|| origin.to_string())
+    }
+
+    /// Whether the answer to `allow_origin` may carry credentials.
+    fn allows_credentials(&self, allow_origin: &str) -> bool {
+        self.config.credentials && allow_origin != ALLOW_ALL_ORIGINS
+    }
+
+    fn preflight(&self, req: &Request, origin: &str) -> Response {
+        let mut res = Response::new(StatusCode::NO_CONTENT);
+        vary(&mut res.headers, "Origin");
+        vary(&mut res.headers, "Access-Control-Request-Method");
+        if self.config.headers.is_none() {
+            vary(&mut res.headers, "Access-Control-Request-Headers");
+        }
+
+        let Some(allow_origin) = self.allow_origin(origin) else {
+            return res;
+        };
+
+        let methods = self.preflight_methods(req);
+        let requested = header(req, &ACCESS_CONTROL_REQUEST_METHOD).unwrap_or_default();
+        if !methods.iter().any(|m| m.as_str().eq_ignore_ascii_case(requested.trim())) {
+            return res;
+        }
+
+        let headers = match &self.config.headers {
+            Some(headers) => headers.join(", "),
+            None => header(req, &ACCESS_CONTROL_REQUEST_HEADERS).unwrap_or_default().to_string(),
+        };
+        let methods: Vec<&str> = methods.iter().map(Method::as_str).collect();
+
+        set(&mut res.headers, ACCESS_CONTROL_ALLOW_ORIGIN, &allow_origin);
+        set(&mut res.headers, ACCESS_CONTROL_ALLOW_METHODS, &methods.join(", "));
+        if !headers.is_empty() {
+            set(&mut res.headers, ACCESS_CONTROL_ALLOW_HEADERS, &headers);
+        }
+        if self.allows_credentials(&allow_origin) {
+            set(&mut res.headers, ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+        }
+        if let Some(max_age) = self.config.max_age {
+            set(&mut res.headers, ACCESS_CONTROL_MAX_AGE, &max_age.as_secs().to_string());
+        }
+
+        res
+    }
+
+    /// Configured methods, narrowed to those the router knows for the path.
+    fn preflight_methods(&self, req: &Request) -> Vec<Method> {
+        match req.extensions.get::<AllowedMethods>() {
+            Some(AllowedMethods(routed)) => {
+                self.config.methods.iter().filter(|m| routed.contains(m)).cloned().collect()
+            }
+            None => self.config.methods.clone(),
+        }
+    }
+
+    fn decorate(&self, mut res: Response, origin: Option<&str>) -> Response {
+        if !self.allows_any_origin() || self.config.credentials {
+            vary(&mut res.headers, "Origin");
+        }
+
+        let Some(allow_origin) = origin.and_then(|o| self.allow_origin(o)) else {
+            return res;
+        };
+
+        set(&mut res.headers, ACCESS_CONTROL_ALLOW_ORIGIN, &allow_origin);
+        if self.allows_credentials(&allow_origin) {
+            set(&mut res.headers, ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+        }
+        if !self.config.expose_headers.is_empty() {
+            let exposed = self.config.expose_headers.join(", ");
+            set(&mut res.headers, ACCESS_CONTROL_EXPOSE_HEADERS, &exposed);
+        }
+
+        res
+    }
+}
+
+impl Default for Cors {
+    fn default() -> Self {
+        Self::new()
+            .allow_origins([ALLOW_ALL_ORIGINS])
+            .allow_methods(ALLOW_METHODS.split(',').filter_map(|m| m.trim().parse().ok()))
+            .allow_headers(ALLOW_HEADERS.split(',').map(str::trim))
+    }
+}
+
+impl Middleware for Cors {
+    fn handle(&self, req: Request, next: Next) -> ResponseFuture {
+        let this = self.clone();
+
+        Box::pin(async move {
+            let origin = header(&req, &ORIGIN).map(str::to_string);
+
+            let is_preflight = req.method == Method::OPTIONS
+                && req.headers.contains_key(ACCESS_CONTROL_REQUEST_METHOD);
+            if let (true, Some(origin)) = (is_preflight, &origin) {
+                return this.preflight(&req, origin);
+            }
+
+            let res = next.run(req).await;
+            this.decorate(res, origin.as_deref())
+        })
+    }
+}
+
+fn set(headers: &mut HeaderMap, name: HeaderName, value: &str) {
+    if let Ok(value) = HeaderValue::from_str(value) {
+        headers.insert(name, value);
+    }
+}
+
+fn header<'a>(req: &'a Request, name: &HeaderName) -> Option<&'a str> {
+    req.headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+/// Adds `value` to the `Vary` header unless already listed.
+fn vary(headers: &mut HeaderMap, value: &str) {
+    let current = headers.get(VARY).and_then(|v| v.to_str().ok()).unwrap_or_default();
+    let listed = current.split(',').any(|v| {
+        let v = v.trim();
+        v == "*" || v.eq_ignore_ascii_case(value)
+    });
+    if listed {
+        return;
+    }
+
+    let merged = if current.is_empty() { value.to_string() } else { format!("{current}, {value}") };
+    if let Ok(merged) = HeaderValue::from_str(&merged) {
+        headers.insert(VARY, merged);
+    }
+}
@@ -0,0 +1,49 @@
+/// An allowed origin: an exact origin, `*`, or a pattern such as
+/// `https://*.example.com` where `*` matches any run of characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OriginPattern {
+    pattern: String,
+}
+
+impl OriginPattern {
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        Self { pattern: pattern.trim_end_matches('/').to_ascii_lowercase() }
+    }
+
+    pub fn is_any(&self) -> bool {
+        self.pattern == "*"
+    }
+
+    pub fn matches(&self, origin: &str) -> bool {
+        glob(&self.pattern, &origin.to_ascii_lowercase())
+    }
+}
+
+impl<S: Into<String>> From<S> for OriginPattern {
+    fn from(pattern: S) -> Self {
+        Self::new(pattern)
+    }
+}
+
+fn glob(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.len() >= last.len() && rest.ends_with(last)
+}
@@ -0,0 +1,170 @@
+//! Cross-site request forgery protection for web routes.
+//!
+//! [`CsrfMiddleware`] compares a token sent with every state-changing request
+//! against the token stored in the session. It needs [`SessionMiddleware`]
+//! earlier in the same stack and belongs on the web stack only: API routes
+//! authenticate with headers, not cookies, and are not exposed to CSRF.
+//!
+//! # Example
+//!
+//! ```ignore
+//! register_web_middleware(SessionMiddleware::new(FileStore::new("storage/sessions"), keys));
+//! register_web_middleware(CsrfMiddleware::new().except(["/webhooks/*"]));
+//!
+//! async fn edit(req: Request) -> Response {
+//!     let form = format!("<form method=\"POST\">{}</form>", req.csrf_field());
+//!     Response::ok(form).header("content-type", "text/html")
+//! }
+//! ```
+//!
+//! [`SessionMiddleware`]: crate::session::SessionMiddleware
+
+use std::sync::Arc;
+
+use http::{Method, StatusCode};
+use subtle::ConstantTimeEq;
+
+use crate::constants::status_code::{self, PAGE_EXPIRED};
+use crate::cookie::{Cookie, CookieJar, SameSite};
+use crate::middleware::{Middleware, Next, ResponseFuture};
+use crate::session::{Session, TOKEN_KEY};
+use crate::{Request, Response};
+
+/// Form field carrying the token.
+pub const TOKEN_FIELD: &str = "_token";
+/// Header carrying the token, e.g. from a `<meta>` tag.
+pub const CSRF_HEADER: &str = "x-csrf-token";
+/// Header carrying the value of the [`XSRF_COOKIE`], as sent by JS clients.
+pub const XSRF_HEADER: &str = "x-xsrf-token";
+/// Script-readable cookie holding the token.
+pub const XSRF_COOKIE: &str = "XSRF-TOKEN";
+
+/// Rejects unsafe requests that do not carry the session's CSRF token.
+///
+/// `GET`, `HEAD` and `OPTIONS` always pass. Other methods answer
+/// `419 Page Expired` when the session has no token yet (typically an
+/// expired session) and `403 Forbidden` when the token is missing or wrong.
+#[derive(Clone, Debug)]
+pub struct CsrfMiddleware {
+    config: Arc<Config>,
+}
+
+#[derive(Clone, Debug)]
+struct Config {
+    except: Vec<String>,
+    xsrf_cookie: bool,
+    secure: bool,
+}
+
+impl CsrfMiddleware {
+    pub fn new() -> Self {
+        let config = Config { except: Vec::new(), xsrf_cookie: true, secure: false };
+        Self { config: Arc::new(config) }
+    }
+
+    /// Paths that skip verification. A trailing `*` matches any suffix.
+    pub fn except<I, S>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Arc::make_mut(&mut self.config).except.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// Whether to send the token in the [`XSRF_COOKIE`] on every response.
+    pub fn xsrf_cookie(mut self, enabled: bool) -> Self {
+        Arc::make_mut(&mut self.config).xsrf_cookie = enabled;
+        self
+    }
+
+    /// Marks the [`XSRF_COOKIE`] as `Secure`.
+    pub fn secure(mut self, secure: bool) -> Self {
+        Arc::make_mut(&mut self.config).secure = secure;
+        self
+    }
+
+    fn is_excluded(&self, path: &str) -> bool {
+        self.config.except.iter().any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => path.starts_with(prefix),
+            None => pattern.trim_end_matches('/') == path.trim_end_matches('/'),
+        })
+    }
+
+    fn verify(&self, req: &Request, session: &Session) -> Result<(), StatusCode> {
+        let Some(expected) = session.get::<String>(TOKEN_KEY) else {
+            return Err(page_expired());
+        };
+
+        let submitted = request_token(req).ok_or(StatusCode::FORBIDDEN)?;
+        if bool::from(submitted.as_bytes().ct_eq(expected.as_bytes())) {
+            Ok(())
+        } else {
+            Err(StatusCode::FORBIDDEN)
+        }
+    }
+
+    fn with_xsrf_cookie(&self, session: &Session, response: Response) -> Response {
+        if !self.config.xsrf_cookie {
+            return response;
+        }
+
+        let cookie = Cookie::new(XSRF_COOKIE, session.token())
+            .path("/")
+            .secure(self.config.secure)
+            .same_site(SameSite::Lax);
+
+        let mut jar = CookieJar::new();
+        jar.add(cookie);
+        response.with_cookies(&jar)
+    }
+}
+
+impl Default for CsrfMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Middleware for CsrfMiddleware {
+    fn handle(&self, req: Request, next: Next) -> ResponseFuture {
+        let this = self.clone();
+
+        Box::pin(async move {
+            let Some(session) = req.try_session() else {
+                tracing::error!("CsrfMiddleware requires SessionMiddleware earlier in the stack");
+                return reject(StatusCode::INTERNAL_SERVER_ERROR);
+            };
+
+            let safe = matches!(req.method, Method::GET | Method::HEAD | Method::OPTIONS);
+            if !safe && !this.is_excluded(&req.path) {
+                if let Err(status) = this.verify(&req, &session) {
+                    return reject(status);
+                }
+            }
+
+            let response = next.run(req).await;
+            this.with_xsrf_cookie(&session, response)
+        })
+    }
+}
+
+/// The token sent with the request: headers first, then the body. A
+/// `_token` in the query string does not count.
+fn request_token(req: &Request) -> Option<String> {
+    for name in [CSRF_HEADER, XSRF_HEADER] {
+        if let Some(value) = req.headers.get(name).and_then(|v| v.to_str().ok()) {
+            return Some(value.to_string());
+        }
+    }
+
+    req.body_string(TOKEN_FIELD).filter(|token| !token.is_empty())
+}
+
+fn page_expired() -> StatusCode {
+    StatusCode::from_u16(PAGE_EXPIRED).expect("419 is a valid status code")
+}
+
+fn reject(status: StatusCode) -> Response {
+    Response::with_body(status, status_code::reason_phrase(status.as_u16()))
+}
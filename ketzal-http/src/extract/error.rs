@@ -0,0 +1,81 @@
+use http::StatusCode;
+use serde_json::json;
+use thiserror::Error;
+
+use crate::response::Response;
+
+/// Why a request body could not be turned into a typed value.
+#[derive(Debug, Error)]
+pub enum DeserializeError {
+    #[error("Content-Type must be {expected}")]
+    UnsupportedMediaType { expected: &'static str },
+
+    /// The body is not well-formed (e.g. broken JSON).
+    #[error("Malformed request body: {message}")]
+    Syntax { message: String },
+
+    #[error("Request body exceeds the maximum nesting depth of {limit}")]
+    TooDeep { limit: usize },
+
+    /// The body is well-formed but does not match the target type.
+    #[error("Invalid value for `{field}`: {message}")]
+    Data {
+        /// Dot-notation path of the failing field (`"user.tags.0"`), empty
+        /// for the top-level value.
+        field: String,
+        /// What the target type expected, when serde reports it.
+        expected: Option<String>,
+        message: String,
+    },
+}
+
+impl DeserializeError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::UnsupportedMediaType { .. } => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Syntax { .. } | Self::TooDeep { .. } => StatusCode::BAD_REQUEST,
+            Self::Data { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    /// A JSON error response. Data errors also carry `field` and `expected`.
+    pub fn into_response(self) -> Response {
+        match &self {
+            Self::Data { field, expected, message } => Response::json_with_status(
+                self.status(),
+                json!({
+                    "error": self.to_string(),
+                    "field": field,
+                    "expected": expected,
+                    "message": message,
+                }),
+            ),
+            _ => Response::json_error(self.status(), self.to_string()),
+        }
+    }
+
+    /// Builds a data error from a serde message and the path it failed at.
+    pub(crate) fn data(mut field: String, message: String) -> Self {
+        // serde reports a missing field at its parent; point at the field itself.
+        if let Some(name) = missing_field(&message) {
+            if !field.is_empty() {
+                field.push('.');
+            }
+            field.push_str(name);
+        }
+
+        let expected = expected_type(&message).map(str::to_string);
+        Self::Data { field, expected, message }
+    }
+}
+
+/// `"missing field `name`"` -> `name`.
+fn missing_field(message: &str) -> Option<&str> {
+    message.strip_prefix("missing field `")?.split('`').next()
+}
+
+/// `"invalid type: string \"x\", expected u32"` -> `u32`.
+fn expected_type(message: &str) -> Option<&str> {
+    let (_, expected) = message.rsplit_once("expected ")?;
+    Some(expected.trim())
+}
@@ -0,0 +1,206 @@
+//! Typed request bodies and handler extractors.
+//!
+//! [`Request::json_as`] and [`Request::form_as`] deserialize the body into any
+//! `DeserializeOwned` type. Failures are [`DeserializeError`]s that render as
+//! JSON: `400` for malformed or too deeply nested bodies, `422` when the body
+//! does not fit the type, with the failing field path and the expected type.
+//!
+//! The same conversions are available as handler arguments through
+//! [`FromRequest`], see [`Json`] and [`Form`]. [`Valid`] validates the input
+//! against a [`FormRequest`](ketzal_validation::FormRequest) first.
+
+mod error;
+mod valid;
+
+use std::ops::Deref;
+
+use serde::de::DeserializeOwned;
+use serde_path_to_error::Segment;
+
+pub use error::DeserializeError;
+pub use valid::Valid;
+
+use crate::constants::MAX_JSON_DEPTH;
+use crate::request::Request;
+use crate::response::Response;
+
+/// A value built from a request before the handler runs.
+///
+/// Route handlers may take an extractor as their last argument, after any
+/// path parameters. A failed extraction answers with the returned response.
+///
+/// # Example
+///
+/// ```ignore
+/// async fn update(id: u64, Json(user): Json<UpdateUser>) -> Response { ... }
+/// ```
+pub trait FromRequest: Sized {
+    #[allow(clippy::result_large_err)]
+    fn from_request(req: &Request) -> Result<Self, Response>;
+}
+
+/// A JSON body deserialized into `T`. See [`Request::json_as`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: DeserializeOwned> FromRequest for Json<T> {
+    fn from_request(req: &Request) -> Result<Self, Response> {
+        req.json_as().map(Json).map_err(DeserializeError::into_response)
+    }
+}
+
+/// A form body deserialized into `T`. See [`Request::form_as`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Form<T>(pub T);
+
+impl<T> Form<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Form<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: DeserializeOwned> FromRequest for Form<T> {
+    fn from_request(req: &Request) -> Result<Self, Response> {
+        req.form_as().map(Form).map_err(DeserializeError::into_response)
+    }
+}
+
+/// Limits enforced while deserializing a JSON body.
+///
+/// [`Request::json_as`] uses the limits found in the request extensions, so
+/// a middleware can set them for a whole route group.
+#[derive(Clone, Copy, Debug)]
+pub struct JsonLimits {
+    /// Maximum nesting of arrays and objects.
+    pub max_depth: usize,
+}
+
+impl Default for JsonLimits {
+    fn default() -> Self {
+        Self { max_depth: MAX_JSON_DEPTH }
+    }
+}
+
+impl JsonLimits {
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+}
+
+pub(crate) fn from_json<T: DeserializeOwned>(
+    body: &[u8],
+    limits: JsonLimits,
+) -> Result<T, DeserializeError> {
+    if exceeds_depth(body, limits.max_depth) {
+        return Err(DeserializeError::TooDeep { limit: limits.max_depth });
+    }
+
+    let mut de = serde_json::Deserializer::from_slice(body);
+
+    let value = serde_path_to_error::deserialize(&mut de).map_err(|err| {
+        let field = dot_path(err.path());
+        let inner = err.into_inner();
+        let message = strip_position(&inner.to_string()).to_string();
+
+        match inner.classify() {
+            serde_json::error::Category::Data => DeserializeError::data(field, message),
+            _ => DeserializeError::Syntax { message },
+        }
+    })?;
+
+    // Trailing characters after the value.
+    de.end()
+        .map_err(|e| DeserializeError::Syntax { message: strip_position(&e.to_string()).into() })?;
+
+    Ok(value)
+}
+
+pub(crate) fn from_form<T: DeserializeOwned>(body: &[u8]) -> Result<T, DeserializeError> {
+    let de = serde_urlencoded::Deserializer::new(form_urlencoded::parse(body));
+
+    serde_path_to_error::deserialize(de)
+        .map_err(|err| DeserializeError::data(dot_path(err.path()), err.inner().to_string()))
+}
+
+pub(crate) fn from_value<T: DeserializeOwned>(
+    value: serde_json::Value,
+) -> Result<T, DeserializeError> {
+    serde_path_to_error::deserialize(value).map_err(|err| {
+        let message = strip_position(&err.inner().to_string()).to_string();
+        DeserializeError::data(dot_path(err.path()), message)
+    })
+}
+
+/// Renders a serde path in the dot notation used by the input helpers.
+fn dot_path(path: &serde_path_to_error::Path) -> String {
+    let segments: Vec<String> = path
+        .iter()
+        .map(|segment| match segment {
+            Segment::Seq { index } => index.to_string(),
+            Segment::Map { key } => key.clone(),
+            Segment::Enum { variant } => variant.clone(),
+            Segment::Unknown => "?".to_string(),
+        })
+        .collect();
+    segments.join(".")
+}
+
+/// serde_json appends ` at line L column C`; the field path says more.
+fn strip_position(message: &str) -> &str {
+    message.rsplit_once(" at line ").map_or(message, |(message, _)| message)
+}
+
+/// Whether arrays and objects nest deeper than `max`, ignoring brackets
+/// inside strings. Runs before parsing so deep input costs no recursion.
+fn exceeds_depth(body: &[u8], max: usize) -> bool {
+    let (mut depth, mut in_string, mut escaped) = (0usize, false, false);
+
+    for &byte in body {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b'[' | b'{' => {
+                depth += 1;
+                if depth > max {
+                    return true;
+                }
+            }
+            b']' | b'}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    false
+}
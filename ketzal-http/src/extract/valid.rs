@@ -0,0 +1,59 @@
+use std::ops::Deref;
+
+use ketzal_validation::FromValidated;
+
+use super::FromRequest;
+use crate::request::Request;
+use crate::response::Response;
+
+/// Input validated with the rules of `T`, then deserialized into `T`.
+///
+/// The request input ([`Request::all`]: query and body) is handed to
+/// [`FromValidated::from_validated`], which `#[derive(FormRequest)]`
+/// implements. Failed rules, and values that do not fit `T`, answer `422`
+/// with the validation errors, as `validate_json` does, in the language of
+/// [`Request::preferred_locale`].
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Deserialize, FormRequest)]
+/// struct CreateUser {
+///     #[rule("required")]
+///     name: String,
+///     #[rule("required|email")]
+///     email: String,
+/// }
+///
+/// async fn store(Valid(user): Valid<CreateUser>) -> Response { ... }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Valid<T>(pub T);
+
+impl<T> Valid<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Valid<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: FromValidated> FromRequest for Valid<T> {
+    fn from_request(req: &Request) -> Result<Self, Response> {
+        let input = req.try_all()?;
+        let data = input.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+
+        let validated = match req.preferred_locale() {
+            Some(locale) => T::from_validated_in(data, &locale),
+            None => T::from_validated(data),
+        };
+
+        validated.map(Valid).map_err(Response::json_validation_error)
+    }
+}
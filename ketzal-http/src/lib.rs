@@ -1,9 +1,19 @@
 pub mod config;
 pub mod constants;
+pub mod cookie;
+pub mod cors;
+pub mod csrf;
+pub mod extract;
+pub mod middleware;
+pub mod multipart;
+pub mod network;
 pub mod protocol;
+pub mod rate_limit;
 
 pub mod request;
 pub mod response;
+pub mod session;
+pub mod static_files;
 
 pub use request::Request;
 pub use response::Response;
@@ -0,0 +1,78 @@
+//! Request middleware.
+//!
+//! A [`Middleware`] wraps the rest of the stack: it receives the request and
+//! a [`Next`] that runs the remaining middleware and, finally, the route
+//! handler. It may answer early, modify the request, or post-process the
+//! response.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let timing = |req: Request, next: Next| -> ResponseFuture {
+//!     Box::pin(async move {
+//!         let started = Instant::now();
+//!         let res = next.run(req).await;
+//!         res.with_header("x-elapsed-ms", started.elapsed().as_millis().to_string())
+//!     })
+//! };
+//! router.middleware(timing);
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use http::Method;
+
+use crate::{Request, Response};
+
+/// The future returned by handlers and middleware.
+pub type ResponseFuture = Pin<Box<dyn Future<Output = Response> + Send>>;
+
+/// The innermost step of a stack, usually route dispatch.
+pub type Endpoint = Arc<dyn Fn(Request) -> ResponseFuture + Send + Sync>;
+
+/// Methods the router has registered for the request path.
+///
+/// Attached to `OPTIONS` requests before the middleware stack runs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AllowedMethods(pub Vec<Method>);
+
+/// A layer around request handling.
+pub trait Middleware: Send + Sync + 'static {
+    fn handle(&self, req: Request, next: Next) -> ResponseFuture;
+}
+
+impl<F> Middleware for F
+where
+    F: Fn(Request, Next) -> ResponseFuture + Send + Sync + 'static,
+{
+    fn handle(&self, req: Request, next: Next) -> ResponseFuture {
+        self(req, next)
+    }
+}
+
+/// The remainder of a middleware stack.
+#[derive(Clone)]
+pub struct Next {
+    stack: Arc<[Arc<dyn Middleware>]>,
+    index: usize,
+    endpoint: Endpoint,
+}
+
+impl Next {
+    pub fn new(stack: Arc<[Arc<dyn Middleware>]>, endpoint: Endpoint) -> Self {
+        Self { stack, index: 0, endpoint }
+    }
+
+    /// Runs the next middleware, or the endpoint once the stack is exhausted.
+    pub fn run(mut self, req: Request) -> ResponseFuture {
+        match self.stack.get(self.index).cloned() {
+            Some(middleware) => {
+                self.index += 1;
+                middleware.handle(req, self)
+            }
+            None => (self.endpoint)(req),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::io;
+
+use http::StatusCode;
+use thiserror::Error;
+
+use crate::response::Response;
+
+#[derive(Debug, Error)]
+pub enum MultipartError {
+    #[error("Content-Type must be multipart/form-data with a boundary")]
+    MissingBoundary,
+
+    #[error("Malformed multipart body: {0}")]
+    Malformed(&'static str),
+
+    #[error("File `{field}` exceeds the maximum size of {limit} bytes")]
+    FileTooLarge { field: String, limit: u64 },
+
+    #[error("Field `{field}` exceeds the maximum size of {limit} bytes")]
+    FieldTooLarge { field: String, limit: u64 },
+
+    #[error("Multipart body exceeds the maximum size of {limit} bytes")]
+    PayloadTooLarge { limit: u64 },
+
+    #[error("Multipart body has more than {limit} parts")]
+    TooManyParts { limit: usize },
+
+    #[error("Failed to store uploaded file: {0}")]
+    Io(#[from] io::Error),
+}
+
+impl MultipartError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::MissingBoundary => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Malformed(_) => StatusCode::BAD_REQUEST,
+            Self::FileTooLarge { .. }
+            | Self::FieldTooLarge { .. }
+            | Self::PayloadTooLarge { .. }
+            | Self::TooManyParts { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::Io(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn into_response(self) -> Response {
+        Response::json_error(self.status(), self.to_string())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::constants::{
+    MAX_BODY_SIZE_BYTES, MAX_FIELD_SIZE_BYTES, MAX_FILE_SIZE_BYTES, MAX_MULTIPART_PARTS,
+    STREAMING_THRESHOLD_BYTES,
+};
+
+/// Size limits enforced while parsing a multipart body.
+#[derive(Clone, Copy, Debug)]
+pub struct MultipartLimits {
+    /// Maximum size of a single uploaded file.
+    pub max_file_size: u64,
+    /// Maximum size of a single text field.
+    pub max_field_size: u64,
+    /// Maximum size of all part bodies combined.
+    pub max_total_size: u64,
+    /// Maximum number of parts (fields and files).
+    pub max_parts: usize,
+    /// Files larger than this are spooled to a temporary file instead of memory.
+    pub spool_threshold: u64,
+}
+
+impl Default for MultipartLimits {
+    fn default() -> Self {
+        Self {
+            max_file_size: MAX_FILE_SIZE_BYTES,
+            max_field_size: MAX_FIELD_SIZE_BYTES,
+            max_total_size: MAX_BODY_SIZE_BYTES,
+            max_parts: MAX_MULTIPART_PARTS,
+            spool_threshold: STREAMING_THRESHOLD_BYTES,
+        }
+    }
+}
+
+impl MultipartLimits {
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = bytes;
+        self
+    }
+
+    pub fn max_field_size(mut self, bytes: u64) -> Self {
+        self.max_field_size = bytes;
+        self
+    }
+
+    pub fn max_total_size(mut self, bytes: u64) -> Self {
+        self.max_total_size = bytes;
+        self
+    }
+
+    pub fn max_parts(mut self, parts: usize) -> Self {
+        self.max_parts = parts;
+        self
+    }
+
+    pub fn spool_threshold(mut self, bytes: u64) -> Self {
+        self.spool_threshold = bytes;
+        self
+    }
+}
@@ -0,0 +1,147 @@
+//! `multipart/form-data` parsing and file uploads.
+
+mod error;
+mod limits;
+mod parser;
+mod uploaded_file;
+
+use std::collections::HashMap;
+
+use ketzal_validation::FileMeta;
+use serde_json::Value;
+
+pub use error::MultipartError;
+pub use limits::MultipartLimits;
+pub use parser::{boundary, MultipartParser};
+pub use uploaded_file::UploadedFile;
+
+/// Chunk size used when feeding an already buffered body to the parser.
+const FEED_CHUNK_BYTES: usize = 8 * 1024;
+
+/// The text fields and files of a parsed `multipart/form-data` body.
+#[derive(Clone, Debug, Default)]
+pub struct Multipart {
+    fields: Vec<(String, String)>,
+    files: Vec<UploadedFile>,
+}
+
+impl Multipart {
+    pub(crate) fn new(fields: Vec<(String, String)>, files: Vec<UploadedFile>) -> Self {
+        Self { fields, files }
+    }
+
+    /// Parses a complete body with the given Content-Type header value.
+    pub fn parse(
+        content_type: &str,
+        body: &[u8],
+        limits: MultipartLimits,
+    ) -> Result<Self, MultipartError> {
+        let boundary = boundary(content_type).ok_or(MultipartError::MissingBoundary)?;
+        let mut parser = MultipartParser::new(boundary, limits);
+
+        for chunk in body.chunks(FEED_CHUNK_BYTES) {
+            parser.feed(chunk)?;
+        }
+
+        parser.finish()
+    }
+
+    /// First value of a text field.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.fields
+    }
+
+    /// First file uploaded under `name`.
+    pub fn file(&self, name: &str) -> Option<&UploadedFile> {
+        self.files.iter().find(|f| f.field() == name)
+    }
+
+    pub fn files(&self) -> &[UploadedFile] {
+        &self.files
+    }
+
+    /// Groups files by field name, with `[]` suffixes stripped.
+    pub fn files_by_field(&self) -> HashMap<String, Vec<UploadedFile>> {
+        let mut map: HashMap<String, Vec<UploadedFile>> = HashMap::new();
+        for file in &self.files {
+            map.entry(field_key(file.field()).to_string()).or_default().push(file.clone());
+        }
+        map
+    }
+
+    /// Builds the data handed to the validator.
+    ///
+    /// Files are described by their [`FileMeta`] value. Fields sent with a
+    /// `[]` suffix, or more than once, become arrays.
+    pub fn to_validation_data(&self) -> HashMap<String, Value> {
+        self.validation_input().0
+    }
+
+    /// The [`FileMeta`] of each file by its path in
+    /// [`Multipart::to_validation_data`], for
+    /// [`Validator::set_files`](ketzal_validation::Validator::set_files).
+    pub fn validation_files(&self) -> HashMap<String, FileMeta> {
+        self.validation_input().1
+    }
+
+    fn validation_input(&self) -> (HashMap<String, Value>, HashMap<String, FileMeta>) {
+        let entries =
+            self.fields.iter().map(|(k, v)| (k.as_str(), Value::String(v.clone()), None)).chain(
+                self.files.iter().map(|f| {
+                    let meta = f.meta();
+                    (f.field(), meta.to_value(), Some(meta))
+                }),
+            );
+
+        let mut map: HashMap<String, Value> = HashMap::new();
+        // Files with the key and position they were stored at.
+        let mut placed: Vec<(&str, usize, FileMeta)> = Vec::new();
+
+        for (raw_key, value, meta) in entries {
+            let key = field_key(raw_key);
+            let is_list = key.len() != raw_key.len();
+
+            let index = match map.get_mut(key) {
+                Some(Value::Array(items)) => {
+                    items.push(value);
+                    items.len() - 1
+                }
+                Some(existing) => {
+                    let first = existing.take();
+                    *existing = Value::Array(vec![first, value]);
+                    1
+                }
+                None if is_list => {
+                    map.insert(key.to_string(), Value::Array(vec![value]));
+                    0
+                }
+                None => {
+                    map.insert(key.to_string(), value);
+                    0
+                }
+            };
+
+            if let Some(meta) = meta {
+                placed.push((key, index, meta));
+            }
+        }
+
+        let files = placed
+            .into_iter()
+            .map(|(key, index, meta)| match map.get(key) {
+                Some(Value::Array(_)) => (format!("{key}.{index}"), meta),
+                _ => (key.to_string(), meta),
+            })
+            .collect();
+
+        (map, files)
+    }
+}
+
+fn field_key(name: &str) -> &str {
+    name.strip_suffix("[]").unwrap_or(name)
+}
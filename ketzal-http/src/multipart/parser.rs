@@ -0,0 +1,342 @@
+use std::io::Write;
+
+use tempfile::NamedTempFile;
+
+use super::{Multipart, MultipartError, MultipartLimits, UploadedFile};
+use crate::constants::{
+    BOUNDARY_PARAM, BOUNDARY_PREFIX, CONTENT_DISPOSITION, CONTENT_TYPE_HEADER, CRLF,
+    DEFAULT_CONTENT_TYPE, FILENAME_PARAM, MAX_HEADERS_SIZE_BYTES, NAME_PARAM,
+};
+
+/// Extracts the boundary from a `multipart/form-data` Content-Type value.
+pub fn boundary(content_type: &str) -> Option<&str> {
+    content_type.split(';').map(str::trim).find_map(|param| {
+        let prefix = param.get(..BOUNDARY_PARAM.len())?;
+        if !prefix.eq_ignore_ascii_case(BOUNDARY_PARAM) {
+            return None;
+        }
+
+        let value = param[BOUNDARY_PARAM.len()..].trim_matches('"');
+        (!value.is_empty()).then_some(value)
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Preamble,
+    Boundary,
+    Headers,
+    Body,
+    Done,
+}
+
+enum Sink {
+    Memory(Vec<u8>),
+    Spooled(NamedTempFile),
+}
+
+struct Part {
+    name: String,
+    file_name: Option<String>,
+    content_type: String,
+    size: u64,
+    sink: Sink,
+}
+
+/// Incremental `multipart/form-data` parser.
+///
+/// Bytes can be fed in chunks of any size as they arrive; only the
+/// unmatched tail of a boundary is buffered between calls. File contents
+/// above the spool threshold are written straight to a temporary file.
+pub struct MultipartParser {
+    delimiter: Vec<u8>,
+    limits: MultipartLimits,
+    state: State,
+    buf: Vec<u8>,
+    part: Option<Part>,
+    parts: usize,
+    total: u64,
+    fields: Vec<(String, String)>,
+    files: Vec<UploadedFile>,
+}
+
+impl MultipartParser {
+    pub fn new(boundary: &str, limits: MultipartLimits) -> Self {
+        let delimiter = format!("{CRLF}{BOUNDARY_PREFIX}{boundary}").into_bytes();
+
+        Self {
+            delimiter,
+            limits,
+            state: State::Preamble,
+            // The first boundary is not preceded by CRLF; seeding the buffer with
+            // one lets every boundary be matched with the same delimiter.
+            buf: CRLF.as_bytes().to_vec(),
+            part: None,
+            parts: 0,
+            total: 0,
+            fields: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), MultipartError> {
+        if self.state == State::Done {
+            return Ok(());
+        }
+
+        self.buf.extend_from_slice(chunk);
+
+        loop {
+            let progressed = match self.state {
+                State::Preamble => self.skip_preamble(),
+                State::Boundary => self.read_boundary()?,
+                State::Headers => self.read_headers()?,
+                State::Body => self.read_body()?,
+                State::Done => {
+                    self.buf.clear();
+                    false
+                }
+            };
+
+            if !progressed {
+                return Ok(());
+            }
+        }
+    }
+
+    pub fn finish(self) -> Result<Multipart, MultipartError> {
+        if self.state != State::Done {
+            return Err(MultipartError::Malformed("unexpected end of body"));
+        }
+
+        Ok(Multipart::new(self.fields, self.files))
+    }
+
+    fn skip_preamble(&mut self) -> bool {
+        match find(&self.buf, &self.delimiter) {
+            Some(pos) => {
+                self.buf.drain(..pos + self.delimiter.len());
+                self.state = State::Boundary;
+                true
+            }
+            None => {
+                let keep = self.delimiter.len() - 1;
+                let discard = self.buf.len().saturating_sub(keep);
+                self.buf.drain(..discard);
+                false
+            }
+        }
+    }
+
+    fn read_boundary(&mut self) -> Result<bool, MultipartError> {
+        let padding = self.buf.iter().take_while(|b| matches!(b, b' ' | b'\t')).count();
+        self.buf.drain(..padding);
+
+        if self.buf.len() < 2 {
+            return Ok(false);
+        }
+
+        if self.buf.starts_with(BOUNDARY_PREFIX.as_bytes()) {
+            self.state = State::Done;
+        } else if self.buf.starts_with(CRLF.as_bytes()) {
+            self.buf.drain(..2);
+            self.state = State::Headers;
+        } else {
+            return Err(MultipartError::Malformed("invalid boundary line"));
+        }
+
+        Ok(true)
+    }
+
+    fn read_headers(&mut self) -> Result<bool, MultipartError> {
+        let (raw, consumed) = if self.buf.starts_with(CRLF.as_bytes()) {
+            (String::new(), CRLF.len())
+        } else {
+            match find(&self.buf, b"\r\n\r\n") {
+                Some(pos) => (String::from_utf8_lossy(&self.buf[..pos]).into_owned(), pos + 4),
+                None if self.buf.len() > MAX_HEADERS_SIZE_BYTES => {
+                    return Err(MultipartError::Malformed("part headers too large"));
+                }
+                None => return Ok(false),
+            }
+        };
+
+        self.buf.drain(..consumed);
+
+        self.parts += 1;
+        if self.parts > self.limits.max_parts {
+            return Err(MultipartError::TooManyParts { limit: self.limits.max_parts });
+        }
+
+        self.part = Some(parse_part_headers(&raw)?);
+        self.state = State::Body;
+        Ok(true)
+    }
+
+    fn read_body(&mut self) -> Result<bool, MultipartError> {
+        match find(&self.buf, &self.delimiter) {
+            Some(pos) => {
+                let data: Vec<u8> =
+                    self.buf.drain(..pos + self.delimiter.len()).take(pos).collect();
+                self.write(&data)?;
+                self.finish_part()?;
+                self.state = State::Boundary;
+                Ok(true)
+            }
+            None => {
+                let keep = self.delimiter.len() - 1;
+                let safe = self.buf.len().saturating_sub(keep);
+                if safe > 0 {
+                    let data: Vec<u8> = self.buf.drain(..safe).collect();
+                    self.write(&data)?;
+                }
+                Ok(false)
+            }
+        }
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<(), MultipartError> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let limits = self.limits;
+        let part = self.part.as_mut().ok_or(MultipartError::Malformed("body outside of part"))?;
+        let len = data.len() as u64;
+
+        self.total += len;
+        if self.total > limits.max_total_size {
+            return Err(MultipartError::PayloadTooLarge { limit: limits.max_total_size });
+        }
+
+        part.size += len;
+        if part.file_name.is_some() {
+            if part.size > limits.max_file_size {
+                return Err(MultipartError::FileTooLarge {
+                    field: part.name.clone(),
+                    limit: limits.max_file_size,
+                });
+            }
+        } else if part.size > limits.max_field_size {
+            return Err(MultipartError::FieldTooLarge {
+                field: part.name.clone(),
+                limit: limits.max_field_size,
+            });
+        }
+
+        match &mut part.sink {
+            Sink::Memory(bytes)
+                if part.file_name.is_some() && part.size > limits.spool_threshold =>
+            {
+                let mut file = NamedTempFile::new()?;
+                file.write_all(bytes)?;
+                file.write_all(data)?;
+                part.sink = Sink::Spooled(file);
+            }
+            Sink::Memory(bytes) => bytes.extend_from_slice(data),
+            Sink::Spooled(file) => file.write_all(data)?,
+        }
+
+        Ok(())
+    }
+
+    fn finish_part(&mut self) -> Result<(), MultipartError> {
+        let Some(part) = self.part.take() else {
+            return Ok(());
+        };
+
+        match (part.file_name, part.sink) {
+            (None, Sink::Memory(bytes)) => {
+                self.fields.push((part.name, String::from_utf8_lossy(&bytes).into_owned()));
+            }
+            // Browsers send an empty, unnamed part for file inputs left blank.
+            (Some(file_name), _) if file_name.is_empty() && part.size == 0 => {}
+            (Some(file_name), Sink::Memory(bytes)) => {
+                self.files.push(UploadedFile::in_memory(
+                    part.name,
+                    file_name,
+                    part.content_type,
+                    bytes,
+                ));
+            }
+            (Some(file_name), Sink::Spooled(mut file)) => {
+                file.flush()?;
+                self.files.push(UploadedFile::spooled(
+                    part.name,
+                    file_name,
+                    part.content_type,
+                    part.size,
+                    file,
+                ));
+            }
+            (None, Sink::Spooled(_)) => unreachable!("text fields are never spooled"),
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_part_headers(raw: &str) -> Result<Part, MultipartError> {
+    let mut name = None;
+    let mut file_name = None;
+    let mut content_type = DEFAULT_CONTENT_TYPE.to_string();
+
+    for line in raw.split(CRLF) {
+        let lower = line.to_ascii_lowercase();
+
+        if lower.starts_with(CONTENT_DISPOSITION) {
+            for param in split_params(&line[CONTENT_DISPOSITION.len()..]) {
+                if let Some(value) = strip_param(param, FILENAME_PARAM) {
+                    file_name = Some(value);
+                } else if let Some(value) = strip_param(param, NAME_PARAM) {
+                    name = Some(value);
+                }
+            }
+        } else if lower.starts_with(CONTENT_TYPE_HEADER) {
+            content_type = line[CONTENT_TYPE_HEADER.len()..].trim().to_string();
+        }
+    }
+
+    let name = name.ok_or(MultipartError::Malformed("part without a name"))?;
+
+    Ok(Part { name, file_name, content_type, size: 0, sink: Sink::Memory(Vec::new()) })
+}
+
+/// Splits header parameters on `;`, ignoring separators inside quotes.
+fn split_params(value: &str) -> Vec<&str> {
+    let mut params = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+
+    for (i, c) in value.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => {
+                params.push(value[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    params.push(value[start..].trim());
+    params
+}
+
+fn strip_param(param: &str, prefix: &str) -> Option<String> {
+    let head = param.get(..prefix.len())?;
+    if !head.eq_ignore_ascii_case(prefix) {
+        return None;
+    }
+
+    // Quoted, or a bare token as RFC 7578 allows.
+    let value = &param[prefix.len()..];
+    match value.strip_prefix('"') {
+        Some(quoted) => Some(quoted.strip_suffix('"')?.replace("\\\"", "\"")),
+        None => Some(value.trim().to_string()),
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
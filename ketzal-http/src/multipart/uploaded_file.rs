@@ -0,0 +1,124 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use ketzal_validation::file::SNIFF_LEN;
+use ketzal_validation::FileMeta;
+use tempfile::NamedTempFile;
+
+#[derive(Clone, Debug)]
+enum Storage {
+    Memory(Arc<[u8]>),
+    Spooled(Arc<NamedTempFile>),
+}
+
+/// A file received in a `multipart/form-data` body.
+///
+/// Small files are kept in memory; files above
+/// [`MultipartLimits::spool_threshold`](super::MultipartLimits) are written
+/// to a temporary file that is removed when the last clone is dropped.
+#[derive(Clone, Debug)]
+pub struct UploadedFile {
+    field: String,
+    file_name: String,
+    content_type: String,
+    size: u64,
+    storage: Storage,
+}
+
+impl UploadedFile {
+    pub(crate) fn in_memory(
+        field: String,
+        file_name: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Self {
+        let size = bytes.len() as u64;
+        Self { field, file_name, content_type, size, storage: Storage::Memory(bytes.into()) }
+    }
+
+    pub(crate) fn spooled(
+        field: String,
+        file_name: String,
+        content_type: String,
+        size: u64,
+        file: NamedTempFile,
+    ) -> Self {
+        Self { field, file_name, content_type, size, storage: Storage::Spooled(Arc::new(file)) }
+    }
+
+    /// Name of the form field the file was sent under.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// File name reported by the client. Never trust it as a filesystem path.
+    pub fn client_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// Lower-cased extension of the client file name.
+    pub fn extension(&self) -> Option<String> {
+        FileMeta::new(self.file_name.as_str(), "", 0).extension()
+    }
+
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Whether the file content lives in a temporary file.
+    pub fn is_spooled(&self) -> bool {
+        matches!(self.storage, Storage::Spooled(_))
+    }
+
+    /// Path of the temporary file, if the upload was spooled to disk.
+    pub fn path(&self) -> Option<&Path> {
+        match &self.storage {
+            Storage::Spooled(file) => Some(file.path()),
+            Storage::Memory(_) => None,
+        }
+    }
+
+    /// Reads the whole file content.
+    pub fn bytes(&self) -> io::Result<Vec<u8>> {
+        match &self.storage {
+            Storage::Memory(bytes) => Ok(bytes.to_vec()),
+            Storage::Spooled(file) => fs::read(file.path()),
+        }
+    }
+
+    /// Copies the file content to `dest`.
+    pub fn store(&self, dest: impl AsRef<Path>) -> io::Result<()> {
+        match &self.storage {
+            Storage::Memory(bytes) => fs::write(dest, bytes),
+            Storage::Spooled(file) => fs::copy(file.path(), dest).map(|_| ()),
+        }
+    }
+
+    /// Metadata for the validator, with the type read from the content.
+    /// A spooled file that cannot be read has no detected type.
+    pub fn meta(&self) -> FileMeta {
+        let meta = FileMeta::new(self.file_name.clone(), self.content_type.clone(), self.size);
+        match self.head() {
+            Ok(head) => meta.with_head(&head),
+            Err(_) => meta,
+        }
+    }
+
+    /// The first bytes of the content, enough to tell its type.
+    fn head(&self) -> io::Result<Vec<u8>> {
+        match &self.storage {
+            Storage::Memory(bytes) => Ok(bytes[..bytes.len().min(SNIFF_LEN)].to_vec()),
+            Storage::Spooled(file) => {
+                let mut head = Vec::with_capacity(SNIFF_LEN);
+                fs::File::open(file.path())?.take(SNIFF_LEN as u64).read_to_end(&mut head)?;
+                Ok(head)
+            }
+        }
+    }
+}
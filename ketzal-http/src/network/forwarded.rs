@@ -0,0 +1,47 @@
+use std::net::IpAddr;
+
+/// One element of an RFC 7239 `Forwarded` header.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ForwardedElement {
+    pub for_: Option<String>,
+    pub proto: Option<String>,
+    pub host: Option<String>,
+}
+
+/// Parses `Forwarded: for=1.2.3.4;proto=https, for="[::1]:80"`.
+pub fn parse_forwarded(header: &str) -> Vec<ForwardedElement> {
+    header
+        .split(',')
+        .map(|element| {
+            let mut parsed = ForwardedElement::default();
+            for pair in element.split(';') {
+                let Some((key, value)) = pair.split_once('=') else {
+                    continue;
+                };
+                let value = value.trim().trim_matches('"').to_string();
+                match key.trim().to_ascii_lowercase().as_str() {
+                    "for" => parsed.for_ = Some(value),
+                    "proto" => parsed.proto = Some(value.to_ascii_lowercase()),
+                    "host" => parsed.host = Some(value),
+                    _ => {}
+                }
+            }
+            parsed
+        })
+        .collect()
+}
+
+/// Parses a node from `Forwarded: for=` or `X-Forwarded-For`, which may
+/// carry a port (`1.2.3.4:80`, `[::1]:80`). Obfuscated or `unknown` nodes
+/// yield `None`.
+pub fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+
+    if let Some(rest) = node.strip_prefix('[') {
+        return rest.split_once(']')?.0.parse().ok();
+    }
+    if let Ok(ip) = node.parse() {
+        return Some(ip);
+    }
+    node.rsplit_once(':').and_then(|(ip, _)| ip.parse::<std::net::Ipv4Addr>().ok()).map(Into::into)
+}
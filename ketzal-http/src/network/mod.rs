@@ -0,0 +1,130 @@
+//! Client network information behind reverse proxies.
+//!
+//! By default [`Request::ip`](crate::Request::ip) and friends only trust the
+//! TCP peer and the `Host` header. Installing [`TrustProxies`] makes them
+//! honor `Forwarded` (RFC 7239) and `X-Forwarded-*` headers, but only when
+//! the request arrives from one of the configured proxies.
+//!
+//! # Example
+//!
+//! ```ignore
+//! register_web_middleware(TrustProxies::new(["10.0.0.0/8", "127.0.0.1"]));
+//!
+//! async fn show(req: Request) -> Response {
+//!     Response::ok(format!("{} via {}", req.ip().unwrap(), req.full_url()))
+//! }
+//! ```
+
+mod cidr;
+mod forwarded;
+
+pub use cidr::IpRange;
+pub use forwarded::{parse_forwarded, parse_node, ForwardedElement};
+
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use crate::middleware::{Middleware, Next, ResponseFuture};
+use crate::Request;
+
+/// Middleware declaring which peers are trusted reverse proxies.
+#[derive(Clone, Debug)]
+pub struct TrustProxies {
+    proxies: Arc<[IpRange]>,
+    any: bool,
+}
+
+impl TrustProxies {
+    /// Trusts the given addresses and CIDR ranges. Invalid entries are
+    /// logged and ignored.
+    pub fn new<I, S>(proxies: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let proxies = proxies
+            .into_iter()
+            .filter_map(|p| match p.as_ref().parse::<IpRange>() {
+                Ok(range) => Some(range),
+                Err(e) => {
+                    tracing::warn!(error = %e, "ignoring trusted proxy");
+                    None
+                }
+            })
+            .collect();
+        Self { proxies, any: false }
+    }
+
+    /// Trusts every peer, for apps only reachable through a proxy.
+    pub fn all() -> Self {
+        Self { proxies: Arc::new([]), any: true }
+    }
+
+    pub fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.any || self.proxies.iter().any(|range| range.contains(ip))
+    }
+
+    /// The client address: walks the forwarding chain from the nearest hop
+    /// and stops at the first address that is not a trusted proxy.
+    pub(crate) fn client_ip(&self, req: &Request, peer: IpAddr) -> IpAddr {
+        if !self.is_trusted(peer) {
+            return peer;
+        }
+
+        let mut client = peer;
+        for node in forwarded_for(req).iter().rev() {
+            let Some(ip) = parse_node(node) else {
+                break;
+            };
+            client = ip;
+            if !self.is_trusted(ip) {
+                break;
+            }
+        }
+        client
+    }
+
+    /// The entry of a per-hop header (`X-Forwarded-Proto`, the elements of
+    /// `Forwarded`, ...) added by the outermost trusted proxy: the hop at
+    /// which [`client_ip`](Self::client_ip) stops, counted from the right.
+    /// Entries further left were written by the client.
+    pub(crate) fn outermost<T>(&self, req: &Request, mut entries: Vec<T>) -> Option<T> {
+        if entries.is_empty() {
+            return None;
+        }
+
+        let mut hops = 0;
+        for node in forwarded_for(req).iter().rev() {
+            hops += 1;
+            if !parse_node(node).is_some_and(|ip| self.is_trusted(ip)) {
+                break;
+            }
+        }
+
+        let index = entries.len().saturating_sub(hops.max(1));
+        Some(entries.swap_remove(index))
+    }
+}
+
+impl Middleware for TrustProxies {
+    fn handle(&self, mut req: Request, next: Next) -> ResponseFuture {
+        req.extensions.insert(self.clone());
+        next.run(req)
+    }
+}
+
+/// The forwarding chain, client first. `Forwarded` wins over
+/// `X-Forwarded-For`.
+fn forwarded_for(req: &Request) -> Vec<String> {
+    if let Some(header) = header(req, "forwarded") {
+        return parse_forwarded(header).into_iter().filter_map(|e| e.for_).collect();
+    }
+
+    header(req, "x-forwarded-for")
+        .map(|h| h.split(',').map(|node| node.trim().to_string()).collect())
+        .unwrap_or_default()
+}
+
+fn header<'a>(req: &'a Request, name: &str) -> Option<&'a str> {
+    req.headers.get(name).and_then(|v| v.to_str().ok())
+}
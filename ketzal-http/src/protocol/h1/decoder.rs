@@ -61,7 +61,12 @@ where
         body.extend_from_slice(&temp[..n]);
     }
 
-    Ok(Some(Request::new(method, path, HashMap::new(), headers, body, HashMap::new())))
+    let query = path
+        .split_once('?')
+        .and_then(|(_, query)| serde_urlencoded::from_str(query).ok())
+        .unwrap_or_default();
+
+    Ok(Some(Request::new(method, path, query, headers, body, HashMap::new())))
 }
 
 fn parse_request_line(request: &str) -> io::Result<(Method, String)> {
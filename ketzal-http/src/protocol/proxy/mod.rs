@@ -0,0 +1,59 @@
+//! HAProxy PROXY protocol, versions 1 (text) and 2 (binary).
+//!
+//! A load balancer speaking the PROXY protocol prefixes each connection with
+//! a header carrying the original client and server addresses. See
+//! <https://www.haproxy.org/download/2.9/doc/proxy-protocol.txt>.
+
+mod v1;
+mod v2;
+
+use std::io;
+use std::net::SocketAddr;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// First bytes of a version 1 header.
+const V1_PREFIX: &[u8; 5] = b"PROXY";
+/// The 12-byte version 2 signature.
+const V2_SIGNATURE: &[u8; 12] = b"\r\n\r\n\0\r\nQUIT\n";
+
+/// Addresses announced by the proxy.
+///
+/// Both are `None` for health checks (`LOCAL`, `UNKNOWN`) and unsupported
+/// address families; the socket addresses should be used then.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProxyHeader {
+    pub source: Option<SocketAddr>,
+    pub destination: Option<SocketAddr>,
+}
+
+/// Reads a PROXY header from the start of `stream`.
+///
+/// Exactly the header is consumed, so the stream is positioned at the first
+/// byte of the proxied protocol afterwards. Connections without a valid
+/// header are rejected with [`io::ErrorKind::InvalidData`].
+pub async fn read_header<S>(stream: &mut S) -> io::Result<ProxyHeader>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut prefix = [0u8; 5];
+    stream.read_exact(&mut prefix).await?;
+
+    if &prefix == V1_PREFIX {
+        return v1::read(stream).await;
+    }
+
+    let mut signature = [0u8; 12];
+    signature[..5].copy_from_slice(&prefix);
+    stream.read_exact(&mut signature[5..]).await?;
+
+    if &signature == V2_SIGNATURE {
+        return v2::read(stream).await;
+    }
+
+    Err(invalid("missing PROXY protocol header"))
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
@@ -0,0 +1,54 @@
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::{invalid, ProxyHeader};
+
+/// Longest v1 header, including `PROXY` and the CRLF.
+const MAX_LEN: usize = 107;
+
+/// Reads the rest of a v1 line after the `PROXY` prefix.
+///
+/// The line is read byte by byte so nothing past the CRLF is consumed.
+pub(super) async fn read<S>(stream: &mut S) -> io::Result<ProxyHeader>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut line = Vec::with_capacity(MAX_LEN);
+
+    while !line.ends_with(b"\r\n") {
+        if line.len() + super::V1_PREFIX.len() >= MAX_LEN {
+            return Err(invalid("PROXY v1 header too long"));
+        }
+        line.push(stream.read_u8().await?);
+    }
+
+    let line = std::str::from_utf8(&line[..line.len() - 2])
+        .map_err(|_| invalid("PROXY v1 header is not ASCII"))?;
+    parse(line)
+}
+
+/// Parses the part after `PROXY`, e.g. ` TCP4 1.2.3.4 5.6.7.8 5000 80`.
+fn parse(line: &str) -> io::Result<ProxyHeader> {
+    let mut parts = line.strip_prefix(' ').ok_or_else(|| invalid("malformed PROXY v1"))?.split(' ');
+
+    match parts.next() {
+        Some("UNKNOWN") => return Ok(ProxyHeader::default()),
+        Some("TCP4" | "TCP6") => {}
+        _ => return Err(invalid("unsupported PROXY v1 protocol")),
+    }
+
+    let fields: Vec<&str> = parts.collect();
+    let [src, dst, src_port, dst_port] = fields[..] else {
+        return Err(invalid("malformed PROXY v1 addresses"));
+    };
+
+    let addr = |ip: &str, port: &str| -> io::Result<SocketAddr> {
+        let ip: IpAddr = ip.parse().map_err(|_| invalid("invalid PROXY v1 address"))?;
+        let port: u16 = port.parse().map_err(|_| invalid("invalid PROXY v1 port"))?;
+        Ok(SocketAddr::new(ip, port))
+    };
+
+    Ok(ProxyHeader { source: Some(addr(src, src_port)?), destination: Some(addr(dst, dst_port)?) })
+}
@@ -0,0 +1,67 @@
+use std::io;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::{invalid, ProxyHeader};
+
+const VERSION: u8 = 0x2;
+const CMD_LOCAL: u8 = 0x0;
+const CMD_PROXY: u8 = 0x1;
+const AF_INET: u8 = 0x1;
+const AF_INET6: u8 = 0x2;
+
+/// Reads the rest of a v2 header after the signature.
+pub(super) async fn read<S>(stream: &mut S) -> io::Result<ProxyHeader>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut fixed = [0u8; 4];
+    stream.read_exact(&mut fixed).await?;
+
+    let len = u16::from_be_bytes([fixed[2], fixed[3]]) as usize;
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload).await?;
+
+    parse(fixed[0], fixed[1], &payload)
+}
+
+/// Parses a v2 header from its version/command byte, family byte and the
+/// address payload (which may be followed by TLVs, ignored here).
+fn parse(version_command: u8, family: u8, payload: &[u8]) -> io::Result<ProxyHeader> {
+    if version_command >> 4 != VERSION {
+        return Err(invalid("unsupported PROXY v2 version"));
+    }
+
+    match version_command & 0x0F {
+        CMD_LOCAL => return Ok(ProxyHeader::default()),
+        CMD_PROXY => {}
+        _ => return Err(invalid("unsupported PROXY v2 command")),
+    }
+
+    let port = |bytes: &[u8]| u16::from_be_bytes([bytes[0], bytes[1]]);
+
+    match family >> 4 {
+        AF_INET if payload.len() >= 12 => {
+            let src = Ipv4Addr::new(payload[0], payload[1], payload[2], payload[3]);
+            let dst = Ipv4Addr::new(payload[4], payload[5], payload[6], payload[7]);
+            Ok(ProxyHeader {
+                source: Some(SocketAddr::new(src.into(), port(&payload[8..]))),
+                destination: Some(SocketAddr::new(dst.into(), port(&payload[10..]))),
+            })
+        }
+        AF_INET6 if payload.len() >= 36 => {
+            let ip = |bytes: &[u8]| {
+                let octets: [u8; 16] = bytes[..16].try_into().expect("16 bytes");
+                Ipv6Addr::from(octets)
+            };
+            Ok(ProxyHeader {
+                source: Some(SocketAddr::new(ip(&payload[..16]).into(), port(&payload[32..]))),
+                destination: Some(SocketAddr::new(ip(&payload[16..]).into(), port(&payload[34..]))),
+            })
+        }
+        AF_INET | AF_INET6 => Err(invalid("truncated PROXY v2 addresses")),
+        // AF_UNSPEC and AF_UNIX carry no usable IP address.
+        _ => Ok(ProxyHeader::default()),
+    }
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use crate::Request;
+
+/// How attempts are counted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// At most `max` attempts per window; the counter resets when the
+    /// window ends.
+    FixedWindow,
+    /// A bucket of `max` tokens refilled evenly over the window, allowing
+    /// bursts up to `max` while enforcing the average rate.
+    TokenBucket,
+}
+
+/// A single rate limit, e.g. 60 attempts per minute per IP.
+///
+/// # Example
+///
+/// ```ignore
+/// Limit::per_minute(60).by_ip(req)
+/// Limit::per_hour(1000).by(user_id).token_bucket()
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Limit {
+    max: u32,
+    window: Duration,
+    key: String,
+    algorithm: Algorithm,
+}
+
+impl Limit {
+    /// `max` attempts per `window`.
+    pub fn new(max: u32, window: Duration) -> Self {
+        Self { max, window, key: String::new(), algorithm: Algorithm::FixedWindow }
+    }
+
+    pub fn per_second(max: u32) -> Self {
+        Self::new(max, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(max: u32) -> Self {
+        Self::new(max, Duration::from_secs(60))
+    }
+
+    /// `max` attempts per `minutes` minutes.
+    pub fn per_minutes(minutes: u64, max: u32) -> Self {
+        Self::new(max, Duration::from_secs(minutes * 60))
+    }
+
+    pub fn per_hour(max: u32) -> Self {
+        Self::new(max, Duration::from_secs(60 * 60))
+    }
+
+    pub fn per_day(max: u32) -> Self {
+        Self::new(max, Duration::from_secs(24 * 60 * 60))
+    }
+
+    /// A limit that never triggers.
+    pub fn none() -> Self {
+        Self::new(u32::MAX, Duration::ZERO)
+    }
+
+    /// Segments the limit, e.g. by IP or user id. Without a key all
+    /// requests share one counter.
+    pub fn by(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    /// Segments the limit by client IP.
+    pub fn by_ip(self, req: &Request) -> Self {
+        let ip = req.ip().map_or_else(|| "unknown".to_string(), |ip| ip.to_string());
+        self.by(ip)
+    }
+
+    pub fn fixed_window(mut self) -> Self {
+        self.algorithm = Algorithm::FixedWindow;
+        self
+    }
+
+    pub fn token_bucket(mut self) -> Self {
+        self.algorithm = Algorithm::TokenBucket;
+        self
+    }
+
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        self.max == u32::MAX || self.window.is_zero()
+    }
+}
+
+impl From<Limit> for Vec<Limit> {
+    fn from(limit: Limit) -> Self {
+        vec![limit]
+    }
+}
+
+/// The outcome of one attempt against a [`Limit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Decision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Time until another attempt is allowed. Zero when allowed.
+    pub retry_after: Duration,
+    /// Time until the limit is fully restored.
+    pub reset_after: Duration,
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use super::{Limit, MemoryStore, RateLimitStore};
+use crate::Request;
+
+type Resolver = Arc<dyn Fn(&Request) -> Vec<Limit> + Send + Sync>;
+
+static GLOBAL: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+
+/// Named limiters and the store counting their attempts.
+///
+/// # Example
+///
+/// ```ignore
+/// RateLimiter::define("api", |req| Limit::per_minute(60).by_ip(req));
+/// RateLimiter::define("uploads", |req| {
+///     vec![Limit::per_minute(10).by_ip(req), Limit::per_day(500).by_ip(req)]
+/// });
+///
+/// Route::post("/api/upload", upload).middleware(Throttle::named("uploads"));
+/// ```
+pub struct RateLimiter {
+    limiters: RwLock<HashMap<String, Resolver>>,
+    store: Arc<dyn RateLimitStore>,
+}
+
+impl RateLimiter {
+    pub fn new(store: impl RateLimitStore) -> Self {
+        Self { limiters: RwLock::new(HashMap::new()), store: Arc::new(store) }
+    }
+
+    /// The process-wide limiter, backed by a [`MemoryStore`].
+    pub fn global() -> Arc<RateLimiter> {
+        GLOBAL.get_or_init(|| Arc::new(Self::new(MemoryStore::new()))).clone()
+    }
+
+    /// Defines a named limiter on the global limiter (Laravel's
+    /// `RateLimiter::for`).
+    pub fn define<F, L>(name: impl Into<String>, resolve: F)
+    where
+        F: Fn(&Request) -> L + Send + Sync + 'static,
+        L: Into<Vec<Limit>>,
+    {
+        Self::global().add(name, resolve);
+    }
+
+    /// Defines a named limiter on this instance.
+    pub fn add<F, L>(&self, name: impl Into<String>, resolve: F)
+    where
+        F: Fn(&Request) -> L + Send + Sync + 'static,
+        L: Into<Vec<Limit>>,
+    {
+        let resolver: Resolver = Arc::new(move |req| resolve(req).into());
+        self.limiters.write().unwrap().insert(name.into(), resolver);
+    }
+
+    /// The limits a named limiter applies to `req`.
+    pub fn limits(&self, name: &str, req: &Request) -> Option<Vec<Limit>> {
+        let resolver = self.limiters.read().unwrap().get(name)?.clone();
+        Some(resolver(req))
+    }
+
+    pub fn store(&self) -> &dyn RateLimitStore {
+        self.store.as_ref()
+    }
+}
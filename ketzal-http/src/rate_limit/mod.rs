@@ -0,0 +1,15 @@
+//! Rate limiting with named limiters.
+//!
+//! Limiters are defined once with [`RateLimiter::define`] and attached to
+//! routes or groups with the [`Throttle`] middleware. Counters live in a
+//! [`RateLimitStore`]; [`MemoryStore`] is used unless another is plugged in.
+
+mod limit;
+mod limiter;
+mod store;
+mod throttle;
+
+pub use limit::{Algorithm, Decision, Limit};
+pub use limiter::RateLimiter;
+pub use store::{HitFuture, MemoryStore, RateLimitStore, RefundFuture};
+pub use throttle::Throttle;
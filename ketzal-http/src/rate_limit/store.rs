@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{Algorithm, Decision, Limit};
+
+/// Prune expired entries once the map grows past this many keys.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// The future returned by [`RateLimitStore::hit`].
+pub type HitFuture<'a> = Pin<Box<dyn Future<Output = io::Result<Decision>> + Send + 'a>>;
+
+/// The future returned by [`RateLimitStore::refund`].
+pub type RefundFuture<'a> = Pin<Box<dyn Future<Output = io::Result<()>> + Send + 'a>>;
+
+/// Counter storage for rate limits.
+///
+/// `hit` must check and consume an attempt atomically, so shared stores
+/// (e.g. Redis) typically implement each [`Algorithm`] as a script.
+pub trait RateLimitStore: Send + Sync + 'static {
+    /// Records an attempt for `key` under `limit` if one is available.
+    fn hit<'a>(&'a self, key: &'a str, limit: &'a Limit) -> HitFuture<'a>;
+
+    /// Gives back an attempt `hit` consumed for `key`, used when another
+    /// limit rejects the same request.
+    fn refund<'a>(&'a self, key: &'a str, limit: &'a Limit) -> RefundFuture<'a>;
+}
+
+/// Counters in process memory.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStore {
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+#[derive(Debug)]
+enum Entry {
+    Window { started: Instant, count: u32, window: Duration },
+    Bucket { tokens: f64, updated: Instant, window: Duration },
+}
+
+impl Entry {
+    fn is_stale(&self, now: Instant) -> bool {
+        match self {
+            Self::Window { started, window, .. } => now.duration_since(*started) >= *window,
+            Self::Bucket { updated, window, .. } => now.duration_since(*updated) >= *window,
+        }
+    }
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn fixed_window(entry: &mut Entry, limit: &Limit, now: Instant) -> Decision {
+        let window = limit.window();
+        if !matches!(entry, Entry::Window { .. }) || entry.is_stale(now) {
+            *entry = Entry::Window { started: now, count: 0, window };
+        }
+        let Entry::Window { started, count, .. } = entry else { unreachable!() };
+
+        let reset_after = window.saturating_sub(now.duration_since(*started));
+        let allowed = *count < limit.max();
+        if allowed {
+            *count += 1;
+        }
+
+        Decision {
+            allowed,
+            limit: limit.max(),
+            remaining: limit.max() - *count,
+            retry_after: if allowed { Duration::ZERO } else { reset_after },
+            reset_after,
+        }
+    }
+
+    fn token_bucket(entry: &mut Entry, limit: &Limit, now: Instant) -> Decision {
+        // Without a refill rate there is no bucket: an empty one never
+        // allows an attempt, and a zero window never limits.
+        if limit.max() == 0 || limit.window().is_zero() {
+            let allowed = limit.max() > 0;
+            let wait = if allowed { Duration::ZERO } else { limit.window() };
+            return Decision {
+                allowed,
+                limit: limit.max(),
+                remaining: limit.max(),
+                retry_after: wait,
+                reset_after: wait,
+            };
+        }
+
+        let capacity = f64::from(limit.max());
+        let rate = capacity / limit.window().as_secs_f64();
+        if !matches!(entry, Entry::Bucket { .. }) {
+            *entry = Entry::Bucket { tokens: capacity, updated: now, window: limit.window() };
+        }
+        let Entry::Bucket { tokens, updated, .. } = entry else { unreachable!() };
+
+        *tokens = (*tokens + now.duration_since(*updated).as_secs_f64() * rate).min(capacity);
+        *updated = now;
+
+        let allowed = *tokens >= 1.0;
+        if allowed {
+            *tokens -= 1.0;
+        }
+
+        let retry_after =
+            if allowed { Duration::ZERO } else { Duration::from_secs_f64((1.0 - *tokens) / rate) };
+
+        Decision {
+            allowed,
+            limit: limit.max(),
+            remaining: tokens.floor() as u32,
+            retry_after,
+            reset_after: Duration::from_secs_f64((capacity - *tokens) / rate),
+        }
+    }
+}
+
+impl RateLimitStore for MemoryStore {
+    fn hit<'a>(&'a self, key: &'a str, limit: &'a Limit) -> HitFuture<'a> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+
+        if entries.len() > PRUNE_THRESHOLD {
+            entries.retain(|_, entry| !entry.is_stale(now));
+        }
+
+        let entry = entries.entry(key.to_string()).or_insert(Entry::Window {
+            started: now,
+            count: 0,
+            window: limit.window(),
+        });
+
+        let decision = match limit.algorithm() {
+            Algorithm::FixedWindow => Self::fixed_window(entry, limit, now),
+            Algorithm::TokenBucket => Self::token_bucket(entry, limit, now),
+        };
+
+        Box::pin(async move { Ok(decision) })
+    }
+
+    fn refund<'a>(&'a self, key: &'a str, limit: &'a Limit) -> RefundFuture<'a> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+
+        if let Some(entry) = entries.get_mut(key).filter(|entry| !entry.is_stale(now)) {
+            match entry {
+                Entry::Window { count, .. } => *count = count.saturating_sub(1),
+                Entry::Bucket { tokens, .. } => {
+                    *tokens = (*tokens + 1.0).min(f64::from(limit.max()));
+                }
+            }
+        }
+
+        Box::pin(async { Ok(()) })
+    }
+}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use http::StatusCode;
+
+use super::{Decision, Limit, RateLimiter};
+use crate::constants::{RATE_LIMIT_LIMIT, RATE_LIMIT_REMAINING, RATE_LIMIT_RESET, RETRY_AFTER};
+use crate::middleware::{Middleware, Next, ResponseFuture};
+use crate::{Request, Response};
+
+/// Middleware enforcing rate limits, answering `429 Too Many Requests`.
+///
+/// Allowed responses carry `X-RateLimit-Limit` and `X-RateLimit-Remaining`
+/// for the tightest limit; rejections add `Retry-After` and
+/// `X-RateLimit-Reset`.
+///
+/// # Example
+///
+/// ```ignore
+/// Route::post("/login", login).middleware(Throttle::named("login"));
+/// RouteGroup::new("/api").middleware(Throttle::limit(Limit::per_minute(60)));
+/// ```
+#[derive(Clone)]
+pub struct Throttle {
+    source: Source,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+#[derive(Clone)]
+enum Source {
+    Named(String),
+    Fixed(Limit),
+}
+
+impl Throttle {
+    /// Uses a limiter defined with [`RateLimiter::define`].
+    pub fn named(name: impl Into<String>) -> Self {
+        Self { source: Source::Named(name.into()), limiter: None }
+    }
+
+    /// Applies `limit` directly, keyed by client IP unless it has a key.
+    pub fn limit(limit: Limit) -> Self {
+        Self { source: Source::Fixed(limit), limiter: None }
+    }
+
+    /// Resolves limiters and counts attempts with `limiter` instead of
+    /// [`RateLimiter::global`].
+    pub fn using(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// The limits for `req`, each with its store key.
+    fn resolve(&self, limiter: &RateLimiter, req: &Request) -> Vec<(String, Limit)> {
+        match &self.source {
+            Source::Named(name) => {
+                let Some(limits) = limiter.limits(name, req) else {
+                    tracing::error!(limiter = %name, "rate limiter is not defined");
+                    return Vec::new();
+                };
+                limits
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, limit)| (format!("{name}:{i}:{}", limit.key()), limit))
+                    .collect()
+            }
+            Source::Fixed(limit) => {
+                let limit = match limit.key() {
+                    "" => limit.clone().by_ip(req),
+                    _ => limit.clone(),
+                };
+                let window = limit.window().as_secs();
+                vec![(format!("throttle:{}/{window}:{}", limit.max(), limit.key()), limit)]
+            }
+        }
+    }
+
+    /// Hits every limit for `req`. When one rejects, the attempts taken by
+    /// the others are refunded, so a rejected request costs nothing.
+    async fn check(&self, req: &Request) -> Result<Option<Decision>, Decision> {
+        let limiter = self.limiter.clone().unwrap_or_else(RateLimiter::global);
+        let mut tightest: Option<Decision> = None;
+        let mut consumed: Vec<(String, Limit)> = Vec::new();
+
+        for (key, limit) in self.resolve(&limiter, req) {
+            if limit.is_unlimited() {
+                continue;
+            }
+
+            let decision = match limiter.store().hit(&key, &limit).await {
+                Ok(decision) => decision,
+                Err(e) => {
+                    tracing::warn!(error = %e, "rate limit store failed; allowing request");
+                    continue;
+                }
+            };
+
+            if !decision.allowed {
+                for (key, limit) in &consumed {
+                    if let Err(e) = limiter.store().refund(key, limit).await {
+                        tracing::warn!(error = %e, "rate limit store failed to refund an attempt");
+                    }
+                }
+                return Err(decision);
+            }
+            if tightest.is_none_or(|t| decision.remaining < t.remaining) {
+                tightest = Some(decision);
+            }
+            consumed.push((key, limit));
+        }
+
+        Ok(tightest)
+    }
+}
+
+impl Middleware for Throttle {
+    fn handle(&self, req: Request, next: Next) -> ResponseFuture {
+        let this = self.clone();
+
+        Box::pin(async move {
+            match this.check(&req).await {
+                Ok(Some(decision)) => with_limit_headers(next.run(req).await, &decision),
+                Ok(None) => next.run(req).await,
+                Err(decision) => too_many_requests(&decision),
+            }
+        })
+    }
+}
+
+fn with_limit_headers(response: Response, decision: &Decision) -> Response {
+    response
+        .with_header(RATE_LIMIT_LIMIT, decision.limit.to_string())
+        .with_header(RATE_LIMIT_REMAINING, decision.remaining.to_string())
+}
+
+fn too_many_requests(decision: &Decision) -> Response {
+    let reset = SystemTime::now() + decision.retry_after;
+    let reset = reset.duration_since(UNIX_EPOCH).map_or(0, ceil_secs);
+
+    let response = Response::with_body(StatusCode::TOO_MANY_REQUESTS, "Too Many Requests");
+    with_limit_headers(response, decision)
+        .with_header(RETRY_AFTER, ceil_secs(decision.retry_after).to_string())
+        .with_header(RATE_LIMIT_RESET, reset.to_string())
+}
+
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
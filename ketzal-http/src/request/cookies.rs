@@ -0,0 +1,14 @@
+use super::Request;
+use crate::cookie::CookieJar;
+
+impl Request {
+    /// Parses the `Cookie` headers into a jar.
+    pub fn cookies(&self) -> CookieJar {
+        CookieJar::from_headers(&self.headers)
+    }
+
+    /// Raw value of a single cookie.
+    pub fn cookie(&self, name: &str) -> Option<String> {
+        self.cookies().get(name).map(str::to_string)
+    }
+}
@@ -0,0 +1,18 @@
+use super::Request;
+use crate::csrf::TOKEN_FIELD;
+
+impl Request {
+    /// The CSRF token of the current session.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session middleware is not installed on this stack.
+    pub fn csrf_token(&self) -> String {
+        self.session().token()
+    }
+
+    /// A hidden `<input>` carrying the CSRF token, for embedding in forms.
+    pub fn csrf_field(&self) -> String {
+        format!(r#"<input type="hidden" name="{TOKEN_FIELD}" value="{}">"#, self.csrf_token())
+    }
+}
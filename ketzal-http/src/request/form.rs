@@ -1,12 +1,32 @@
+use http::header::CONTENT_TYPE;
+use serde::de::DeserializeOwned;
+
 use super::Request;
-use std::collections::HashMap;
+use crate::constants::FORM_URLENCODED;
+use crate::extract::{self, DeserializeError};
 
 impl Request {
-    fn form(&self) -> HashMap<String, String> {
-        serde_urlencoded::from_bytes::<HashMap<String, String>>(&self.body).unwrap_or_default()
+    /// The input value at `key` as text, or an empty string.
+    ///
+    /// Shorthand for [`Request::string`]; the body is parsed only once.
+    pub fn form_value(&self, key: &str) -> String {
+        self.string(key).unwrap_or_default()
     }
 
-    pub fn form_value(&self, key: &str) -> String {
-        self.form().get(key).cloned().unwrap_or_default()
+    /// Deserializes an `application/x-www-form-urlencoded` body into `T`.
+    ///
+    /// Form values are text; numbers and booleans are parsed from it.
+    pub fn form_as<T: DeserializeOwned>(&self) -> Result<T, DeserializeError> {
+        let is_form = self
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with(FORM_URLENCODED));
+
+        if !is_form {
+            return Err(DeserializeError::UnsupportedMediaType { expected: FORM_URLENCODED });
+        }
+
+        extract::from_form(&self.body)
     }
 }
@@ -1,3 +1,185 @@
-use super::Request;
+use std::collections::HashMap;
 
-impl Request {}
+use serde_json::{Map, Value};
+
+/// Top-level key-value storage the dot-notation helpers work on.
+pub(crate) trait InputMap {
+    fn value(&self, key: &str) -> Option<&Value>;
+    fn value_mut(&mut self, key: &str) -> Option<&mut Value>;
+    fn remove_value(&mut self, key: &str) -> Option<Value>;
+    /// The value at `key`, inserting `null` if absent.
+    fn slot(&mut self, key: &str) -> &mut Value;
+}
+
+impl InputMap for Map<String, Value> {
+    fn value(&self, key: &str) -> Option<&Value> {
+        self.get(key)
+    }
+
+    fn value_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.get_mut(key)
+    }
+
+    fn remove_value(&mut self, key: &str) -> Option<Value> {
+        self.remove(key)
+    }
+
+    fn slot(&mut self, key: &str) -> &mut Value {
+        self.entry(key).or_insert(Value::Null)
+    }
+}
+
+impl InputMap for HashMap<String, Value> {
+    fn value(&self, key: &str) -> Option<&Value> {
+        self.get(key)
+    }
+
+    fn value_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.get_mut(key)
+    }
+
+    fn remove_value(&mut self, key: &str) -> Option<Value> {
+        self.remove(key)
+    }
+
+    fn slot(&mut self, key: &str) -> &mut Value {
+        self.entry(key.to_string()).or_insert(Value::Null)
+    }
+}
+
+/// Looks up a dot-separated `path`. Numeric segments index into arrays.
+///
+/// A top-level key that literally contains dots wins over the nested lookup.
+pub(crate) fn data_get<'a>(data: &'a impl InputMap, path: &str) -> Option<&'a Value> {
+    if let Some(value) = data.value(path) {
+        return Some(value);
+    }
+
+    let mut segments = path.split('.');
+    let mut current = data.value(segments.next()?)?;
+
+    for segment in segments {
+        current = match current {
+            Value::Object(map) => map.get(segment)?,
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+
+    Some(current)
+}
+
+/// Sets `value` at a dot-separated `path`, creating containers on the way.
+pub(crate) fn data_set(data: &mut impl InputMap, path: &str, value: Value) {
+    let segments: Vec<&str> = path.split('.').collect();
+    insert_segments(data, &segments, value);
+}
+
+/// Removes the value at a dot-separated `path`, if present.
+pub(crate) fn data_forget(data: &mut impl InputMap, path: &str) {
+    if data.remove_value(path).is_some() {
+        return;
+    }
+
+    let Some((parent, last)) = path.rsplit_once('.') else {
+        return;
+    };
+
+    let mut segments = parent.split('.');
+    let Some(mut current) = segments.next().and_then(|first| data.value_mut(first)) else {
+        return;
+    };
+
+    for segment in segments {
+        let next = match current {
+            Value::Object(map) => map.get_mut(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get_mut(i)),
+            _ => None,
+        };
+        let Some(next) = next else {
+            return;
+        };
+        current = next;
+    }
+
+    match current {
+        Value::Object(map) => {
+            map.remove(last);
+        }
+        Value::Array(items) => {
+            if let Some(index) = last.parse::<usize>().ok().filter(|i| *i < items.len()) {
+                items.remove(index);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Inserts a form field, expanding PHP-style brackets: `tags[]=a` appends
+/// to an array and `user[name]=b` nests into an object.
+pub(crate) fn insert_form_field(data: &mut impl InputMap, key: &str, value: String) {
+    let segments = bracket_segments(key).unwrap_or_else(|| vec![key]);
+    insert_segments(data, &segments, Value::String(value));
+}
+
+/// `a[b][]` -> `["a", "b", ""]`. `None` if the brackets are malformed.
+fn bracket_segments(key: &str) -> Option<Vec<&str>> {
+    let Some((head, mut rest)) = key.split_once('[') else {
+        return Some(vec![key]);
+    };
+
+    let mut segments = vec![head];
+    loop {
+        let (segment, after) = rest.split_once(']')?;
+        segments.push(segment);
+        rest = match after {
+            "" => return Some(segments),
+            after => after.strip_prefix('[')?,
+        };
+    }
+}
+
+fn insert_segments(data: &mut impl InputMap, segments: &[&str], value: Value) {
+    let Some((first, rest)) = segments.split_first() else {
+        return;
+    };
+
+    insert_into(data.slot(first), rest, value);
+}
+
+fn insert_into(target: &mut Value, segments: &[&str], value: Value) {
+    let Some((segment, rest)) = segments.split_first() else {
+        *target = value;
+        return;
+    };
+
+    let wants_array = segment.is_empty() || segment.parse::<usize>().is_ok();
+    if !target.is_object() && !target.is_array() {
+        *target = if wants_array { Value::Array(Vec::new()) } else { Value::Object(Map::new()) };
+    }
+
+    match target {
+        Value::Array(items) => {
+            let index = match segment.parse::<usize>() {
+                Ok(index) if index <= items.len() => index,
+                Err(_) if segment.is_empty() => items.len(),
+                _ => {
+                    // A named key or a gap turns the list into an object keyed
+                    // by position, as PHP keeps sparse indexes.
+                    let map = items.drain(..).enumerate().map(|(i, v)| (i.to_string(), v));
+                    *target = Value::Object(map.collect());
+                    return insert_into(target, segments, value);
+                }
+            };
+            if index == items.len() {
+                items.push(Value::Null);
+            }
+            insert_into(&mut items[index], rest, value);
+        }
+        Value::Object(map) => {
+            let key = if segment.is_empty() { map.len().to_string() } else { segment.to_string() };
+            insert_into(map.entry(key).or_insert(Value::Null), rest, value);
+        }
+        _ => unreachable!("target was turned into a container above"),
+    }
+}
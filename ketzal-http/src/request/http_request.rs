@@ -1,18 +1,43 @@
-use http::{HeaderMap, Method};
+use http::{Extensions, HeaderMap, Method};
 use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, OnceLock};
+
+use super::input::ParsedInput;
+use crate::multipart::{Multipart, MultipartError};
 
 #[derive(Clone, Debug)]
 pub struct Request {
     pub method: Method,
+    /// Path of the request target, without the query string.
     pub path: String,
     pub query: HashMap<String, String>,
     pub headers: HeaderMap,
     pub body: Vec<u8>,
 
     pub params: HashMap<String, String>,
+
+    /// Address of the TCP peer, set by the server.
+    pub remote_addr: Option<SocketAddr>,
+
+    /// Typed values attached by middleware (e.g. the session).
+    pub extensions: Extensions,
+
+    /// Query and body input, parsed on first use. See [`Request::all`].
+    pub(crate) parsed_input: OnceLock<ParsedInput>,
+
+    /// The raw query string of the target, kept so repeated keys survive.
+    pub(crate) raw_query: Option<String>,
+
+    /// The `multipart/form-data` body, parsed on first use. See
+    /// [`Request::multipart`].
+    pub(crate) parsed_multipart: OnceLock<Result<Multipart, Arc<MultipartError>>>,
 }
 
 impl Request {
+    /// Builds a request. A query string on `path` is split off: `path`
+    /// keeps only the path, and the query is read through
+    /// [`Request::query_string`] and the input accessors.
     pub fn new(
         method: Method,
         path: String,
@@ -21,6 +46,23 @@ impl Request {
         body: Vec<u8>,
         params: HashMap<String, String>,
     ) -> Self {
-        Self { method, path, query, headers, body, params }
+        let (path, raw_query) = match path.split_once('?') {
+            Some((path, query)) => (path.to_string(), Some(query.to_string())),
+            None => (path, None),
+        };
+
+        Self {
+            method,
+            path,
+            query,
+            headers,
+            body,
+            params,
+            remote_addr: None,
+            extensions: Extensions::new(),
+            parsed_input: OnceLock::new(),
+            raw_query,
+            parsed_multipart: OnceLock::new(),
+        }
     }
 }
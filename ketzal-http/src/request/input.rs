@@ -1,3 +1,284 @@
+use http::header::CONTENT_TYPE;
+use http::StatusCode;
+use serde_json::{Map, Value};
+
+use super::helpers::{data_forget, data_get, data_set, insert_form_field};
 use super::Request;
+use crate::constants::FORM_URLENCODED;
+use crate::multipart::MultipartLimits;
+use crate::response::Response;
+
+/// Query and body input, parsed once per request. See [`Request::all`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ParsedInput {
+    data: Map<String, Value>,
+    /// Top-level keys that came from the body.
+    body_keys: Vec<String>,
+    error: Option<&'static str>,
+}
+
+/// Input accessors in the style of Laravel's `$request->input()`.
+///
+/// The input is the query string merged with the body. Body values take
+/// precedence over query values with the same top-level key. The body is
+/// read according to `Content-Type`:
+///
+/// - `application/json`: the JSON object, nested values kept as-is
+/// - `multipart/form-data`: the text fields (files are left to [`Request::multipart`])
+/// - `application/x-www-form-urlencoded`, or no content type: the form fields
+///
+/// Form and query keys use PHP-style brackets, so `tags[]=a&tags[]=b` becomes
+/// an array and `user[name]=b` an object. Keys passed to the accessors use
+/// dot notation (`"user.tags.0"`).
+///
+/// The body is parsed on first access and cached on the request; changes to
+/// `body` after that are not seen. A malformed body yields the query values
+/// only, see [`Request::try_all`].
+impl Request {
+    /// Every input value.
+    pub fn all(&self) -> &Map<String, Value> {
+        &self.parsed_input().data
+    }
+
+    /// Like [`Request::all`], but fails with `400 Bad Request` if the body
+    /// could not be parsed.
+    #[allow(clippy::result_large_err)]
+    pub fn try_all(&self) -> Result<&Map<String, Value>, Response> {
+        let parsed = self.parsed_input();
+        match parsed.error {
+            Some(message) => Err(Response::json_error(StatusCode::BAD_REQUEST, message)),
+            None => Ok(&parsed.data),
+        }
+    }
+
+    /// The value at a dot-notation `key`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // {"user": {"tags": ["admin"]}}
+    /// assert_eq!(req.input("user.tags.0"), Some(&json!("admin")));
+    /// ```
+    pub fn input(&self, key: &str) -> Option<&Value> {
+        data_get(self.all(), key)
+    }
+
+    /// The value at `key` as text. Numbers and booleans are converted.
+    pub fn string(&self, key: &str) -> Option<String> {
+        match self.input(key)? {
+            Value::String(s) => Some(s.clone()),
+            value @ (Value::Number(_) | Value::Bool(_)) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Whether `key` is truthy: `true`, a non-zero number, or one of
+    /// `"1"`, `"true"`, `"on"`, `"yes"` (case-insensitive).
+    pub fn boolean(&self, key: &str) -> bool {
+        match self.input(key) {
+            Some(Value::Bool(b)) => *b,
+            Some(Value::Number(n)) => n.as_f64().is_some_and(|n| n != 0.0),
+            Some(Value::String(s)) => {
+                matches!(s.trim().to_ascii_lowercase().as_str(), "1" | "true" | "on" | "yes")
+            }
+            _ => false,
+        }
+    }
+
+    /// The value at `key` as an integer, parsing strings.
+    pub fn integer(&self, key: &str) -> Option<i64> {
+        match self.input(key)? {
+            Value::Number(n) => n.as_i64(),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// The value at `key` as a float, parsing strings.
+    pub fn float(&self, key: &str) -> Option<f64> {
+        match self.input(key)? {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Whether `key` is present, even if empty or `null`.
+    pub fn has(&self, key: &str) -> bool {
+        self.input(key).is_some()
+    }
+
+    /// Whether any of `keys` is present.
+    pub fn has_any(&self, keys: &[&str]) -> bool {
+        keys.iter().any(|key| self.has(key))
+    }
+
+    pub fn missing(&self, key: &str) -> bool {
+        !self.has(key)
+    }
+
+    /// Whether `key` is present and not empty: not `null`, not a blank
+    /// string and not an empty array or object.
+    pub fn filled(&self, key: &str) -> bool {
+        match self.input(key) {
+            None | Some(Value::Null) => false,
+            Some(Value::String(s)) => !s.trim().is_empty(),
+            Some(Value::Array(items)) => !items.is_empty(),
+            Some(Value::Object(map)) => !map.is_empty(),
+            Some(_) => true,
+        }
+    }
+
+    /// The given keys, nested as in the input. Missing keys are left out.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let credentials = req.only(["email", "password"]);
+    /// ```
+    pub fn only<I, K>(&self, keys: I) -> Map<String, Value>
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let mut out = Map::new();
+        for key in keys {
+            if let Some(value) = self.input(key.as_ref()) {
+                data_set(&mut out, key.as_ref(), value.clone());
+            }
+        }
+        out
+    }
+
+    /// Every input value except the given keys.
+    pub fn except<I, K>(&self, keys: I) -> Map<String, Value>
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let mut out = self.all().clone();
+        for key in keys {
+            data_forget(&mut out, key.as_ref());
+        }
+        out
+    }
+
+    /// Adds values to the input, replacing existing ones. Keys use dot
+    /// notation.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// req.merge([("user.role", json!("member"))]);
+    /// ```
+    pub fn merge<I, K>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = (K, Value)>,
+        K: AsRef<str>,
+    {
+        self.parsed_input();
+        let parsed = self.parsed_input.get_mut().expect("input was just parsed");
+
+        for (key, value) in values {
+            data_set(&mut parsed.data, key.as_ref(), value);
+        }
+    }
+
+    /// The body value at top-level `key` as text, ignoring the query.
+    pub(crate) fn body_string(&self, key: &str) -> Option<String> {
+        let parsed = self.parsed_input();
+        if !parsed.body_keys.iter().any(|k| k == key) {
+            return None;
+        }
+        self.string(key)
+    }
+
+    fn parsed_input(&self) -> &ParsedInput {
+        self.parsed_input.get_or_init(|| self.parse_input())
+    }
+
+    fn parse_input(&self) -> ParsedInput {
+        let mut parsed =
+            ParsedInput { data: self.query_input(), body_keys: Vec::new(), error: None };
+
+        match self.body_input() {
+            Ok(body) => {
+                parsed.body_keys = body.keys().cloned().collect();
+                parsed.data.extend(body);
+            }
+            Err(message) => parsed.error = Some(message),
+        }
+
+        parsed
+    }
+
+    /// Query values, taken from the raw query string so repeated keys
+    /// survive, or from `query` when the target carried none.
+    fn query_input(&self) -> Map<String, Value> {
+        let mut data = Map::new();
+
+        match &self.raw_query {
+            Some(query) => {
+                for (key, value) in form_pairs(query.as_bytes()) {
+                    insert_form_field(&mut data, &key, value);
+                }
+            }
+            None => {
+                for (key, value) in &self.query {
+                    insert_form_field(&mut data, key, value.clone());
+                }
+            }
+        }
+
+        data
+    }
+
+    fn body_input(&self) -> Result<Map<String, Value>, &'static str> {
+        let mut data = Map::new();
+
+        if self.body.is_empty() {
+            return Ok(data);
+        }
+
+        if self.is_json() {
+            return match serde_json::from_slice(&self.body) {
+                Ok(Value::Object(map)) => Ok(map),
+                Ok(_) => Err("JSON body must be an object"),
+                Err(_) => Err("Invalid JSON body"),
+            };
+        }
+
+        if self.is_multipart() {
+            let multipart = self
+                .parsed_multipart(MultipartLimits::default())
+                .as_ref()
+                .map_err(|_| "Invalid multipart body")?;
+
+            for (key, value) in multipart.fields() {
+                insert_form_field(&mut data, key, value.clone());
+            }
+            return Ok(data);
+        }
+
+        let is_form = self
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_none_or(|v| v.starts_with(FORM_URLENCODED));
+
+        if is_form {
+            let pairs = serde_urlencoded::from_bytes::<Vec<(String, String)>>(&self.body)
+                .map_err(|_| "Invalid form body")?;
+            for (key, value) in pairs {
+                insert_form_field(&mut data, &key, value);
+            }
+        }
+
+        Ok(data)
+    }
+}
 
-impl Request {}
+/// Query pairs in order. Undecodable strings yield no pairs.
+fn form_pairs(query: &[u8]) -> Vec<(String, String)> {
+    serde_urlencoded::from_bytes(query).unwrap_or_default()
+}
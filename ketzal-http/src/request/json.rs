@@ -1,8 +1,11 @@
 use super::Request;
 use http::{header::CONTENT_TYPE, StatusCode};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::collections::HashMap;
 
+use crate::constants::APPLICATION_JSON;
+use crate::extract::{self, DeserializeError, JsonLimits};
 use crate::response::Response;
 impl Request {
     pub fn is_json(&self) -> bool {
@@ -49,4 +52,33 @@ impl Request {
 
         Ok(data)
     }
+
+    /// Deserializes the JSON body into `T`.
+    ///
+    /// Uses the [`JsonLimits`] in the request extensions, or the defaults.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let user: CreateUser = match req.json_as() {
+    ///     Ok(user) => user,
+    ///     Err(e) => return e.into_response(),
+    /// };
+    /// ```
+    pub fn json_as<T: DeserializeOwned>(&self) -> Result<T, DeserializeError> {
+        let limits = self.extensions.get::<JsonLimits>().copied().unwrap_or_default();
+        self.json_as_with(limits)
+    }
+
+    /// Like [`Request::json_as`], with explicit limits.
+    pub fn json_as_with<T: DeserializeOwned>(
+        &self,
+        limits: JsonLimits,
+    ) -> Result<T, DeserializeError> {
+        if !self.is_json() {
+            return Err(DeserializeError::UnsupportedMediaType { expected: APPLICATION_JSON });
+        }
+
+        extract::from_json(&self.body, limits)
+    }
 }
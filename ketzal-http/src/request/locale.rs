@@ -0,0 +1,30 @@
+use http::header::ACCEPT_LANGUAGE;
+use ketzal_validation::messages;
+
+use super::Request;
+
+impl Request {
+    /// The locale of validation messages for this request: the first
+    /// language of `Accept-Language`, by quality, with a registered catalog.
+    ///
+    /// `fr-CA` is served by a `fr` catalog. Without such a language the
+    /// messages stay in English.
+    pub fn preferred_locale(&self) -> Option<String> {
+        let header = self.headers.get(ACCEPT_LANGUAGE)?.to_str().ok()?;
+
+        let mut languages: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';').map(str::trim);
+                let tag = parts.next().filter(|tag| !tag.is_empty() && *tag != "*")?;
+                let quality = parts
+                    .find_map(|part| part.strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.parse::<f32>().ok())?;
+                (quality > 0.0).then_some((tag, quality))
+            })
+            .collect();
+        languages.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        languages.into_iter().find_map(|(tag, _)| messages::resolve_locale(tag))
+    }
+}
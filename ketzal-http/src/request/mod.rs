@@ -3,9 +3,14 @@ pub use http_request::Request;
 
 pub mod validated_data;
 
+mod cookies;
+mod csrf;
 mod form;
 mod helpers;
 mod input;
 mod json;
+mod locale;
+mod multipart;
 mod network;
+mod session;
 mod validate;
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use http::{header::CONTENT_TYPE, StatusCode};
+
+use super::Request;
+use crate::constants::MULTIPART_FORM_DATA;
+use crate::multipart::{Multipart, MultipartError, MultipartLimits};
+use crate::response::Response;
+
+impl Request {
+    pub fn is_multipart(&self) -> bool {
+        self.headers
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.starts_with(MULTIPART_FORM_DATA))
+            .unwrap_or(false)
+    }
+
+    /// The parsed `multipart/form-data` body, with the default limits.
+    #[allow(clippy::result_large_err)]
+    pub fn multipart(&self) -> Result<Multipart, Response> {
+        self.multipart_with(MultipartLimits::default())
+    }
+
+    /// The parsed `multipart/form-data` body.
+    ///
+    /// The body is parsed once per request, with the limits of the first
+    /// call; later calls, including the ones made by [`Request::all`],
+    /// `CsrfMiddleware` and [`Request::validate_multipart`], share the
+    /// result. Uploaded files are cheap to clone.
+    #[allow(clippy::result_large_err)]
+    pub fn multipart_with(&self, limits: MultipartLimits) -> Result<Multipart, Response> {
+        if !self.is_multipart() {
+            return Err(Response::json_error(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "Content-Type must be multipart/form-data",
+            ));
+        }
+
+        match self.parsed_multipart(limits) {
+            Ok(multipart) => Ok(multipart.clone()),
+            Err(e) => Err(Response::json_error(e.status(), e.to_string())),
+        }
+    }
+
+    pub(crate) fn parsed_multipart(
+        &self,
+        limits: MultipartLimits,
+    ) -> &Result<Multipart, Arc<MultipartError>> {
+        self.parsed_multipart.get_or_init(|| {
+            let content_type =
+                self.headers.get(CONTENT_TYPE).and_then(|v| v.to_str().ok()).unwrap_or_default();
+
+            Multipart::parse(content_type, &self.body, limits).map_err(Arc::new)
+        })
+    }
+}
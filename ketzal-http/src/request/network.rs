@@ -1,3 +1,154 @@
+use std::net::{IpAddr, SocketAddr};
+
+use http::header::HOST;
+
 use super::Request;
+use crate::network::{parse_forwarded, TrustProxies};
+
+impl Request {
+    /// Address of the TCP peer, or of the client announced through the
+    /// PROXY protocol when the listener expects it.
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        self.remote_addr
+    }
+
+    /// IP address of the client, following `Forwarded`/`X-Forwarded-For`
+    /// through trusted proxies.
+    pub fn ip(&self) -> Option<IpAddr> {
+        let peer = self.peer_addr()?.ip();
+        Some(match self.extensions.get::<TrustProxies>() {
+            Some(proxies) => proxies.client_ip(self, peer),
+            None => peer,
+        })
+    }
+
+    /// `https` or `http`, as seen by the client.
+    pub fn scheme(&self) -> &'static str {
+        let proto = self.forwarded_proto();
+        match proto.as_deref() {
+            Some("https") => "https",
+            _ => "http",
+        }
+    }
+
+    pub fn is_secure(&self) -> bool {
+        self.scheme() == "https"
+    }
+
+    /// Host name the client connected to, without port.
+    pub fn host(&self) -> Option<String> {
+        let raw = self.raw_host()?;
+        let (host, _) = split_host_port(&raw);
+        Some(host.to_ascii_lowercase())
+    }
+
+    /// Port the client connected to, defaulting to the scheme's port.
+    pub fn port(&self) -> u16 {
+        let forwarded_port = self
+            .trusted_proxy()
+            .and_then(|proxies| self.forwarded_entry(proxies, "x-forwarded-port"))
+            .and_then(|p| p.parse().ok());
+
+        forwarded_port
+            .or_else(|| self.raw_host().and_then(|h| split_host_port(&h).1))
+            .unwrap_or(if self.is_secure() { 443 } else { 80 })
+    }
+
+    /// URL of the request without the query string.
+    pub fn url(&self) -> String {
+        format!("{}{}", self.origin(), self.path)
+    }
+
+    /// The raw query string of the request target, without the `?`.
+    pub fn query_string(&self) -> Option<&str> {
+        self.raw_query.as_deref()
+    }
+
+    /// URL of the request including the query string.
+    pub fn full_url(&self) -> String {
+        let mut url = self.url();
+
+        if let Some(query) = self.query_string() {
+            url.push('?');
+            url.push_str(query);
+        } else if !self.query.is_empty() {
+            let mut pairs: Vec<_> = self.query.iter().collect();
+            pairs.sort();
+            if let Ok(query) = serde_urlencoded::to_string(pairs) {
+                url.push('?');
+                url.push_str(&query);
+            }
+        }
+
+        url
+    }
+
+    /// `scheme://host[:port]`, omitting the scheme's default port.
+    fn origin(&self) -> String {
+        let scheme = self.scheme();
+        let host = self.host().unwrap_or_else(|| "localhost".to_string());
+        let port = self.port();
+
+        match (scheme, port) {
+            ("http", 80) | ("https", 443) => format!("{scheme}://{host}"),
+            _ => format!("{scheme}://{host}:{port}"),
+        }
+    }
+
+    /// The proxy configuration, if the peer is one of the trusted proxies.
+    fn trusted_proxy(&self) -> Option<&TrustProxies> {
+        let proxies = self.extensions.get::<TrustProxies>()?;
+        proxies.is_trusted(self.peer_addr()?.ip()).then_some(proxies)
+    }
+
+    fn forwarded_proto(&self) -> Option<String> {
+        let proxies = self.trusted_proxy()?;
+        if let Some(header) = self.header_str("forwarded") {
+            return proxies.outermost(self, parse_forwarded(header))?.proto;
+        }
+        self.forwarded_entry(proxies, "x-forwarded-proto").map(|p| p.to_ascii_lowercase())
+    }
+
+    /// `Host`, or the forwarded host when behind a trusted proxy.
+    fn raw_host(&self) -> Option<String> {
+        if let Some(proxies) = self.trusted_proxy() {
+            let forwarded = match self.header_str("forwarded") {
+                Some(header) => {
+                    proxies.outermost(self, parse_forwarded(header)).and_then(|e| e.host)
+                }
+                None => self.forwarded_entry(proxies, "x-forwarded-host").map(str::to_string),
+            };
+            if forwarded.is_some() {
+                return forwarded;
+            }
+        }
+
+        self.header_str(HOST.as_str()).map(str::to_string)
+    }
+
+    /// The entry of a comma-separated `X-Forwarded-*` header added by the
+    /// outermost trusted proxy.
+    fn forwarded_entry(&self, proxies: &TrustProxies, name: &str) -> Option<&str> {
+        let entries = self.header_str(name)?.split(',').map(str::trim).collect();
+        proxies.outermost(self, entries).filter(|entry| !entry.is_empty())
+    }
+
+    fn header_str(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok()).filter(|v| !v.trim().is_empty())
+    }
+}
 
-impl Request {}
+/// Splits `example.com:8080` or `[::1]:8080` into host and port.
+fn split_host_port(host: &str) -> (&str, Option<u16>) {
+    let host = host.trim();
+    if host.starts_with('[') {
+        return match host.split_once("]:") {
+            Some((ip, port)) => (&host[..ip.len() + 1], port.parse().ok()),
+            None => (host, None),
+        };
+    }
+    match host.rsplit_once(':') {
+        Some((name, port)) if !name.contains(':') => (name, port.parse().ok()),
+        _ => (host, None),
+    }
+}
@@ -0,0 +1,20 @@
+use super::Request;
+use crate::session::Session;
+
+impl Request {
+    /// The session attached by [`SessionMiddleware`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session middleware is not installed on this stack.
+    ///
+    /// [`SessionMiddleware`]: crate::session::SessionMiddleware
+    pub fn session(&self) -> Session {
+        self.try_session().expect("no session on request; is SessionMiddleware registered?")
+    }
+
+    /// The session, or `None` when the session middleware is not installed.
+    pub fn try_session(&self) -> Option<Session> {
+        self.extensions.get::<Session>().cloned()
+    }
+}
@@ -4,7 +4,7 @@ use std::ops::ControlFlow;
 use http::{header::CONTENT_TYPE, StatusCode};
 use serde_json::Value;
 
-use ketzal_validation::Validator;
+use ketzal_validation::{FileMeta, Validator};
 
 use crate::request::validated_data::ValidatedData;
 use crate::request::Request;
@@ -20,7 +20,7 @@ impl Request {
             Err(resp) => return ControlFlow::Break(resp),
         };
 
-        self.run_validation(data, rules)
+        self.run_validation(data, HashMap::new(), rules)
     }
 
     pub fn validate_form<const N: usize>(
@@ -32,17 +32,36 @@ impl Request {
             Err(resp) => return ControlFlow::Break(resp),
         };
 
-        self.run_validation(data, rules)
+        self.run_validation(data, HashMap::new(), rules)
+    }
+
+    pub fn validate_multipart<const N: usize>(
+        &self,
+        rules: [(&'static str, &'static str); N],
+    ) -> ControlFlow<Response, ValidatedData> {
+        let multipart = match self.multipart() {
+            Ok(multipart) => multipart,
+            Err(resp) => return ControlFlow::Break(resp),
+        };
+
+        let files = multipart.validation_files();
+        self.run_validation(multipart.to_validation_data(), files, rules)
+            .map_continue(|validated| validated.with_files(multipart.files_by_field()))
     }
 
     fn run_validation<const N: usize>(
         &self,
         data: HashMap<String, Value>,
+        files: HashMap<String, FileMeta>,
         rules: [(&'static str, &'static str); N],
     ) -> ControlFlow<Response, ValidatedData> {
         let rules_map: HashMap<&str, &str> = rules.iter().cloned().collect();
 
         let mut validator = Validator::make(data, rules_map);
+        validator.set_files(files);
+        if let Some(locale) = self.preferred_locale() {
+            validator.set_locale(&locale);
+        }
 
         match validator.validate() {
             Ok(()) => ControlFlow::Continue(ValidatedData::new(validator.validated_data())),
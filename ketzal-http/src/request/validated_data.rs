@@ -1,34 +1,105 @@
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 
+use super::helpers::{data_forget, data_get, data_set};
+use crate::extract::{self, DeserializeError};
+use crate::multipart::UploadedFile;
+
+/// Input that passed validation.
+///
+/// Keys given to [`get`](Self::get), [`only`](Self::only) and
+/// [`except`](Self::except) use dot notation (`"user.address.city"`).
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ValidatedData {
     data: HashMap<String, Value>,
+    #[serde(skip)]
+    files: HashMap<String, Vec<UploadedFile>>,
 }
 
 impl ValidatedData {
     pub fn new(data: HashMap<String, Value>) -> Self {
-        Self { data }
+        Self { data, files: HashMap::new() }
+    }
+
+    /// Attaches the uploads of the validated fields.
+    pub fn with_files(mut self, files: HashMap<String, Vec<UploadedFile>>) -> Self {
+        self.files = files.into_iter().filter(|(k, _)| self.data.contains_key(k)).collect();
+        self
     }
 
     pub fn all(self) -> HashMap<String, Value> {
         self.data
     }
 
-    pub fn only<const N: usize>(self, keys: [&str; N]) -> Self {
-        let filtered = self.data.into_iter().filter(|(k, _)| keys.contains(&k.as_str())).collect();
+    /// Keeps only `keys`, which may be an array, a slice or a `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let profile = validated.only(["name", "address.city"]);
+    /// ```
+    pub fn only<I, K>(self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let keys: Vec<K> = keys.into_iter().collect();
+        let mut data = HashMap::new();
 
-        Self { data: filtered }
+        for key in &keys {
+            if let Some(value) = data_get(&self.data, key.as_ref()) {
+                data_set(&mut data, key.as_ref(), value.clone());
+            }
+        }
+
+        let files =
+            self.files.into_iter().filter(|(k, _)| keys.iter().any(|key| key.as_ref() == k));
+
+        Self { data, files: files.collect() }
     }
 
-    pub fn except<const N: usize>(self, keys: [&str; N]) -> Self {
-        let filtered = self.data.into_iter().filter(|(k, _)| !keys.contains(&k.as_str())).collect();
+    /// Drops `keys`, which may be an array, a slice or a `Vec`.
+    pub fn except<I, K>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        for key in keys {
+            data_forget(&mut self.data, key.as_ref());
+            self.files.remove(key.as_ref());
+        }
 
-        Self { data: filtered }
+        self
     }
 
     pub fn get(&self, key: &str) -> Option<&Value> {
-        self.data.get(key)
+        data_get(&self.data, key)
+    }
+
+    /// Deserializes the validated data into `T`.
+    ///
+    /// Values keep the type they had in the input: form and multipart fields
+    /// are text, so numeric fields of `T` should come from a JSON body.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let user: CreateUser = validated.into_struct()?;
+    /// ```
+    pub fn into_struct<T: DeserializeOwned>(self) -> Result<T, DeserializeError> {
+        let object: Map<String, Value> = self.data.into_iter().collect();
+        extract::from_value(Value::Object(object))
+    }
+
+    /// First uploaded file of a validated field.
+    pub fn file(&self, key: &str) -> Option<&UploadedFile> {
+        self.files.get(key).and_then(|files| files.first())
+    }
+
+    /// All uploaded files of a validated field.
+    pub fn files(&self, key: &str) -> &[UploadedFile] {
+        self.files.get(key).map(Vec::as_slice).unwrap_or_default()
     }
 }
@@ -0,0 +1,37 @@
+use std::io::{self, SeekFrom};
+use std::path::PathBuf;
+
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWrite};
+
+/// A response body that is streamed from disk instead of held in memory.
+#[derive(Clone, Debug)]
+pub struct FileBody {
+    pub path: PathBuf,
+    pub offset: u64,
+    pub len: u64,
+}
+
+impl FileBody {
+    pub fn new(path: impl Into<PathBuf>, offset: u64, len: u64) -> Self {
+        Self { path: path.into(), offset, len }
+    }
+
+    /// Copies the selected byte range of the file to `writer`.
+    pub async fn write_to<W>(&self, writer: &mut W) -> io::Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut file = tokio::fs::File::open(&self.path).await?;
+        file.seek(SeekFrom::Start(self.offset)).await?;
+
+        let mut range = file.take(self.len);
+        tokio::io::copy(&mut range, writer).await
+    }
+
+    /// Reads the selected byte range into memory.
+    pub async fn read(&self) -> io::Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        self.write_to(&mut buf).await?;
+        Ok(buf)
+    }
+}
@@ -1,15 +1,22 @@
+use http::header::{IntoHeaderName, CONTENT_LENGTH, SET_COOKIE};
 use http::{HeaderMap, HeaderValue, StatusCode};
 
+use super::FileBody;
+use crate::constants::status_code;
+use crate::cookie::{Cookie, CookieJar};
+
 #[derive(Clone, Debug)]
 pub struct Response {
     pub status: StatusCode,
     pub headers: HeaderMap,
     pub body: Vec<u8>,
+    /// Body streamed from disk after the in-memory `body`, if any.
+    pub file: Option<FileBody>,
 }
 
 impl Response {
     pub fn new(status: StatusCode) -> Self {
-        Self { status, headers: HeaderMap::new(), body: Vec::new() }
+        Self { status, headers: HeaderMap::new(), body: Vec::new(), file: None }
     }
 
     pub fn with_body(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
@@ -30,16 +37,66 @@ impl Response {
         Self::with_body(StatusCode::BAD_REQUEST, body)
     }
 
+    /// Creates a response whose body is streamed from `file`.
+    pub fn with_file(status: StatusCode, file: FileBody) -> Self {
+        let mut res = Self::new(status);
+        res.file = Some(file);
+        res
+    }
+
     pub fn header(mut self, key: &'static str, value: &'static str) -> Self {
         self.headers.insert(key, HeaderValue::from_static(value));
         self
     }
-    pub fn to_http_string(&self) -> String {
-        let reason = self.status.canonical_reason().unwrap_or("Unknown");
+
+    /// Sets a header from a runtime value. Values that are not valid header
+    /// text (e.g. containing newlines) are dropped with a warning.
+    pub fn with_header<K: IntoHeaderName>(mut self, key: K, value: impl AsRef<str>) -> Self {
+        match HeaderValue::from_str(value.as_ref()) {
+            Ok(value) => {
+                self.headers.insert(key, value);
+            }
+            Err(_) => tracing::warn!("dropping invalid header value"),
+        }
+        self
+    }
+
+    /// Appends a `Set-Cookie` header.
+    pub fn cookie(mut self, cookie: &Cookie) -> Self {
+        match HeaderValue::from_str(&cookie.to_header_value()) {
+            Ok(value) => {
+                self.headers.append(SET_COOKIE, value);
+            }
+            Err(_) => tracing::warn!(cookie = cookie.name(), "dropping invalid cookie"),
+        }
+        self
+    }
+
+    /// Appends a `Set-Cookie` header for every change queued in `jar`.
+    pub fn with_cookies(self, jar: &CookieJar) -> Self {
+        jar.delta().iter().fold(self, Self::cookie)
+    }
+
+    /// Length of the body as sent on the wire.
+    pub fn content_length(&self) -> u64 {
+        self.body.len() as u64 + self.file.as_ref().map_or(0, |f| f.len)
+    }
+
+    /// Status line and headers, terminated by the blank line.
+    ///
+    /// `Content-Length` is computed from the body unless a handler set it
+    /// explicitly (e.g. for `HEAD` responses).
+    pub fn head_string(&self) -> String {
+        let reason = self
+            .status
+            .canonical_reason()
+            .unwrap_or_else(|| status_code::reason_phrase(self.status.as_u16()));
 
         let mut response = format!("HTTP/1.1 {} {}\r\n", self.status.as_u16(), reason);
 
-        response.push_str(&format!("Content-Length: {}\r\n", self.body.len()));
+        if !self.headers.contains_key(CONTENT_LENGTH) {
+            response.push_str(&format!("Content-Length: {}\r\n", self.content_length()));
+        }
 
         for (key, value) in self.headers.iter() {
             if let Ok(value_str) = value.to_str() {
@@ -48,6 +105,11 @@ impl Response {
         }
 
         response.push_str("\r\n");
+        response
+    }
+
+    pub fn to_http_string(&self) -> String {
+        let mut response = self.head_string();
 
         response.push_str(&String::from_utf8_lossy(&self.body));
 
@@ -55,7 +117,12 @@ impl Response {
     }
 
     /// Convert response to HTTP bytes
+    ///
+    /// The body is copied verbatim, so binary payloads survive. A
+    /// [`FileBody`] is not included and must be written separately.
     pub fn to_bytes(&self) -> Vec<u8> {
-        self.to_http_string().into_bytes()
+        let mut bytes = self.head_string().into_bytes();
+        bytes.extend_from_slice(&self.body);
+        bytes
     }
 }
@@ -1,8 +1,10 @@
 use http::{HeaderValue, StatusCode};
+use ketzal_validation::ValidationErrors;
 use serde::Serialize;
 use std::collections::HashMap;
 
 use super::Response;
+use crate::config::ErrorFormat;
 
 impl Response {
     pub fn json<T: Serialize>(value: T) -> Self {
@@ -28,7 +30,26 @@ impl Response {
         Self::json_with_status(status, map)
     }
 
-    pub fn json_validation_error<T: Serialize>(errors: T) -> Self {
-        Self::json_with_status(StatusCode::UNPROCESSABLE_ENTITY, errors)
+    /// A `422` response with `errors` in the global [`ErrorFormat`].
+    pub fn json_validation_error(errors: ValidationErrors) -> Self {
+        Self::json_validation_error_as(ErrorFormat::global(), &errors)
+    }
+
+    /// A `422` response with `errors` in `format`.
+    pub fn json_validation_error_as(format: ErrorFormat, errors: &ValidationErrors) -> Self {
+        match format {
+            ErrorFormat::Laravel => {
+                Self::json_with_status(StatusCode::UNPROCESSABLE_ENTITY, errors.to_laravel())
+            }
+            ErrorFormat::Problem => {
+                let mut response =
+                    Self::json_with_status(StatusCode::UNPROCESSABLE_ENTITY, errors.to_problem());
+                response.headers.insert(
+                    http::header::CONTENT_TYPE,
+                    HeaderValue::from_static("application/problem+json"),
+                );
+                response
+            }
+        }
     }
 }
@@ -1,4 +1,6 @@
+pub mod file_body;
 pub mod http_response;
+pub use file_body::FileBody;
 pub use http_response::Response;
 
 mod json;
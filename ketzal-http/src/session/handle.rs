@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{random_token, SessionRecord, TOKEN_KEY};
+
+/// The session of the current request.
+///
+/// Cloning is cheap and every clone refers to the same session, so a handler
+/// can keep one around while middleware persists it afterwards.
+#[derive(Clone, Debug)]
+pub struct Session {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    record: SessionRecord,
+    dirty: bool,
+    is_new: bool,
+    regenerated: bool,
+    regenerated_from: Option<String>,
+}
+
+/// What the middleware needs to persist a session.
+pub(crate) struct Finished {
+    pub record: SessionRecord,
+    pub dirty: bool,
+    pub is_new: bool,
+    pub regenerated: bool,
+    pub regenerated_from: Option<String>,
+}
+
+impl Session {
+    /// Starts a fresh, empty session with a random id.
+    pub fn start() -> Self {
+        Self::from_state(SessionRecord::new(random_token()), true)
+    }
+
+    /// Resumes a session loaded from a store.
+    pub fn resume(record: SessionRecord) -> Self {
+        Self::from_state(record, false)
+    }
+
+    fn from_state(record: SessionRecord, is_new: bool) -> Self {
+        let state =
+            State { record, dirty: false, is_new, regenerated: false, regenerated_from: None };
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn id(&self) -> String {
+        self.lock().record.id.clone()
+    }
+
+    /// Whether the session was created during this request.
+    pub fn is_new(&self) -> bool {
+        self.lock().is_new
+    }
+
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let state = self.lock();
+        let value = state.record.data.get(key)?;
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    pub fn has(&self, key: &str) -> bool {
+        self.lock().record.data.contains_key(key)
+    }
+
+    /// A copy of all session data.
+    pub fn all(&self) -> HashMap<String, Value> {
+        self.lock().record.data.clone()
+    }
+
+    /// Stores a value. Values that cannot be serialized are ignored.
+    pub fn put(&self, key: impl Into<String>, value: impl Serialize) {
+        let Ok(value) = serde_json::to_value(value) else {
+            tracing::warn!("dropping session value that cannot be serialized");
+            return;
+        };
+        let mut state = self.lock();
+        state.record.data.insert(key.into(), value);
+        state.dirty = true;
+    }
+
+    pub fn forget(&self, key: &str) {
+        let mut state = self.lock();
+        if state.record.data.remove(key).is_some() {
+            state.dirty = true;
+        }
+    }
+
+    /// Returns a value and removes it from the session.
+    pub fn pull<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.get(key);
+        self.forget(key);
+        value
+    }
+
+    /// Stores a value for the current and the next request only.
+    pub fn flash(&self, key: impl Into<String>, value: impl Serialize) {
+        let key = key.into();
+        self.put(key.clone(), value);
+
+        let mut state = self.lock();
+        state.record.flash_old.retain(|k| *k != key);
+        if !state.record.flash_new.contains(&key) {
+            state.record.flash_new.push(key);
+        }
+    }
+
+    /// Keeps the flash data of the previous request for one more request.
+    pub fn reflash(&self) {
+        let mut state = self.lock();
+        let old = std::mem::take(&mut state.record.flash_old);
+        for key in old {
+            if !state.record.flash_new.contains(&key) {
+                state.record.flash_new.push(key);
+            }
+        }
+        state.dirty = true;
+    }
+
+    /// The CSRF token of this session, created on first use.
+    pub fn token(&self) -> String {
+        if let Some(token) = self.get::<String>(TOKEN_KEY) {
+            return token;
+        }
+        self.regenerate_token()
+    }
+
+    /// Replaces the CSRF token, e.g. after login or logout.
+    pub fn regenerate_token(&self) -> String {
+        let token = random_token();
+        self.put(TOKEN_KEY, &token);
+        token
+    }
+
+    /// Removes all data, keeping the session id.
+    pub fn flush(&self) {
+        let mut state = self.lock();
+        state.record.data.clear();
+        state.record.flash_new.clear();
+        state.record.flash_old.clear();
+        state.dirty = true;
+    }
+
+    /// Moves the data to a new session id and discards the old one.
+    ///
+    /// Call this whenever the privilege level changes, e.g. on login, so an
+    /// id planted by an attacker before authentication becomes useless
+    /// (session fixation).
+    pub fn regenerate(&self) {
+        let mut state = self.lock();
+        let old = std::mem::replace(&mut state.record.id, random_token());
+        if !state.is_new && state.regenerated_from.is_none() {
+            state.regenerated_from = Some(old);
+        }
+        state.regenerated = true;
+        state.dirty = true;
+    }
+
+    /// Removes all data and moves to a new id, e.g. on logout.
+    pub fn invalidate(&self) {
+        self.flush();
+        self.regenerate();
+    }
+
+    /// Ages flash data and hands the state over for persistence.
+    pub(crate) fn finish(&self) -> Finished {
+        let mut state = self.lock();
+        let record = &mut state.record;
+
+        let aged = !record.flash_old.is_empty() || !record.flash_new.is_empty();
+        for key in std::mem::take(&mut record.flash_old) {
+            if !record.flash_new.contains(&key) {
+                record.data.remove(&key);
+            }
+        }
+        record.flash_old = std::mem::take(&mut record.flash_new);
+
+        Finished {
+            record: record.clone(),
+            dirty: state.dirty || aged,
+            is_new: state.is_new,
+            regenerated: state.regenerated,
+            regenerated_from: state.regenerated_from.take(),
+        }
+    }
+}
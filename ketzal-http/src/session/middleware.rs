@@ -0,0 +1,195 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::handle::Finished;
+use super::{Session, SessionStore, SESSION_COOKIE};
+use crate::cookie::{Cookie, CookieJar, Keyring, SameSite};
+use crate::middleware::{Middleware, Next, ResponseFuture};
+use crate::{Request, Response};
+
+/// Default session lifetime: two hours.
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(2 * 60 * 60);
+
+/// Loads and persists the [`Session`] of every request.
+///
+/// The session cookie is encrypted with the given [`Keyring`], `HttpOnly`
+/// and `SameSite=Lax` by default.
+///
+/// # Example
+///
+/// ```ignore
+/// let sessions = SessionMiddleware::new(MemoryStore::new(), keys)
+///     .cookie_name("app_session")
+///     .lifetime(Duration::from_secs(30 * 60))
+///     .rolling(false)
+///     .secure(true);
+/// ```
+#[derive(Clone)]
+pub struct SessionMiddleware {
+    store: Arc<dyn SessionStore>,
+    config: Arc<Config>,
+}
+
+#[derive(Clone)]
+struct Config {
+    keys: Keyring,
+    cookie_name: String,
+    lifetime: Duration,
+    rolling: bool,
+    expire_on_close: bool,
+    path: String,
+    domain: Option<String>,
+    secure: bool,
+    same_site: SameSite,
+}
+
+impl SessionMiddleware {
+    pub fn new(store: impl SessionStore, keys: impl Into<Keyring>) -> Self {
+        let config = Config {
+            keys: keys.into(),
+            cookie_name: SESSION_COOKIE.to_string(),
+            lifetime: DEFAULT_LIFETIME,
+            rolling: true,
+            expire_on_close: false,
+            path: "/".to_string(),
+            domain: None,
+            secure: false,
+            same_site: SameSite::Lax,
+        };
+        Self { store: Arc::new(store), config: Arc::new(config) }
+    }
+
+    fn config(&mut self) -> &mut Config {
+        Arc::make_mut(&mut self.config)
+    }
+
+    pub fn cookie_name(mut self, name: impl Into<String>) -> Self {
+        self.config().cookie_name = name.into();
+        self
+    }
+
+    /// How long a session lives without being used (or at all, if not rolling).
+    pub fn lifetime(mut self, lifetime: Duration) -> Self {
+        self.config().lifetime = lifetime;
+        self
+    }
+
+    /// Whether every request extends the session by its full lifetime.
+    /// When disabled, the expiry is fixed when the session starts or is
+    /// regenerated.
+    pub fn rolling(mut self, rolling: bool) -> Self {
+        self.config().rolling = rolling;
+        self
+    }
+
+    /// Sends the cookie without `Max-Age`, so it ends with the browser session.
+    pub fn expire_on_close(mut self, enabled: bool) -> Self {
+        self.config().expire_on_close = enabled;
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.config().path = path.into();
+        self
+    }
+
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.config().domain = Some(domain.into());
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.config().secure = secure;
+        self
+    }
+
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.config().same_site = same_site;
+        self
+    }
+
+    async fn load(&self, req: &Request, now: SystemTime) -> Session {
+        let mut jar = CookieJar::from_headers(&req.headers);
+        let Some(handle) = jar.encrypted(&self.config.keys).get(&self.config.cookie_name) else {
+            return Session::start();
+        };
+
+        match self.store.load(&handle).await {
+            Ok(Some(record)) if !record.is_expired(now) => Session::resume(record),
+            Ok(Some(record)) => {
+                if let Err(e) = self.store.destroy(&record.id).await {
+                    tracing::warn!(error = %e, "failed to destroy expired session");
+                }
+                Session::start()
+            }
+            Ok(None) => Session::start(),
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to load session");
+                Session::start()
+            }
+        }
+    }
+
+    async fn persist(&self, session: &Session, response: Response, now: SystemTime) -> Response {
+        let Finished { mut record, dirty, is_new, regenerated, regenerated_from } =
+            session.finish();
+        let config = &self.config;
+
+        if let Some(old) = regenerated_from {
+            if let Err(e) = self.store.destroy(&old).await {
+                tracing::warn!(error = %e, "failed to destroy regenerated session");
+            }
+        }
+
+        // Untouched new sessions are not worth storing; untouched existing
+        // ones only need a write when every request extends them.
+        if !dirty && (is_new || !config.rolling) {
+            return response;
+        }
+
+        if config.rolling || is_new || regenerated {
+            record.set_expires_at(now + config.lifetime);
+        }
+
+        let handle = match self.store.save(&record).await {
+            Ok(handle) => handle,
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to save session");
+                return response;
+            }
+        };
+
+        let mut cookie = Cookie::new(&config.cookie_name, handle)
+            .path(&config.path)
+            .http_only(true)
+            .secure(config.secure)
+            .same_site(config.same_site);
+        if let Some(domain) = &config.domain {
+            cookie = cookie.domain(domain);
+        }
+        if !config.expire_on_close {
+            let now_secs = now.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+            cookie =
+                cookie.max_age(Duration::from_secs(record.expires_at.saturating_sub(now_secs)));
+        }
+
+        let mut jar = CookieJar::new();
+        jar.encrypted(&config.keys).add(cookie);
+        response.with_cookies(&jar)
+    }
+}
+
+impl Middleware for SessionMiddleware {
+    fn handle(&self, mut req: Request, next: Next) -> ResponseFuture {
+        let this = self.clone();
+
+        Box::pin(async move {
+            let now = SystemTime::now();
+            let session = this.load(&req, now).await;
+            req.extensions.insert(session.clone());
+
+            let response = next.run(req).await;
+            this.persist(&session, response, now).await
+        })
+    }
+}
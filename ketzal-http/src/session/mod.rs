@@ -0,0 +1,52 @@
+//! Server-side sessions with pluggable storage drivers.
+//!
+//! [`SessionMiddleware`] loads the session named by an encrypted cookie,
+//! exposes it to handlers through [`Request::session`](crate::Request::session)
+//! and persists it after the handler ran. Storage is abstracted by
+//! [`SessionStore`]; memory, file and cookie drivers are provided.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let keys = Keyring::new(Key::from_app_key(&env::var("APP_KEY")?));
+//! let sessions = SessionMiddleware::new(FileStore::new("storage/sessions"), keys)
+//!     .lifetime(Duration::from_secs(2 * 60 * 60));
+//!
+//! register_web_middleware(sessions);
+//!
+//! async fn login(req: Request) -> Response {
+//!     let session = req.session();
+//!     session.regenerate();
+//!     session.put("user_id", 42);
+//!     session.flash("status", "Welcome back!");
+//!     Response::ok("")
+//! }
+//! ```
+
+mod handle;
+mod middleware;
+mod record;
+mod stores;
+
+pub use handle::Session;
+pub use middleware::SessionMiddleware;
+pub use record::SessionRecord;
+pub use stores::{CookieStore, FileStore, MemoryStore, SessionStore, StoreFuture};
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::OsRng;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+/// Default name of the session cookie.
+pub const SESSION_COOKIE: &str = "ketzal_session";
+
+/// Session key holding the CSRF token.
+pub const TOKEN_KEY: &str = "_token";
+
+/// A random, URL-safe identifier with 256 bits of entropy.
+pub(crate) fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The persisted form of a session, as handed to a [`SessionStore`].
+///
+/// [`SessionStore`]: super::SessionStore
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub id: String,
+    pub data: HashMap<String, Value>,
+    /// Keys flashed during this request, readable during the next one.
+    #[serde(default)]
+    pub flash_new: Vec<String>,
+    /// Keys flashed during the previous request, removed after this one.
+    #[serde(default)]
+    pub flash_old: Vec<String>,
+    /// Expiry as seconds since the Unix epoch.
+    pub expires_at: u64,
+}
+
+impl SessionRecord {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into(), ..Self::default() }
+    }
+
+    pub fn expires_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.expires_at)
+    }
+
+    pub fn set_expires_at(&mut self, at: SystemTime) {
+        self.expires_at = at.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    }
+
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires_at() <= now
+    }
+}
@@ -0,0 +1,41 @@
+use super::{SessionStore, StoreFuture};
+use crate::session::SessionRecord;
+
+/// Browsers commonly reject cookies larger than this.
+const MAX_COOKIE_BYTES: usize = 4096;
+
+/// Keeps the whole session in the (encrypted) session cookie.
+///
+/// Nothing is stored on the server, so [`Session::regenerate`] cannot revoke
+/// a copied cookie before it expires, and the data must stay well below the
+/// ~4 KiB browser cookie limit.
+///
+/// [`Session::regenerate`]: crate::session::Session::regenerate
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CookieStore;
+
+impl CookieStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SessionStore for CookieStore {
+    fn load<'a>(&'a self, handle: &'a str) -> StoreFuture<'a, Option<SessionRecord>> {
+        Box::pin(async move { Ok(serde_json::from_str(handle).ok()) })
+    }
+
+    fn save<'a>(&'a self, record: &'a SessionRecord) -> StoreFuture<'a, String> {
+        Box::pin(async move {
+            let json = serde_json::to_string(record)?;
+            if json.len() > MAX_COOKIE_BYTES {
+                tracing::warn!(bytes = json.len(), "session cookie may exceed browser limits");
+            }
+            Ok(json)
+        })
+    }
+
+    fn destroy<'a>(&'a self, _id: &'a str) -> StoreFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+}
@@ -0,0 +1,94 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::{SessionStore, StoreFuture};
+use crate::session::SessionRecord;
+
+/// Stores each session as a JSON file named after its id.
+#[derive(Clone, Debug)]
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    /// Uses `dir`, which is created on first write if missing.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Maps an id to its file, rejecting anything but URL-safe base64 so a
+    /// forged handle cannot name a path outside `dir`.
+    fn path(&self, id: &str) -> Option<PathBuf> {
+        let valid = !id.is_empty()
+            && id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+        valid.then(|| self.dir.join(id))
+    }
+
+    /// Deletes expired session files. Returns how many were removed.
+    pub async fn prune(&self) -> io::Result<usize> {
+        let now = SystemTime::now();
+        let mut removed = 0;
+        let mut entries = tokio::fs::read_dir(&self.dir).await?;
+
+        while let Some(entry) = entries.next_entry().await? {
+            let Ok(bytes) = tokio::fs::read(entry.path()).await else {
+                continue;
+            };
+            let expired = serde_json::from_slice::<SessionRecord>(&bytes)
+                .map_or(true, |record| record.is_expired(now));
+            if expired && tokio::fs::remove_file(entry.path()).await.is_ok() {
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+impl SessionStore for FileStore {
+    fn load<'a>(&'a self, handle: &'a str) -> StoreFuture<'a, Option<SessionRecord>> {
+        Box::pin(async move {
+            let Some(path) = self.path(handle) else {
+                return Ok(None);
+            };
+            match tokio::fs::read(&path).await {
+                Ok(bytes) => Ok(serde_json::from_slice(&bytes).ok()),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    fn save<'a>(&'a self, record: &'a SessionRecord) -> StoreFuture<'a, String> {
+        Box::pin(async move {
+            let path = self
+                .path(&record.id)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid session id"))?;
+            tokio::fs::create_dir_all(&self.dir).await?;
+
+            // Write then rename, so concurrent readers never see a partial file.
+            let tmp = path.with_extension("tmp");
+            tokio::fs::write(&tmp, serde_json::to_vec(record)?).await?;
+            tokio::fs::rename(&tmp, &path).await?;
+
+            Ok(record.id.clone())
+        })
+    }
+
+    fn destroy<'a>(&'a self, id: &'a str) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(path) = self.path(id) else {
+                return Ok(());
+            };
+            match tokio::fs::remove_file(path).await {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        })
+    }
+}
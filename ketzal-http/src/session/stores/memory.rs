@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use super::{SessionStore, StoreFuture};
+use crate::session::SessionRecord;
+
+/// Keeps sessions in process memory. Sessions are lost on restart and not
+/// shared between processes, so this driver suits development and tests.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStore {
+    sessions: Arc<Mutex<HashMap<String, SessionRecord>>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of stored sessions, including expired ones not yet pruned.
+    pub fn len(&self) -> usize {
+        self.sessions.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl SessionStore for MemoryStore {
+    fn load<'a>(&'a self, handle: &'a str) -> StoreFuture<'a, Option<SessionRecord>> {
+        let record = self.sessions.lock().unwrap().get(handle).cloned();
+        Box::pin(async move { Ok(record) })
+    }
+
+    fn save<'a>(&'a self, record: &'a SessionRecord) -> StoreFuture<'a, String> {
+        let now = SystemTime::now();
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|_, r| !r.is_expired(now));
+        sessions.insert(record.id.clone(), record.clone());
+
+        let handle = record.id.clone();
+        Box::pin(async move { Ok(handle) })
+    }
+
+    fn destroy<'a>(&'a self, id: &'a str) -> StoreFuture<'a, ()> {
+        self.sessions.lock().unwrap().remove(id);
+        Box::pin(async { Ok(()) })
+    }
+}
@@ -0,0 +1,43 @@
+mod cookie;
+mod file;
+mod memory;
+
+pub use cookie::CookieStore;
+pub use file::FileStore;
+pub use memory::MemoryStore;
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+
+use super::SessionRecord;
+
+/// The future returned by [`SessionStore`] operations.
+pub type StoreFuture<'a, T> = Pin<Box<dyn Future<Output = io::Result<T>> + Send + 'a>>;
+
+/// Storage backend for sessions.
+///
+/// The session cookie carries a *handle* chosen by the store: server-side
+/// stores use the session id, [`CookieStore`] the serialized record itself.
+/// The cookie is encrypted by the middleware either way.
+///
+/// # Example
+///
+/// ```ignore
+/// impl SessionStore for RedisStore {
+///     fn load<'a>(&'a self, handle: &'a str) -> StoreFuture<'a, Option<SessionRecord>> {
+///         Box::pin(async move { self.get_json(handle).await })
+///     }
+///     // ...
+/// }
+/// ```
+pub trait SessionStore: Send + Sync + 'static {
+    /// Loads the session referenced by a cookie handle.
+    fn load<'a>(&'a self, handle: &'a str) -> StoreFuture<'a, Option<SessionRecord>>;
+
+    /// Persists a session and returns the handle to put in the cookie.
+    fn save<'a>(&'a self, record: &'a SessionRecord) -> StoreFuture<'a, String>;
+
+    /// Deletes a session by id.
+    fn destroy<'a>(&'a self, id: &'a str) -> StoreFuture<'a, ()>;
+}
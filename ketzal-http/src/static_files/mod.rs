@@ -0,0 +1,337 @@
+//! Serving files from a directory on disk.
+//!
+//! [`StaticFiles`] resolves a request path below a root directory and answers
+//! with a streamed [`FileBody`]. It supports single byte ranges (206),
+//! conditional requests (304), and precompressed `.br`/`.gz` siblings.
+
+mod mime;
+mod range;
+
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use http::header::{
+    ACCEPT_ENCODING, ACCEPT_RANGES, ALLOW, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_LENGTH,
+    CONTENT_RANGE, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_RANGE, LAST_MODIFIED,
+    RANGE, VARY,
+};
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+
+pub use mime::guess as guess_mime;
+pub use range::{parse as parse_range, ByteRange};
+
+use crate::constants::{BROTLI_ENCODING, BYTES_UNIT, GZIP_ENCODING};
+use crate::request::Request;
+use crate::response::{FileBody, Response};
+
+/// Precompressed variants, in order of preference.
+const ENCODINGS: [(&str, &str); 2] = [(BROTLI_ENCODING, "br"), (GZIP_ENCODING, "gz")];
+
+/// Serves files below a root directory.
+///
+/// # Example
+///
+/// ```ignore
+/// let assets = StaticFiles::new("public")
+///     .index_files(["index.html"])
+///     .max_age(Duration::from_secs(3600));
+///
+/// Route::static_files("/public", assets);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StaticFiles {
+    root: PathBuf,
+    index_files: Vec<String>,
+    cache_control: Option<String>,
+    precompressed: bool,
+    dotfiles: bool,
+}
+
+impl StaticFiles {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            index_files: vec!["index.html".to_string()],
+            cache_control: None,
+            precompressed: true,
+            dotfiles: false,
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Files tried, in order, when the path names a directory.
+    pub fn index_files<I, S>(mut self, files: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.index_files = files.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Raw `Cache-Control` value added to every file response.
+    pub fn cache_control(mut self, value: impl Into<String>) -> Self {
+        self.cache_control = Some(value.into());
+        self
+    }
+
+    /// Shorthand for `Cache-Control: public, max-age=<secs>`.
+    pub fn max_age(self, max_age: Duration) -> Self {
+        let secs = max_age.as_secs();
+        self.cache_control(format!("public, max-age={secs}"))
+    }
+
+    /// Whether `.br`/`.gz` siblings are served to clients that accept them.
+    pub fn precompressed(mut self, enabled: bool) -> Self {
+        self.precompressed = enabled;
+        self
+    }
+
+    /// Whether paths with segments starting with `.` may be served.
+    /// `.well-known` is always allowed.
+    pub fn dotfiles(mut self, enabled: bool) -> Self {
+        self.dotfiles = enabled;
+        self
+    }
+
+    /// Answers `req` for `path`, relative to the mount prefix.
+    pub async fn serve(&self, req: &Request, path: &str) -> Response {
+        if req.method != Method::GET && req.method != Method::HEAD {
+            return Response::with_body(StatusCode::METHOD_NOT_ALLOWED, "Method Not Allowed")
+                .header(ALLOW.as_str(), "GET, HEAD");
+        }
+
+        let Some(relative) = self.sanitize(path) else {
+            return Response::not_found();
+        };
+
+        let Some((root, file)) = self.resolve(&relative).await else {
+            return Response::not_found();
+        };
+
+        let (served, encoding) = self.negotiate(req, &root, &file).await;
+
+        let Ok(meta) = tokio::fs::metadata(&served).await else {
+            return Response::not_found();
+        };
+
+        let len = meta.len();
+        let modified = meta.modified().ok();
+        let etag = etag(len, modified);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(guess_mime(&file)));
+        headers.insert(ACCEPT_RANGES, HeaderValue::from_static(BYTES_UNIT));
+        insert(&mut headers, ETAG, &etag);
+        if let Some(modified) = modified {
+            insert(&mut headers, LAST_MODIFIED, &httpdate::fmt_http_date(modified));
+        }
+        if let Some(cache_control) = &self.cache_control {
+            insert(&mut headers, CACHE_CONTROL, cache_control);
+        }
+        if self.precompressed {
+            headers.insert(VARY, HeaderValue::from_static("Accept-Encoding"));
+        }
+        if let Some(encoding) = encoding {
+            headers.insert(CONTENT_ENCODING, HeaderValue::from_static(encoding));
+        }
+
+        if is_not_modified(req, &etag, modified) {
+            headers.remove(CONTENT_TYPE);
+            return finish(Response::new(StatusCode::NOT_MODIFIED), headers, len);
+        }
+
+        let range = match header(req, &RANGE) {
+            Some(value) if range_applies(req, &etag, modified) => parse_range(value, len),
+            _ => ByteRange::Full,
+        };
+
+        let (status, start, body_len) = match range {
+            ByteRange::Full => (StatusCode::OK, 0, len),
+            ByteRange::Partial { start, len: part } => {
+                let end = start + part - 1;
+                insert(&mut headers, CONTENT_RANGE, &format!("{BYTES_UNIT} {start}-{end}/{len}"));
+                (StatusCode::PARTIAL_CONTENT, start, part)
+            }
+            ByteRange::Unsatisfiable => {
+                insert(&mut headers, CONTENT_RANGE, &format!("{BYTES_UNIT} */{len}"));
+                headers.remove(CONTENT_ENCODING);
+                return finish(Response::new(StatusCode::RANGE_NOT_SATISFIABLE), headers, 0);
+            }
+        };
+
+        if req.method == Method::HEAD {
+            return finish(Response::new(status), headers, body_len);
+        }
+
+        let body = FileBody::new(served, start, body_len);
+        finish(Response::with_file(status, body), headers, body_len)
+    }
+
+    /// Turns the URL path into a relative filesystem path, rejecting
+    /// traversal, absolute paths and (optionally) hidden files.
+    fn sanitize(&self, path: &str) -> Option<PathBuf> {
+        let decoded = urlencoding::decode(path).ok()?;
+        if decoded.contains('\0') || decoded.contains('\\') {
+            return None;
+        }
+
+        let mut relative = PathBuf::new();
+
+        for segment in decoded.split('/') {
+            match segment {
+                "" | "." => continue,
+                ".." => return None,
+                ".well-known" => {}
+                s if s.starts_with('.') && !self.dotfiles => return None,
+                _ => {}
+            }
+
+            let mut components = Path::new(segment).components();
+            match (components.next(), components.next()) {
+                (Some(Component::Normal(part)), None) => relative.push(part),
+                _ => return None,
+            }
+        }
+
+        Some(relative)
+    }
+
+    /// Finds the file to serve and checks it does not escape the root,
+    /// even through symlinks. Returns the canonical root and file.
+    async fn resolve(&self, relative: &Path) -> Option<(PathBuf, PathBuf)> {
+        let root = tokio::fs::canonicalize(&self.root).await.ok()?;
+        let mut candidate = root.join(relative);
+
+        if tokio::fs::metadata(&candidate).await.ok()?.is_dir() {
+            let mut index = None;
+            for name in &self.index_files {
+                let path = candidate.join(name);
+                if is_file(&path).await {
+                    index = Some(path);
+                    break;
+                }
+            }
+            candidate = index?;
+        }
+
+        let file = tokio::fs::canonicalize(&candidate).await.ok()?;
+        (file.starts_with(&root) && is_file(&file).await).then_some((root, file))
+    }
+
+    /// Picks a precompressed sibling accepted by the client, if any.
+    async fn negotiate(
+        &self,
+        req: &Request,
+        root: &Path,
+        file: &Path,
+    ) -> (PathBuf, Option<&'static str>) {
+        if !self.precompressed {
+            return (file.to_path_buf(), None);
+        }
+
+        let accepted = header(req, &ACCEPT_ENCODING).unwrap_or_default();
+
+        for (encoding, ext) in ENCODINGS {
+            if !accepts_encoding(accepted, encoding) {
+                continue;
+            }
+
+            let mut name = file.as_os_str().to_owned();
+            name.push(".");
+            name.push(ext);
+            let sibling = PathBuf::from(name);
+
+            if let Ok(canonical) = tokio::fs::canonicalize(&sibling).await {
+                if canonical.starts_with(root) && is_file(&canonical).await {
+                    return (canonical, Some(encoding));
+                }
+            }
+        }
+
+        (file.to_path_buf(), None)
+    }
+}
+
+/// Merges `headers` and, for bodiless answers, advertises the length the
+/// full response would have.
+fn finish(mut response: Response, headers: HeaderMap, len: u64) -> Response {
+    response.headers.extend(headers);
+    if response.file.is_none() {
+        insert(&mut response.headers, CONTENT_LENGTH, &len.to_string());
+    }
+    response
+}
+
+fn insert(headers: &mut HeaderMap, name: HeaderName, value: &str) {
+    if let Ok(value) = HeaderValue::from_str(value) {
+        headers.insert(name, value);
+    }
+}
+
+fn header<'a>(req: &'a Request, name: &HeaderName) -> Option<&'a str> {
+    req.headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+async fn is_file(path: &Path) -> bool {
+    tokio::fs::metadata(path).await.map(|m| m.is_file()).unwrap_or(false)
+}
+
+fn etag(len: u64, modified: Option<SystemTime>) -> String {
+    let nanos =
+        modified.and_then(|m| m.duration_since(UNIX_EPOCH).ok()).map_or(0, |d| d.as_nanos());
+    format!("\"{len:x}-{nanos:x}\"")
+}
+
+fn accepts_encoding(header: &str, encoding: &str) -> bool {
+    header.split(',').any(|item| {
+        let mut parts = item.split(';').map(str::trim);
+        let token = parts.next().unwrap_or_default();
+        let rejected = parts.any(|p| {
+            p.strip_prefix("q=").and_then(|q| q.parse::<f32>().ok()).is_some_and(|q| q == 0.0)
+        });
+        (token.eq_ignore_ascii_case(encoding) || token == "*") && !rejected
+    })
+}
+
+fn strip_weak(tag: &str) -> &str {
+    tag.trim().strip_prefix("W/").unwrap_or(tag.trim())
+}
+
+fn is_not_modified(req: &Request, etag: &str, modified: Option<SystemTime>) -> bool {
+    if let Some(tags) = header(req, &IF_NONE_MATCH) {
+        return tags.trim() == "*" || tags.split(',').any(|tag| strip_weak(tag) == etag);
+    }
+
+    let since = header(req, &IF_MODIFIED_SINCE).and_then(|v| httpdate::parse_http_date(v).ok());
+
+    match (since, modified) {
+        (Some(since), Some(modified)) => truncate_secs(modified) <= since,
+        _ => false,
+    }
+}
+
+/// `If-Range` only lets the range through when the validator still matches.
+fn range_applies(req: &Request, etag: &str, modified: Option<SystemTime>) -> bool {
+    let Some(validator) = header(req, &IF_RANGE) else {
+        return true;
+    };
+
+    let validator = validator.trim();
+    if validator.starts_with('"') {
+        return validator == etag;
+    }
+
+    match (httpdate::parse_http_date(validator), modified) {
+        (Ok(date), Some(modified)) => truncate_secs(modified) == date,
+        _ => false,
+    }
+}
+
+fn truncate_secs(time: SystemTime) -> SystemTime {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
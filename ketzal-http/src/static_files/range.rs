@@ -0,0 +1,61 @@
+use crate::constants::BYTES_UNIT;
+
+/// Outcome of evaluating a `Range` header against a file length.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ByteRange {
+    /// Serve the whole file (no range, or a form we do not support).
+    Full,
+    /// Serve `len` bytes starting at `start`.
+    Partial { start: u64, len: u64 },
+    /// The range cannot be satisfied; answer 416.
+    Unsatisfiable,
+}
+
+/// Parses a single `bytes=` range. Multi-range requests are served in full.
+pub fn parse(header: &str, file_len: u64) -> ByteRange {
+    let Some(spec) =
+        header.trim().strip_prefix(BYTES_UNIT).and_then(|rest| rest.trim_start().strip_prefix('='))
+    else {
+        return ByteRange::Full;
+    };
+
+    if spec.contains(',') {
+        return ByteRange::Full;
+    }
+
+    let Some((start, end)) = spec.trim().split_once('-') else {
+        return ByteRange::Full;
+    };
+
+    let (start, end) = (start.trim(), end.trim());
+
+    if start.is_empty() {
+        // Suffix range: the last `end` bytes.
+        let Ok(suffix) = end.parse::<u64>() else {
+            return ByteRange::Full;
+        };
+        if suffix == 0 || file_len == 0 {
+            return ByteRange::Unsatisfiable;
+        }
+        let len = suffix.min(file_len);
+        return ByteRange::Partial { start: file_len - len, len };
+    }
+
+    let Ok(start) = start.parse::<u64>() else {
+        return ByteRange::Full;
+    };
+    if start >= file_len {
+        return ByteRange::Unsatisfiable;
+    }
+
+    let last = if end.is_empty() {
+        file_len - 1
+    } else {
+        match end.parse::<u64>() {
+            Ok(end) if end >= start => end.min(file_len - 1),
+            _ => return ByteRange::Full,
+        }
+    };
+
+    ByteRange::Partial { start, len: last - start + 1 }
+}
@@ -0,0 +1,64 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use ketzal_http::cookie::{parse_cookie_header, Cookie, SameSite};
+
+/// Tests for Cookie rendering and Cookie header parsing
+
+#[test]
+fn renders_all_attributes() {
+    let cookie = Cookie::new("theme", "dark")
+        .path("/")
+        .domain("example.com")
+        .max_age(Duration::from_secs(3600))
+        .secure(true)
+        .http_only(true)
+        .same_site(SameSite::Lax);
+
+    assert_eq!(
+        cookie.to_header_value(),
+        "theme=dark; Path=/; Domain=example.com; Max-Age=3600; Secure; HttpOnly; SameSite=Lax"
+    );
+}
+
+#[test]
+fn renders_expires_as_http_date() {
+    let cookie = Cookie::new("a", "b").expires(UNIX_EPOCH + Duration::from_secs(784111777));
+
+    assert_eq!(cookie.to_header_value(), "a=b; Expires=Sun, 06 Nov 1994 08:49:37 GMT");
+}
+
+#[test]
+fn percent_encodes_values() {
+    let cookie = Cookie::new("note", "a b;c");
+
+    assert_eq!(cookie.to_header_value(), "note=a%20b%3Bc");
+}
+
+#[test]
+fn same_site_none_implies_secure() {
+    let cookie = Cookie::new("a", "b").same_site(SameSite::None);
+
+    assert_eq!(cookie.to_header_value(), "a=b; Secure; SameSite=None");
+}
+
+#[test]
+fn removal_cookie_expires_immediately() {
+    let cookie = Cookie::removal("session");
+
+    assert!(cookie.is_removal());
+    assert!(cookie.to_header_value().contains("Max-Age=0"));
+}
+
+#[test]
+fn parses_cookie_header() {
+    let pairs = parse_cookie_header("a=1; b=\"two\"; note=a%20b; broken; =x");
+
+    assert_eq!(
+        pairs,
+        vec![
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "two".to_string()),
+            ("note".to_string(), "a b".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,62 @@
+use http::{header, Method};
+use ketzal_http::cookie::Cookie;
+use ketzal_http::Response;
+
+use crate::helpers::request;
+
+/// Tests for CookieJar and the Request/Response integration
+
+#[test]
+fn reads_request_cookies() {
+    let req = request(Method::GET, "/", &[("cookie", "a=1; b=2"), ("cookie", "c=3")], b"");
+
+    let jar = req.cookies();
+
+    assert_eq!(jar.get("a"), Some("1"));
+    assert_eq!(jar.get("c"), Some("3"));
+    assert_eq!(req.cookie("b").as_deref(), Some("2"));
+    assert!(req.cookie("missing").is_none());
+}
+
+#[test]
+fn reads_see_pending_changes() {
+    let req = request(Method::GET, "/", &[("cookie", "a=1; b=2")], b"");
+    let mut jar = req.cookies();
+
+    jar.add(Cookie::new("a", "changed"));
+    jar.remove("b");
+
+    assert_eq!(jar.get("a"), Some("changed"));
+    assert!(!jar.contains("b"));
+}
+
+#[test]
+fn only_changes_are_written() {
+    let req = request(Method::GET, "/", &[("cookie", "a=1")], b"");
+    let mut jar = req.cookies();
+    jar.add(Cookie::new("x", "first"));
+    jar.add(Cookie::new("x", "second"));
+    jar.add(Cookie::new("y", "1"));
+
+    let resp = Response::ok("").with_cookies(&jar);
+
+    let cookies: Vec<_> =
+        resp.headers.get_all(header::SET_COOKIE).iter().map(|v| v.to_str().unwrap()).collect();
+    assert_eq!(cookies, vec!["x=second", "y=1"]);
+}
+
+#[test]
+fn with_header_accepts_dynamic_values() {
+    let id = format!("req-{}", 42);
+
+    let resp = Response::ok("").with_header("x-request-id", &id);
+
+    assert_eq!(resp.headers.get("x-request-id").unwrap(), "req-42");
+}
+
+#[test]
+fn with_header_drops_invalid_values() {
+    let resp = Response::ok("").with_header("x-bad", "a\r\nb");
+
+    assert!(!resp.headers.contains_key("x-bad"));
+}
@@ -0,0 +1,85 @@
+use ketzal_http::cookie::{Cookie, CookieJar, Key, Keyring};
+
+/// Tests for signed and encrypted cookies and key rotation
+
+#[test]
+fn signed_cookies_round_trip() {
+    let keys = Keyring::new(Key::from_app_key("secret"));
+    let mut jar = CookieJar::new();
+
+    jar.signed(&keys).add(Cookie::new("user", "42"));
+
+    assert!(jar.get("user").unwrap().starts_with("42."));
+    assert_eq!(jar.signed(&keys).get("user").as_deref(), Some("42"));
+}
+
+#[test]
+fn tampered_signed_cookies_are_rejected() {
+    let keys = Keyring::new(Key::from_app_key("secret"));
+    let mut jar = CookieJar::new();
+    jar.signed(&keys).add(Cookie::new("user", "42"));
+    let forged = jar.get("user").unwrap().replacen("42", "1", 1);
+
+    jar.add(Cookie::new("user", forged));
+
+    assert!(jar.signed(&keys).get("user").is_none());
+}
+
+#[test]
+fn signatures_are_bound_to_the_cookie_name() {
+    let keys = Keyring::new(Key::from_app_key("secret"));
+    let mut jar = CookieJar::new();
+    jar.signed(&keys).add(Cookie::new("role", "admin"));
+    let value = jar.get("role").unwrap().to_string();
+
+    jar.add(Cookie::new("other", value));
+
+    assert!(jar.signed(&keys).get("other").is_none());
+}
+
+#[test]
+fn encrypted_cookies_hide_their_value() {
+    let keys = Keyring::new(Key::from_app_key("secret"));
+    let mut jar = CookieJar::new();
+
+    jar.encrypted(&keys).add(Cookie::new("cart", "apples"));
+
+    assert!(!jar.get("cart").unwrap().contains("apples"));
+    assert_eq!(jar.encrypted(&keys).get("cart").as_deref(), Some("apples"));
+}
+
+#[test]
+fn encrypted_cookies_reject_wrong_key() {
+    let keys = Keyring::new(Key::from_app_key("secret"));
+    let other = Keyring::new(Key::from_app_key("other"));
+    let mut jar = CookieJar::new();
+
+    jar.encrypted(&keys).add(Cookie::new("cart", "apples"));
+
+    assert!(jar.encrypted(&other).get("cart").is_none());
+}
+
+#[test]
+fn previous_keys_still_verify_after_rotation() {
+    let old = Keyring::new(Key::from_app_key("old"));
+    let mut jar = CookieJar::new();
+    jar.signed(&old).add(Cookie::new("user", "42"));
+    jar.encrypted(&old).add(Cookie::new("cart", "apples"));
+
+    let rotated = Keyring::new(Key::from_app_key("new")).with_previous(Key::from_app_key("old"));
+
+    assert_eq!(jar.signed(&rotated).get("user").as_deref(), Some("42"));
+    assert_eq!(jar.encrypted(&rotated).get("cart").as_deref(), Some("apples"));
+}
+
+#[test]
+fn base64_app_keys_are_decoded() {
+    let app_key = Key::generate_app_key();
+    assert!(app_key.starts_with("base64:"));
+
+    let keys = Keyring::new(Key::from_app_key(&app_key));
+    let mut jar = CookieJar::new();
+    jar.signed(&keys).add(Cookie::new("a", "b"));
+
+    assert_eq!(jar.signed(&keys).get("a").as_deref(), Some("b"));
+}
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+use ketzal_http::cors::Cors;
+use ketzal_http::middleware::AllowedMethods;
+
+use super::run;
+use crate::helpers::request;
+
+/// Tests for CORS response and preflight headers
+
+#[tokio::test]
+async fn default_follows_the_cors_constants() {
+    let res = run(Cors::default(), preflight("https://spa.test", "POST", None)).await;
+
+    assert_eq!(res.status, StatusCode::NO_CONTENT);
+    assert_eq!(res.headers["access-control-allow-origin"], "*");
+    assert_eq!(
+        res.headers["access-control-allow-methods"],
+        "GET, POST, PUT, DELETE, PATCH, OPTIONS"
+    );
+    assert_eq!(res.headers["access-control-allow-headers"], "Content-Type, Authorization");
+}
+
+#[tokio::test]
+async fn simple_requests_get_origin_headers() {
+    let cors = Cors::new()
+        .allow_origins(["https://spa.test"])
+        .allow_credentials(true)
+        .expose_headers(["x-request-id"]);
+    let req = request(Method::GET, "/", &[("origin", "https://spa.test")], b"");
+
+    let res = run(cors, req).await;
+
+    assert_eq!(res.body, b"ok");
+    assert_eq!(res.headers["access-control-allow-origin"], "https://spa.test");
+    assert_eq!(res.headers["access-control-allow-credentials"], "true");
+    assert_eq!(res.headers["access-control-expose-headers"], "x-request-id");
+    assert_eq!(res.headers["vary"], "Accept-Encoding, Origin");
+}
+
+#[tokio::test]
+async fn disallowed_origins_get_no_cors_headers() {
+    let cors = Cors::new().allow_origins(["https://spa.test"]);
+    let req = request(Method::GET, "/", &[("origin", "https://evil.test")], b"");
+
+    let res = run(cors, req).await;
+
+    assert_eq!(res.body, b"ok");
+    assert!(!res.headers.contains_key("access-control-allow-origin"));
+    assert_eq!(res.headers["vary"], "Accept-Encoding, Origin");
+}
+
+#[tokio::test]
+async fn wildcard_origins_never_get_credentials() {
+    let cors = Cors::default().allow_credentials(true);
+    let req = request(Method::GET, "/", &[("origin", "https://evil.test")], b"");
+
+    let res = run(cors, req).await;
+
+    assert_eq!(res.headers["access-control-allow-origin"], "*");
+    assert!(!res.headers.contains_key("access-control-allow-credentials"));
+}
+
+#[tokio::test]
+async fn listed_origins_keep_credentials_next_to_a_wildcard() {
+    let cors = Cors::new().allow_origins(["https://spa.test", "*"]).allow_credentials(true);
+    let req = request(Method::GET, "/", &[("origin", "https://spa.test")], b"");
+
+    let res = run(cors, req).await;
+
+    assert_eq!(res.headers["access-control-allow-origin"], "https://spa.test");
+    assert_eq!(res.headers["access-control-allow-credentials"], "true");
+}
+
+#[tokio::test]
+async fn preflight_uses_the_router_methods() {
+    let cors = Cors::default().max_age(Duration::from_secs(600));
+    let mut req = preflight("https://spa.test", "DELETE", None);
+    req.extensions.insert(AllowedMethods(vec![Method::GET, Method::DELETE]));
+
+    let res = run(cors, req).await;
+
+    assert_eq!(res.headers["access-control-allow-methods"], "GET, DELETE");
+    assert_eq!(res.headers["access-control-max-age"], "600");
+    assert_eq!(res.headers["vary"], "Origin, Access-Control-Request-Method");
+}
+
+#[tokio::test]
+async fn preflight_for_unrouted_method_is_not_allowed() {
+    let mut req = preflight("https://spa.test", "PUT", None);
+    req.extensions.insert(AllowedMethods(vec![Method::GET]));
+
+    let res = run(Cors::default(), req).await;
+
+    assert_eq!(res.status, StatusCode::NO_CONTENT);
+    assert!(!res.headers.contains_key("access-control-allow-origin"));
+}
+
+#[tokio::test]
+async fn any_header_mirrors_the_request() {
+    let cors = Cors::default().allow_any_header();
+
+    let res =
+        run(cors, preflight("https://spa.test", "POST", Some("x-custom, content-type"))).await;
+
+    assert_eq!(res.headers["access-control-allow-headers"], "x-custom, content-type");
+    assert!(res.headers["vary"].to_str().unwrap().contains("Access-Control-Request-Headers"));
+}
+
+#[tokio::test]
+async fn options_without_preflight_headers_reach_the_handler() {
+    let req = request(Method::OPTIONS, "/", &[("origin", "https://spa.test")], b"");
+
+    let res = run(Cors::default(), req).await;
+
+    assert_eq!(res.body, b"ok");
+}
+
+fn preflight(origin: &str, method: &str, headers: Option<&str>) -> ketzal_http::Request {
+    let mut list = vec![("origin", origin), ("access-control-request-method", method)];
+    if let Some(headers) = headers {
+        list.push(("access-control-request-headers", headers));
+    }
+    request(Method::OPTIONS, "/", &list, b"")
+}
@@ -0,0 +1,15 @@
+pub mod headers;
+pub mod origin;
+
+use std::sync::Arc;
+
+use ketzal_http::cors::Cors;
+use ketzal_http::middleware::{Endpoint, Next};
+use ketzal_http::{Request, Response};
+
+/// Runs `req` through `cors` into a handler answering `ok`
+pub async fn run(cors: Cors, req: Request) -> Response {
+    let endpoint: Endpoint =
+        Arc::new(|_| Box::pin(async { Response::ok("ok").with_header("vary", "Accept-Encoding") }));
+    Next::new(Arc::new([Arc::new(cors) as _]), endpoint).run(req).await
+}
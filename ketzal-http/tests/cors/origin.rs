@@ -0,0 +1,30 @@
+use ketzal_http::cors::OriginPattern;
+
+/// Tests for origin patterns
+
+#[test]
+fn exact_origins_match_case_insensitively() {
+    let pattern = OriginPattern::new("https://app.example.com");
+
+    assert!(pattern.matches("https://APP.example.com"));
+    assert!(!pattern.matches("https://app.example.com.evil.io"));
+    assert!(!pattern.matches("http://app.example.com"));
+}
+
+#[test]
+fn wildcards_match_subdomains() {
+    let pattern = OriginPattern::new("https://*.example.com");
+
+    assert!(pattern.matches("https://a.example.com"));
+    assert!(pattern.matches("https://a.b.example.com"));
+    assert!(!pattern.matches("https://example.com"));
+    assert!(!pattern.matches("https://a.example.com.evil.io"));
+}
+
+#[test]
+fn star_matches_any_origin() {
+    let pattern = OriginPattern::new("*");
+
+    assert!(pattern.is_any());
+    assert!(pattern.matches("null"));
+}
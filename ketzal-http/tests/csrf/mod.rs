@@ -0,0 +1,39 @@
+pub mod verify;
+
+use std::sync::Arc;
+
+use http::header;
+use ketzal_http::csrf::{CsrfMiddleware, XSRF_COOKIE};
+use ketzal_http::middleware::{Endpoint, Middleware, Next};
+use ketzal_http::session::{MemoryStore, SessionMiddleware};
+use ketzal_http::{Request, Response};
+
+use crate::session::keys;
+
+/// Session and CSRF middleware sharing one store
+pub fn stack(csrf: CsrfMiddleware) -> Arc<[Arc<dyn Middleware>]> {
+    let sessions = SessionMiddleware::new(MemoryStore::new(), keys());
+    Arc::new([Arc::new(sessions) as _, Arc::new(csrf) as _])
+}
+
+/// Runs `req` through `stack` into a handler answering `ok`
+pub async fn run(stack: &Arc<[Arc<dyn Middleware>]>, req: Request) -> Response {
+    let endpoint: Endpoint = Arc::new(|_| Box::pin(async { Response::ok("ok") }));
+    Next::new(stack.clone(), endpoint).run(req).await
+}
+
+/// The session cookie pair and the CSRF token issued by `res`
+pub fn credentials(res: &Response) -> (String, String) {
+    let mut session = None;
+    let mut token = None;
+
+    for value in res.headers.get_all(header::SET_COOKIE) {
+        let pair = value.to_str().unwrap().split(';').next().unwrap().to_string();
+        match pair.strip_prefix(&format!("{XSRF_COOKIE}=")) {
+            Some(value) => token = Some(value.to_string()),
+            None => session = Some(pair),
+        }
+    }
+
+    (session.unwrap(), token.unwrap())
+}
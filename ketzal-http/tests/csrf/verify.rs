@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use http::{Method, StatusCode};
+use ketzal_http::csrf::CsrfMiddleware;
+use ketzal_http::middleware::{Endpoint, Next};
+use ketzal_http::Response;
+
+use super::{credentials, run, stack};
+use crate::helpers::request;
+use crate::multipart::{body, content_type};
+
+/// Tests for CSRF token verification
+
+#[tokio::test]
+async fn safe_methods_pass_and_receive_a_token() {
+    let stack = stack(CsrfMiddleware::new());
+
+    let res = run(&stack, request(Method::GET, "/", &[], b"")).await;
+
+    assert_eq!(res.status, StatusCode::OK);
+    let (_, token) = credentials(&res);
+    assert!(!token.is_empty());
+}
+
+#[tokio::test]
+async fn accepts_token_from_headers() {
+    let stack = stack(CsrfMiddleware::new());
+    let (session, token) = credentials(&run(&stack, request(Method::GET, "/", &[], b"")).await);
+
+    for header in ["x-csrf-token", "x-xsrf-token"] {
+        let headers = [("cookie", session.as_str()), (header, token.as_str())];
+        let res = run(&stack, request(Method::POST, "/", &headers, b"")).await;
+
+        assert_eq!(res.status, StatusCode::OK, "{header}");
+    }
+}
+
+#[tokio::test]
+async fn accepts_token_from_form_field() {
+    let stack = stack(CsrfMiddleware::new());
+    let (session, token) = credentials(&run(&stack, request(Method::GET, "/", &[], b"")).await);
+    let form = format!("name=joe&_token={token}");
+    let headers =
+        [("cookie", session.as_str()), ("content-type", "application/x-www-form-urlencoded")];
+
+    let res = run(&stack, request(Method::POST, "/", &headers, form.as_bytes())).await;
+
+    assert_eq!(res.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn ignores_token_in_query_string() {
+    let stack = stack(CsrfMiddleware::new());
+    let (session, token) = credentials(&run(&stack, request(Method::GET, "/", &[], b"")).await);
+    let path = format!("/?_token={token}");
+
+    let res = run(&stack, request(Method::POST, &path, &[("cookie", session.as_str())], b"")).await;
+
+    assert_eq!(res.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn accepts_token_from_multipart_field() {
+    let stack = stack(CsrfMiddleware::new());
+    let (session, token) = credentials(&run(&stack, request(Method::GET, "/", &[], b"")).await);
+    let payload = body(&[("_token", None, None, token.as_bytes())]);
+    let content_type = content_type();
+    let headers = [("cookie", session.as_str()), ("content-type", content_type.as_str())];
+
+    let res = run(&stack, request(Method::POST, "/", &headers, &payload)).await;
+
+    assert_eq!(res.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn wrong_or_missing_token_is_forbidden() {
+    let stack = stack(CsrfMiddleware::new());
+    let (session, _) = credentials(&run(&stack, request(Method::GET, "/", &[], b"")).await);
+
+    let wrong = [("cookie", session.as_str()), ("x-csrf-token", "forged")];
+    let missing = [("cookie", session.as_str())];
+
+    assert_eq!(
+        run(&stack, request(Method::POST, "/", &wrong, b"")).await.status,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        run(&stack, request(Method::DELETE, "/", &missing, b"")).await.status,
+        StatusCode::FORBIDDEN
+    );
+}
+
+#[tokio::test]
+async fn sessions_without_token_answer_page_expired() {
+    let stack = stack(CsrfMiddleware::new());
+
+    let res = run(&stack, request(Method::POST, "/", &[("x-csrf-token", "stale")], b"")).await;
+
+    assert_eq!(res.status.as_u16(), 419);
+    assert!(res.to_http_string().starts_with("HTTP/1.1 419 Page Expired"));
+}
+
+#[tokio::test]
+async fn excluded_paths_skip_verification() {
+    let stack = stack(CsrfMiddleware::new().except(["/webhooks/*", "/ping"]));
+
+    for path in ["/webhooks/stripe", "/ping/"] {
+        let res = run(&stack, request(Method::POST, path, &[], b"")).await;
+        assert_eq!(res.status, StatusCode::OK, "{path}");
+    }
+    let res = run(&stack, request(Method::POST, "/pings", &[], b"")).await;
+    assert_eq!(res.status.as_u16(), 419);
+}
+
+#[tokio::test]
+async fn excluded_paths_ignore_the_query_string() {
+    let stack = stack(CsrfMiddleware::new().except(["/webhooks/stripe"]));
+
+    let res = run(&stack, request(Method::POST, "/webhooks/stripe?x=1", &[], b"")).await;
+
+    assert_eq!(res.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn csrf_field_embeds_the_session_token() {
+    let stack = stack(CsrfMiddleware::new().xsrf_cookie(false));
+    let endpoint: Endpoint = Arc::new(|req| {
+        let field = req.csrf_field();
+        let token = req.csrf_token();
+        Box::pin(async move { Response::ok(format!("{token}|{field}")) })
+    });
+
+    let res = Next::new(stack, endpoint).run(request(Method::GET, "/", &[], b"")).await;
+
+    let body = String::from_utf8(res.body).unwrap();
+    let (token, field) = body.split_once('|').unwrap();
+    assert_eq!(field, format!(r#"<input type="hidden" name="_token" value="{token}">"#));
+}
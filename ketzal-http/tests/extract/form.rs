@@ -0,0 +1,52 @@
+use http::{Method, StatusCode};
+use ketzal_http::extract::{DeserializeError, Form, FromRequest};
+use ketzal_http::Request;
+use serde::Deserialize;
+
+use crate::helpers::request;
+
+/// Tests for typed form bodies
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Login {
+    email: String,
+    remember: bool,
+    attempts: Option<u8>,
+}
+
+#[test]
+fn parses_scalars_from_text() {
+    let req = form_request("email=a%40b.c&remember=true&attempts=3");
+
+    let login: Login = req.form_as().unwrap();
+
+    assert_eq!(login, Login { email: "a@b.c".into(), remember: true, attempts: Some(3) });
+}
+
+#[test]
+fn invalid_values_report_the_field() {
+    let req = form_request("email=a%40b.c&remember=true&attempts=many");
+
+    match req.form_as::<Login>().unwrap_err() {
+        DeserializeError::Data { field, .. } => assert_eq!(field, "attempts"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn form_extractor_rejects_json_bodies() {
+    let req = request(Method::POST, "/", &[("content-type", "application/json")], b"{}");
+
+    let resp = Form::<Login>::from_request(&req).unwrap_err();
+
+    assert_eq!(resp.status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+fn form_request(body: &str) -> Request {
+    request(
+        Method::POST,
+        "/",
+        &[("content-type", "application/x-www-form-urlencoded")],
+        body.as_bytes(),
+    )
+}
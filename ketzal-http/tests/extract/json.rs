@@ -0,0 +1,115 @@
+use http::{Method, StatusCode};
+use ketzal_http::extract::{DeserializeError, FromRequest, Json, JsonLimits};
+use ketzal_http::Request;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::helpers::request;
+
+/// Tests for typed JSON bodies
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct CreateUser {
+    name: String,
+    age: u32,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn deserializes_into_the_target_type() {
+    let req = json_request(r#"{"name": "Ada", "age": 36, "tags": ["admin"]}"#);
+
+    let user: CreateUser = req.json_as().unwrap();
+
+    assert_eq!(user, CreateUser { name: "Ada".into(), age: 36, tags: vec!["admin".into()] });
+}
+
+#[test]
+fn type_mismatch_reports_field_and_expected_type() {
+    let req = json_request(r#"{"name": "Ada", "age": "old"}"#);
+
+    let err = req.json_as::<CreateUser>().unwrap_err();
+
+    assert_eq!(err.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = body_json(err.into_response().body);
+    assert_eq!(body["field"], "age");
+    assert_eq!(body["expected"], "u32");
+}
+
+#[test]
+fn nested_paths_use_dot_notation() {
+    let req = json_request(r#"{"name": "Ada", "age": 36, "tags": ["ok", 7]}"#);
+
+    match req.json_as::<CreateUser>().unwrap_err() {
+        DeserializeError::Data { field, expected, .. } => {
+            assert_eq!(field, "tags.1");
+            assert_eq!(expected.as_deref(), Some("a string"));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn missing_field_points_at_the_field() {
+    let req = json_request(r#"{"name": "Ada"}"#);
+
+    match req.json_as::<CreateUser>().unwrap_err() {
+        DeserializeError::Data { field, .. } => assert_eq!(field, "age"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn malformed_json_is_a_bad_request() {
+    let err = json_request(r#"{"name": "#).json_as::<CreateUser>().unwrap_err();
+    assert_eq!(err.status(), StatusCode::BAD_REQUEST);
+
+    let err = json_request(r#"{"name": "Ada", "age": 1} trailing"#).json_as::<CreateUser>();
+    assert_eq!(err.unwrap_err().status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn rejects_other_content_types() {
+    let req = request(Method::POST, "/", &[("content-type", "text/plain")], b"{}");
+
+    let err = req.json_as::<Value>().unwrap_err();
+
+    assert_eq!(err.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[test]
+fn enforces_the_maximum_depth() {
+    let req = json_request(r#"{"a": [[{"b": "[[[[ in a string ]]]]"}]]}"#);
+
+    assert!(req.json_as_with::<Value>(JsonLimits::default().max_depth(4)).is_ok());
+    let err = req.json_as_with::<Value>(JsonLimits::default().max_depth(3)).unwrap_err();
+    assert!(matches!(err, DeserializeError::TooDeep { limit: 3 }));
+    assert_eq!(err.status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn limits_are_read_from_extensions() {
+    let mut req = json_request("[[[1]]]");
+    req.extensions.insert(JsonLimits::default().max_depth(2));
+
+    assert!(matches!(req.json_as::<Value>(), Err(DeserializeError::TooDeep { .. })));
+}
+
+#[test]
+fn json_extractor_answers_with_the_error_response() {
+    let Json(user) =
+        Json::<CreateUser>::from_request(&json_request(r#"{"name": "Ada", "age": 1}"#)).unwrap();
+    assert_eq!(user.age, 1);
+
+    let resp = Json::<CreateUser>::from_request(&json_request("{}")).unwrap_err();
+    assert_eq!(resp.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+fn json_request(body: &str) -> Request {
+    request(Method::POST, "/", &[("content-type", "application/json")], body.as_bytes())
+}
+
+fn body_json(body: Vec<u8>) -> Value {
+    serde_json::from_slice(&body).unwrap()
+}
@@ -0,0 +1,3 @@
+pub mod form;
+pub mod json;
+pub mod valid;
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use http::{Method, StatusCode};
+use ketzal_http::extract::{DeserializeError, FromRequest, Valid};
+use ketzal_http::request::validated_data::ValidatedData;
+use ketzal_http::Request;
+use ketzal_validation::{Catalog, FormRequest};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::helpers::request;
+
+/// Tests for typed validated data and the Valid extractor
+
+#[derive(Debug, Deserialize, PartialEq, FormRequest)]
+struct CreateUser {
+    #[rule("required|min:2")]
+    name: String,
+    #[rule("required")]
+    age: u32,
+}
+
+#[test]
+fn into_struct_deserializes_validated_data() {
+    let user: CreateUser = validated(json!({"name": "Ada", "age": 36})).into_struct().unwrap();
+
+    assert_eq!(user, CreateUser { name: "Ada".into(), age: 36 });
+}
+
+#[test]
+fn into_struct_reports_the_failing_field() {
+    let err = validated(json!({"name": "Ada", "age": -1})).into_struct::<CreateUser>();
+
+    match err.unwrap_err() {
+        DeserializeError::Data { field, expected, .. } => {
+            assert_eq!(field, "age");
+            assert_eq!(expected.as_deref(), Some("u32"));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn only_and_except_accept_slices_vecs_and_arrays() {
+    let data = validated(json!({"a": 1, "b": 2, "c": 3}));
+
+    let keys: Vec<String> = vec!["a".into(), "b".into()];
+    assert_eq!(data.clone().only(&keys).all().len(), 2);
+    assert_eq!(data.clone().only(&["a"][..]).all().len(), 1);
+    assert_eq!(data.clone().except(vec!["a"]).all().len(), 2);
+    assert_eq!(data.except(["a", "b"]).all().len(), 1);
+}
+
+#[test]
+fn only_and_except_follow_dot_keys() {
+    let data = validated(json!({"user": {"name": "Ada", "password": "x"}, "page": 1}));
+
+    assert_eq!(data.get("user.name"), Some(&json!("Ada")));
+    assert_eq!(
+        data.clone().only(["user.name"]).all(),
+        HashMap::from([("user".to_string(), json!({"name": "Ada"}))])
+    );
+    assert_eq!(
+        data.except(["user.password", "page"]).all(),
+        HashMap::from([("user".to_string(), json!({"name": "Ada"}))])
+    );
+}
+
+#[test]
+fn valid_extractor_validates_then_deserializes() {
+    let Valid(user) =
+        Valid::<CreateUser>::from_request(&json_request(r#"{"name": "Ada", "age": 36}"#)).unwrap();
+
+    assert_eq!(user.name, "Ada");
+}
+
+#[test]
+fn valid_extractor_answers_validation_errors() {
+    let resp = Valid::<CreateUser>::from_request(&json_request(r#"{"name": "A"}"#)).unwrap_err();
+
+    assert_eq!(resp.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert!(body["errors"].get("name").is_some());
+    assert!(body["errors"].get("age").is_some());
+}
+
+#[test]
+fn valid_extractor_reports_values_that_do_not_fit_the_type() {
+    let req = json_request(r#"{"name": "Ada", "age": "old"}"#);
+
+    let resp = Valid::<CreateUser>::from_request(&req).unwrap_err();
+
+    assert_eq!(resp.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(body["errors"]["age"][0], "The field age must be u32.");
+}
+
+#[test]
+fn valid_extractor_answers_in_the_accepted_language() {
+    ketzal_validation::register_catalog("it", italian());
+    let req = request(
+        Method::POST,
+        "/",
+        &[("content-type", "application/json"), ("accept-language", "it-IT,en;q=0.5")],
+        br#"{"age": 30}"#,
+    );
+
+    let resp = Valid::<CreateUser>::from_request(&req).unwrap_err();
+
+    let body: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(body["errors"]["name"][0], "Il campo nome è obbligatorio.");
+}
+
+#[test]
+fn validate_json_answers_in_the_accepted_language() {
+    ketzal_validation::register_catalog("it", italian());
+    let req = request(
+        Method::POST,
+        "/",
+        &[("content-type", "application/json"), ("accept-language", "it")],
+        b"{}",
+    );
+
+    let ControlFlow::Break(resp) = req.validate_json([("name", "required")]) else {
+        panic!("validation passed");
+    };
+
+    let body: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(body["errors"]["name"][0], "Il campo nome è obbligatorio.");
+}
+
+#[test]
+fn preferred_locale_follows_quality_and_registered_catalogs() {
+    ketzal_validation::register_catalog("it", italian());
+    let locale = |header: &str| {
+        request(Method::GET, "/", &[("accept-language", header)], b"").preferred_locale()
+    };
+
+    assert_eq!(locale("en;q=0.9, it-CH;q=0.8, *").as_deref(), Some("it"));
+    assert_eq!(locale("sv, it;q=0").as_deref(), None);
+    assert_eq!(locale("da, en-GB;q=0.8").as_deref(), None);
+    assert_eq!(request(Method::GET, "/", &[], b"").preferred_locale(), None);
+}
+
+#[test]
+fn valid_extractor_rejects_malformed_bodies() {
+    let resp = Valid::<CreateUser>::from_request(&json_request("{oops")).unwrap_err();
+
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+}
+
+fn italian() -> Catalog {
+    Catalog::from_json(
+        r#"{ "required": "Il campo :attribute è obbligatorio.", "attributes": { "name": "nome" } }"#,
+    )
+    .unwrap()
+}
+
+fn validated(value: Value) -> ValidatedData {
+    let Value::Object(map) = value else { unreachable!() };
+    ValidatedData::new(map.into_iter().collect())
+}
+
+fn json_request(body: &str) -> Request {
+    request(Method::POST, "/", &[("content-type", "application/json")], body.as_bytes())
+}
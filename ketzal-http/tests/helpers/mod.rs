@@ -0,0 +1,3 @@
+pub mod request_factory;
+
+pub use request_factory::request;
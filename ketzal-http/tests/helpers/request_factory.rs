@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+use http::{HeaderMap, HeaderName, HeaderValue, Method};
+use ketzal_http::Request;
+
+/// Builds a request with the given headers and body
+pub fn request(method: Method, path: &str, headers: &[(&str, &str)], body: &[u8]) -> Request {
+    let mut map = HeaderMap::new();
+    for (key, value) in headers {
+        map.append(
+            HeaderName::from_bytes(key.as_bytes()).unwrap(),
+            HeaderValue::from_str(value).unwrap(),
+        );
+    }
+
+    Request::new(method, path.to_string(), HashMap::new(), map, body.to_vec(), HashMap::new())
+}
@@ -0,0 +1,84 @@
+use http::Method;
+use ketzal_http::Request;
+use serde_json::json;
+
+use crate::helpers::request;
+
+/// Tests for dot-notation input accessors
+
+#[test]
+fn reads_nested_values_with_dot_notation() {
+    let req = json_request(r#"{"user": {"name": "Ada", "tags": ["admin", "ops"]}}"#);
+
+    assert_eq!(req.input("user.name"), Some(&json!("Ada")));
+    assert_eq!(req.input("user.tags.1"), Some(&json!("ops")));
+    assert_eq!(req.input("user.tags.2"), None);
+    assert_eq!(req.input("user.name.first"), None);
+}
+
+#[test]
+fn top_level_keys_containing_dots_win() {
+    let req = json_request(r#"{"a.b": 1, "a": {"b": 2}}"#);
+
+    assert_eq!(req.input("a.b"), Some(&json!(1)));
+}
+
+#[test]
+fn converts_scalars() {
+    let req = json_request(
+        r#"{"age": "42", "score": 9.5, "count": 3, "agree": "on", "off": "no", "flag": true}"#,
+    );
+
+    assert_eq!(req.integer("age"), Some(42));
+    assert_eq!(req.integer("count"), Some(3));
+    assert_eq!(req.float("score"), Some(9.5));
+    assert_eq!(req.string("count").as_deref(), Some("3"));
+    assert!(req.boolean("agree"));
+    assert!(req.boolean("flag"));
+    assert!(!req.boolean("off"));
+    assert!(!req.boolean("missing"));
+}
+
+#[test]
+fn has_and_filled_differ_on_empty_values() {
+    let req = json_request(r#"{"name": " ", "bio": null, "tags": [], "email": "a@b.c"}"#);
+
+    assert!(req.has("name") && req.has("bio") && req.has("tags"));
+    assert!(!req.filled("name") && !req.filled("bio") && !req.filled("tags"));
+    assert!(req.filled("email"));
+    assert!(req.missing("phone"));
+    assert!(req.has_any(&["phone", "email"]));
+}
+
+#[test]
+fn only_keeps_the_requested_nesting() {
+    let req = json_request(r#"{"user": {"name": "Ada", "role": "admin"}, "page": 2}"#);
+
+    assert_eq!(
+        serde_json::Value::Object(req.only(["user.name", "page", "missing"])),
+        json!({"user": {"name": "Ada"}, "page": 2})
+    );
+}
+
+#[test]
+fn except_removes_nested_keys() {
+    let req = json_request(r#"{"user": {"name": "Ada", "password": "x"}, "_token": "t"}"#);
+
+    assert_eq!(
+        serde_json::Value::Object(req.except(["user.password", "_token"])),
+        json!({"user": {"name": "Ada"}})
+    );
+}
+
+#[test]
+fn merge_adds_and_replaces_values() {
+    let mut req = json_request(r#"{"user": {"name": "Ada"}}"#);
+
+    req.merge([("user.role", json!("member")), ("user.name", json!("Grace"))]);
+
+    assert_eq!(req.input("user"), Some(&json!({"name": "Grace", "role": "member"})));
+}
+
+fn json_request(body: &str) -> Request {
+    request(Method::POST, "/", &[("content-type", "application/json")], body.as_bytes())
+}
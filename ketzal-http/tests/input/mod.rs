@@ -0,0 +1,2 @@
+pub mod accessors;
+pub mod sources;
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use http::Method;
+use ketzal_http::Request;
+use serde_json::json;
+
+use crate::helpers::request;
+
+/// Tests for merging query, JSON, form and multipart input
+
+#[test]
+fn body_values_take_precedence_over_query() {
+    let req = request(
+        Method::POST,
+        "/users?page=2&name=query",
+        &[("content-type", "application/json")],
+        br#"{"name": "body"}"#,
+    );
+
+    assert_eq!(req.string("name").as_deref(), Some("body"));
+    assert_eq!(req.string("page").as_deref(), Some("2"));
+}
+
+#[test]
+fn form_brackets_build_arrays_and_objects() {
+    let req = request(
+        Method::POST,
+        "/",
+        &[("content-type", "application/x-www-form-urlencoded")],
+        b"tags[]=a&tags[]=b&user[name]=Ada&user[langs][0]=rust",
+    );
+
+    assert_eq!(req.input("tags"), Some(&json!(["a", "b"])));
+    assert_eq!(req.input("user"), Some(&json!({"name": "Ada", "langs": ["rust"]})));
+    assert_eq!(req.form_value("user.langs.0"), "rust");
+}
+
+#[test]
+fn sparse_indexes_keep_their_positions() {
+    let req = request(
+        Method::POST,
+        "/",
+        &[("content-type", "application/x-www-form-urlencoded")],
+        b"items[5]=x&list[0]=a&list[2]=c",
+    );
+
+    assert_eq!(req.input("items"), Some(&json!({"5": "x"})));
+    assert_eq!(req.input("list"), Some(&json!({"0": "a", "2": "c"})));
+}
+
+#[test]
+fn query_map_is_used_when_the_path_has_no_query() {
+    let query = HashMap::from([("page".to_string(), "3".to_string())]);
+    let req = Request::new(
+        Method::GET,
+        "/users".to_string(),
+        query,
+        Default::default(),
+        Vec::new(),
+        HashMap::new(),
+    );
+
+    assert_eq!(req.integer("page"), Some(3));
+}
+
+#[test]
+fn reads_multipart_text_fields() {
+    let body =
+        "--XYZ\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\nHello\r\n--XYZ--\r\n";
+    let req = request(
+        Method::POST,
+        "/",
+        &[("content-type", "multipart/form-data; boundary=XYZ")],
+        body.as_bytes(),
+    );
+
+    assert_eq!(req.string("title").as_deref(), Some("Hello"));
+}
+
+#[test]
+fn malformed_body_keeps_query_values_and_reports_error() {
+    let req =
+        request(Method::POST, "/?page=1", &[("content-type", "application/json")], b"{not json");
+
+    assert_eq!(req.integer("page"), Some(1));
+    let resp = req.try_all().unwrap_err();
+    assert_eq!(resp.status, http::StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn body_is_parsed_once() {
+    let mut req = request(
+        Method::POST,
+        "/",
+        &[("content-type", "application/json")],
+        br#"{"name": "first"}"#,
+    );
+
+    assert_eq!(req.string("name").as_deref(), Some("first"));
+    req.body = br#"{"name": "second"}"#.to_vec();
+    assert_eq!(req.string("name").as_deref(), Some("first"));
+}
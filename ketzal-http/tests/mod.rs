@@ -0,0 +1,12 @@
+pub mod cookie;
+pub mod cors;
+pub mod csrf;
+pub mod extract;
+pub mod helpers;
+pub mod input;
+pub mod multipart;
+pub mod network;
+pub mod rate_limit;
+pub mod response;
+pub mod session;
+pub mod static_files;
@@ -0,0 +1,32 @@
+pub mod parser;
+pub mod validate;
+
+pub const BOUNDARY: &str = "----ketzal";
+
+/// A part given as `(name, filename, content type, content)`
+pub type Part<'a> = (&'a str, Option<&'a str>, Option<&'a str>, &'a [u8]);
+
+/// Builds a multipart body from the given parts
+pub fn body(parts: &[Part]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for (name, filename, content_type, content) in parts {
+        out.extend_from_slice(format!("--{BOUNDARY}\r\n").as_bytes());
+        let disposition = match filename {
+            Some(f) => format!("form-data; name=\"{name}\"; filename=\"{f}\""),
+            None => format!("form-data; name=\"{name}\""),
+        };
+        out.extend_from_slice(format!("Content-Disposition: {disposition}\r\n").as_bytes());
+        if let Some(ct) = content_type {
+            out.extend_from_slice(format!("Content-Type: {ct}\r\n").as_bytes());
+        }
+        out.extend_from_slice(b"\r\n");
+        out.extend_from_slice(content);
+        out.extend_from_slice(b"\r\n");
+    }
+    out.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+    out
+}
+
+pub fn content_type() -> String {
+    format!("multipart/form-data; boundary={BOUNDARY}")
+}
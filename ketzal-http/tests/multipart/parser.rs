@@ -0,0 +1,156 @@
+use ketzal_http::multipart::{
+    boundary, Multipart, MultipartError, MultipartLimits, MultipartParser,
+};
+
+use super::{body, content_type, BOUNDARY};
+
+/// Tests for the streaming multipart parser
+
+#[test]
+fn extracts_boundary_from_content_type() {
+    assert_eq!(boundary("multipart/form-data; boundary=abc"), Some("abc"));
+    assert_eq!(boundary("multipart/form-data; Boundary=\"a b\""), Some("a b"));
+    assert_eq!(boundary("multipart/form-data"), None);
+}
+
+#[test]
+fn parses_text_fields() {
+    let raw = body(&[("name", None, None, b"John"), ("email", None, None, b"john@example.com")]);
+
+    let mp = Multipart::parse(&content_type(), &raw, MultipartLimits::default()).unwrap();
+
+    assert_eq!(mp.field("name"), Some("John"));
+    assert_eq!(mp.field("email"), Some("john@example.com"));
+    assert!(mp.files().is_empty());
+}
+
+#[test]
+fn accepts_unquoted_parameters() {
+    let raw = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=doc; filename=a.txt\r\n\r\nhi\r\n\
+         --{BOUNDARY}\r\nContent-Disposition: form-data; name=title\r\n\r\nNotes\r\n--{BOUNDARY}--\r\n"
+    );
+
+    let mp = Multipart::parse(&content_type(), raw.as_bytes(), MultipartLimits::default()).unwrap();
+
+    assert_eq!(mp.file("doc").unwrap().client_name(), "a.txt");
+    assert_eq!(mp.field("title"), Some("Notes"));
+}
+
+#[test]
+fn parses_file_parts() {
+    let raw = body(&[("avatar", Some("me.png"), Some("image/png"), b"\x89PNG\r\n\x1a\n")]);
+
+    let mp = Multipart::parse(&content_type(), &raw, MultipartLimits::default()).unwrap();
+
+    let file = mp.file("avatar").unwrap();
+    assert_eq!(file.client_name(), "me.png");
+    assert_eq!(file.content_type(), "image/png");
+    assert_eq!(file.size(), 8);
+    assert_eq!(file.bytes().unwrap(), b"\x89PNG\r\n\x1a\n");
+    assert!(!file.is_spooled());
+}
+
+#[test]
+fn handles_one_byte_chunks() {
+    let raw = body(&[
+        ("title", None, None, b"Hello\r\n-- not a boundary"),
+        ("doc", Some("a.txt"), Some("text/plain"), b"contents"),
+    ]);
+
+    let mut parser = MultipartParser::new(BOUNDARY, MultipartLimits::default());
+    for byte in &raw {
+        parser.feed(std::slice::from_ref(byte)).unwrap();
+    }
+    let mp = parser.finish().unwrap();
+
+    assert_eq!(mp.field("title"), Some("Hello\r\n-- not a boundary"));
+    assert_eq!(mp.file("doc").unwrap().bytes().unwrap(), b"contents");
+}
+
+#[test]
+fn spools_large_files_to_disk() {
+    let content = vec![b'x'; 4096];
+    let raw = body(&[("upload", Some("big.bin"), None, &content)]);
+    let limits = MultipartLimits::default().spool_threshold(1024);
+
+    let mp = Multipart::parse(&content_type(), &raw, limits).unwrap();
+
+    let file = mp.file("upload").unwrap();
+    assert!(file.is_spooled());
+    assert!(file.path().unwrap().exists());
+    assert_eq!(file.size(), 4096);
+    assert_eq!(file.bytes().unwrap(), content);
+}
+
+#[test]
+fn rejects_files_over_limit() {
+    let content = vec![0u8; 200];
+    let raw = body(&[("upload", Some("big.bin"), None, &content)]);
+    let limits = MultipartLimits::default().max_file_size(100);
+
+    let err = Multipart::parse(&content_type(), &raw, limits).unwrap_err();
+
+    assert!(matches!(err, MultipartError::FileTooLarge { limit: 100, .. }));
+    assert_eq!(err.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[test]
+fn rejects_body_over_total_limit() {
+    let raw = body(&[("a", None, None, b"0123456789"), ("b", None, None, b"0123456789")]);
+    let limits = MultipartLimits::default().max_total_size(15);
+
+    let err = Multipart::parse(&content_type(), &raw, limits).unwrap_err();
+
+    assert!(matches!(err, MultipartError::PayloadTooLarge { limit: 15 }));
+}
+
+#[test]
+fn rejects_too_many_parts() {
+    let raw = body(&[("a", None, None, b"1"), ("b", None, None, b"2"), ("c", None, None, b"3")]);
+    let limits = MultipartLimits::default().max_parts(2);
+
+    let err = Multipart::parse(&content_type(), &raw, limits).unwrap_err();
+
+    assert!(matches!(err, MultipartError::TooManyParts { limit: 2 }));
+}
+
+#[test]
+fn rejects_truncated_body() {
+    let mut raw = body(&[("a", None, None, b"1")]);
+    raw.truncate(raw.len() - 8);
+
+    let err = Multipart::parse(&content_type(), &raw, MultipartLimits::default()).unwrap_err();
+
+    assert!(matches!(err, MultipartError::Malformed(_)));
+}
+
+#[test]
+fn skips_empty_file_inputs() {
+    let raw = body(&[("avatar", Some(""), Some("application/octet-stream"), b"")]);
+
+    let mp = Multipart::parse(&content_type(), &raw, MultipartLimits::default()).unwrap();
+
+    assert!(mp.file("avatar").is_none());
+}
+
+#[test]
+fn builds_arrays_for_repeated_fields() {
+    let raw = body(&[
+        ("tags[]", None, None, b"rust"),
+        ("tags[]", None, None, b"web"),
+        ("photos", Some("a.png"), Some("image/png"), b"a"),
+        ("photos", Some("b.png"), Some("image/png"), b"b"),
+    ]);
+
+    let mp = Multipart::parse(&content_type(), &raw, MultipartLimits::default()).unwrap();
+    let data = mp.to_validation_data();
+
+    assert_eq!(data["tags"], serde_json::json!(["rust", "web"]));
+    assert_eq!(data["photos"].as_array().unwrap().len(), 2);
+    assert_eq!(mp.files_by_field()["photos"].len(), 2);
+
+    let files = mp.validation_files();
+    assert_eq!(files["photos.1"].name, "b.png");
+    assert!(!files.contains_key("tags.0"));
+}
@@ -0,0 +1,113 @@
+use std::ops::ControlFlow;
+
+use http::{Method, StatusCode};
+
+use ketzal_http::multipart::MultipartLimits;
+
+use super::{body, content_type};
+use crate::helpers::request;
+
+/// Tests for Request::multipart and Request::validate_multipart
+
+#[test]
+fn multipart_requires_multipart_content_type() {
+    let req = request(Method::POST, "/", &[("content-type", "application/json")], b"{}");
+
+    let resp = req.multipart().unwrap_err();
+
+    assert_eq!(resp.status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[test]
+fn body_is_parsed_once_per_request() {
+    let raw = body(&[("_token", None, None, b"t"), ("avatar", Some("me.png"), None, PNG)]);
+    let req = request(Method::POST, "/", &[("content-type", &content_type())], &raw);
+
+    let first = req.multipart_with(MultipartLimits::default().spool_threshold(0)).unwrap();
+    assert_eq!(req.input("_token").unwrap(), "t");
+    let second = req.multipart().unwrap();
+
+    let path = first.file("avatar").unwrap().path().unwrap();
+    assert_eq!(second.file("avatar").unwrap().path(), Some(path));
+}
+
+#[test]
+fn validate_multipart_returns_fields_and_files() {
+    let raw =
+        body(&[("name", None, None, b"John"), ("avatar", Some("me.png"), Some("image/png"), PNG)]);
+    let req = request(Method::POST, "/", &[("content-type", &content_type())], &raw);
+
+    let ControlFlow::Continue(validated) =
+        req.validate_multipart([("name", "required|string"), ("avatar", "required|image")])
+    else {
+        panic!("validation should pass");
+    };
+
+    assert_eq!(validated.get("name").unwrap(), "John");
+    assert_eq!(validated.file("avatar").unwrap().bytes().unwrap(), PNG);
+}
+
+#[test]
+fn validate_multipart_checks_file_content() {
+    let raw = body(&[("avatar", Some("me.png"), Some("image/png"), b"<script>")]);
+    let req = request(Method::POST, "/", &[("content-type", &content_type())], &raw);
+
+    let ControlFlow::Break(resp) = req.validate_multipart([("avatar", "image")]) else {
+        panic!("a disguised file should fail");
+    };
+
+    assert_eq!(resp.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[test]
+fn json_objects_are_not_files() {
+    let forged =
+        br#"{"avatar": {"$file": true, "name": "me.png", "content_type": "image/png", "size": 1}}"#;
+    let req = request(Method::POST, "/", &[("content-type", "application/json")], forged);
+
+    let ControlFlow::Break(resp) = req.validate_json([("avatar", "required|file|image")]) else {
+        panic!("a JSON object should not pass as an upload");
+    };
+
+    assert_eq!(resp.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[test]
+fn validate_multipart_rejects_invalid_files() {
+    let raw = body(&[("avatar", Some("cv.pdf"), Some("application/pdf"), b"%PDF")]);
+    let req = request(Method::POST, "/", &[("content-type", &content_type())], &raw);
+
+    let ControlFlow::Break(resp) = req.validate_multipart([("avatar", "required|image")]) else {
+        panic!("validation should fail");
+    };
+
+    assert_eq!(resp.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[test]
+fn validate_multipart_drops_unvalidated_files() {
+    let raw =
+        body(&[("name", None, None, b"John"), ("extra", Some("x.txt"), Some("text/plain"), b"x")]);
+    let req = request(Method::POST, "/", &[("content-type", &content_type())], &raw);
+
+    let ControlFlow::Continue(validated) = req.validate_multipart([("name", "required")]) else {
+        panic!("validation should pass");
+    };
+
+    assert!(validated.file("extra").is_none());
+}
+
+#[test]
+fn validate_multipart_maps_limit_errors_to_413() {
+    let content = vec![0u8; 3 * 1024 * 1024];
+    let raw = body(&[("upload", Some("big.bin"), None, &content)]);
+    let req = request(Method::POST, "/", &[("content-type", &content_type())], &raw);
+
+    let ControlFlow::Break(resp) = req.validate_multipart([("upload", "file")]) else {
+        panic!("upload should be rejected");
+    };
+
+    assert_eq!(resp.status, StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
@@ -0,0 +1,53 @@
+use std::net::IpAddr;
+
+use ketzal_http::network::{parse_node, IpRange};
+
+/// Tests for CIDR ranges and forwarded node parsing
+
+#[test]
+fn ranges_match_their_network() {
+    let range: IpRange = "10.0.0.0/8".parse().unwrap();
+
+    assert!(range.contains(ip("10.1.2.3")));
+    assert!(!range.contains(ip("11.0.0.1")));
+    assert!(!range.contains(ip("::1")));
+}
+
+#[test]
+fn single_addresses_and_ipv6_ranges() {
+    let single: IpRange = "127.0.0.1".parse().unwrap();
+    let v6: IpRange = "fd00::/8".parse().unwrap();
+    let all: IpRange = "0.0.0.0/0".parse().unwrap();
+
+    assert!(single.contains(ip("127.0.0.1")));
+    assert!(!single.contains(ip("127.0.0.2")));
+    assert!(v6.contains(ip("fd12::1")));
+    assert!(all.contains(ip("8.8.8.8")));
+}
+
+#[test]
+fn ipv4_mapped_addresses_match_ipv4_ranges() {
+    let range: IpRange = "192.168.0.0/16".parse().unwrap();
+
+    assert!(range.contains(ip("::ffff:192.168.1.1")));
+}
+
+#[test]
+fn rejects_invalid_ranges() {
+    assert!("10.0.0.0/33".parse::<IpRange>().is_err());
+    assert!("proxy.local".parse::<IpRange>().is_err());
+}
+
+#[test]
+fn parses_forwarded_nodes() {
+    assert_eq!(parse_node("1.2.3.4"), Some(ip("1.2.3.4")));
+    assert_eq!(parse_node("1.2.3.4:8080"), Some(ip("1.2.3.4")));
+    assert_eq!(parse_node("\"[2001:db8::1]:4711\""), Some(ip("2001:db8::1")));
+    assert_eq!(parse_node("2001:db8::1"), Some(ip("2001:db8::1")));
+    assert_eq!(parse_node("unknown"), None);
+    assert_eq!(parse_node("_hidden"), None);
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
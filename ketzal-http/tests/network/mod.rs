@@ -0,0 +1,3 @@
+pub mod ip_range;
+pub mod proxy_protocol;
+pub mod request_info;
@@ -0,0 +1,74 @@
+use ketzal_http::protocol::proxy::{read_header, ProxyHeader};
+use tokio::io::AsyncReadExt;
+
+/// Tests for reading PROXY protocol headers
+
+#[tokio::test]
+async fn reads_v1_and_leaves_the_request_untouched() {
+    let mut input: &[u8] = b"PROXY TCP4 203.0.113.7 10.0.0.1 56324 443\r\nGET / HTTP/1.1\r\n";
+
+    let header = read_header(&mut input).await.unwrap();
+
+    assert_eq!(header.source, Some("203.0.113.7:56324".parse().unwrap()));
+    assert_eq!(header.destination, Some("10.0.0.1:443".parse().unwrap()));
+    assert_eq!(rest(&mut input).await, "GET / HTTP/1.1\r\n");
+}
+
+#[tokio::test]
+async fn reads_v1_ipv6_and_unknown() {
+    let mut v6: &[u8] = b"PROXY TCP6 2001:db8::1 2001:db8::2 1000 80\r\n";
+    let mut unknown: &[u8] = b"PROXY UNKNOWN\r\n";
+
+    let header = read_header(&mut v6).await.unwrap();
+
+    assert_eq!(header.source, Some("[2001:db8::1]:1000".parse().unwrap()));
+    assert_eq!(read_header(&mut unknown).await.unwrap(), ProxyHeader::default());
+}
+
+#[tokio::test]
+async fn reads_v2_ipv4() {
+    let mut bytes = signature();
+    bytes.extend_from_slice(&[0x21, 0x11, 0, 12]);
+    bytes.extend_from_slice(&[203, 0, 113, 7, 10, 0, 0, 1]);
+    bytes.extend_from_slice(&56324u16.to_be_bytes());
+    bytes.extend_from_slice(&443u16.to_be_bytes());
+    bytes.extend_from_slice(b"GET");
+    let mut input = bytes.as_slice();
+
+    let header = read_header(&mut input).await.unwrap();
+
+    assert_eq!(header.source, Some("203.0.113.7:56324".parse().unwrap()));
+    assert_eq!(rest(&mut input).await, "GET");
+}
+
+#[tokio::test]
+async fn reads_v2_local_command_and_skips_tlvs() {
+    let mut bytes = signature();
+    bytes.extend_from_slice(&[0x20, 0x00, 0, 3, 0xEE, 0, 0]);
+    bytes.extend_from_slice(b"GET");
+    let mut input = bytes.as_slice();
+
+    assert_eq!(read_header(&mut input).await.unwrap(), ProxyHeader::default());
+    assert_eq!(rest(&mut input).await, "GET");
+}
+
+#[tokio::test]
+async fn rejects_connections_without_header() {
+    let mut plain: &[u8] = b"GET / HTTP/1.1\r\n\r\n";
+    let mut garbage: &[u8] = b"PROXY TCP4 nope\r\n";
+    let long = [b"PROXY ".as_slice(), &[b'A'; 200]].concat();
+
+    assert!(read_header(&mut plain).await.is_err());
+    assert!(read_header(&mut garbage).await.is_err());
+    assert!(read_header(&mut long.as_slice()).await.is_err());
+}
+
+fn signature() -> Vec<u8> {
+    b"\r\n\r\n\0\r\nQUIT\n".to_vec()
+}
+
+async fn rest(input: &mut &[u8]) -> String {
+    let mut out = String::new();
+    input.read_to_string(&mut out).await.unwrap();
+    out
+}
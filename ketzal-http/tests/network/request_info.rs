@@ -0,0 +1,134 @@
+use std::net::IpAddr;
+
+use http::Method;
+use ketzal_http::network::TrustProxies;
+use ketzal_http::Request;
+
+use crate::helpers::request;
+
+/// Tests for client address, scheme, host and URL on Request
+
+#[test]
+fn uses_the_peer_and_host_header_by_default() {
+    let req =
+        req("203.0.113.7:5000", &[("host", "Example.com:8080"), ("x-forwarded-for", "1.1.1.1")]);
+
+    assert_eq!(req.peer_addr(), Some("203.0.113.7:5000".parse().unwrap()));
+    assert_eq!(req.ip(), Some(ip("203.0.113.7")));
+    assert_eq!(req.scheme(), "http");
+    assert_eq!(req.host().as_deref(), Some("example.com"));
+    assert_eq!(req.port(), 8080);
+    assert_eq!(req.full_url(), "http://example.com:8080/users?page=2");
+    assert_eq!(req.url(), "http://example.com:8080/users");
+}
+
+#[test]
+fn forwarded_headers_from_untrusted_peers_are_ignored() {
+    let mut req = req(
+        "203.0.113.7:5000",
+        &[("host", "app.test"), ("x-forwarded-for", "1.1.1.1"), ("x-forwarded-proto", "https")],
+    );
+    req.extensions.insert(TrustProxies::new(["10.0.0.0/8"]));
+
+    assert_eq!(req.ip(), Some(ip("203.0.113.7")));
+    assert!(!req.is_secure());
+}
+
+#[test]
+fn x_forwarded_headers_from_trusted_proxies() {
+    let mut req = req(
+        "10.0.0.2:5000",
+        &[
+            ("host", "internal:8080"),
+            ("x-forwarded-for", "198.51.100.1, 203.0.113.7, 10.0.0.9"),
+            ("x-forwarded-proto", "https"),
+            ("x-forwarded-host", "shop.example.com"),
+        ],
+    );
+    req.extensions.insert(TrustProxies::new(["10.0.0.0/8"]));
+
+    assert_eq!(req.ip(), Some(ip("203.0.113.7")));
+    assert!(req.is_secure());
+    assert_eq!(req.host().as_deref(), Some("shop.example.com"));
+    assert_eq!(req.port(), 443);
+    assert_eq!(req.full_url(), "https://shop.example.com/users?page=2");
+}
+
+#[test]
+fn forwarded_values_come_from_the_outermost_trusted_proxy() {
+    let mut req = req(
+        "10.0.0.2:5000",
+        &[
+            ("x-forwarded-for", "198.51.100.1, 203.0.113.7, 10.0.0.9"),
+            ("x-forwarded-proto", "https, http, http"),
+            ("x-forwarded-host", "evil.test, shop.example.com, internal"),
+            ("x-forwarded-port", "443, 8080, 80"),
+        ],
+    );
+    req.extensions.insert(TrustProxies::new(["10.0.0.0/8"]));
+
+    assert_eq!(req.scheme(), "http");
+    assert_eq!(req.host().as_deref(), Some("shop.example.com"));
+    assert_eq!(req.port(), 8080);
+}
+
+#[test]
+fn forwarded_elements_come_from_the_outermost_trusted_proxy() {
+    let mut req = req(
+        "10.0.0.2:5000",
+        &[("forwarded", "for=1.1.1.1;proto=https;host=evil.test, for=203.0.113.7;host=app.test")],
+    );
+    req.extensions.insert(TrustProxies::new(["10.0.0.0/8"]));
+
+    assert_eq!(req.scheme(), "http");
+    assert_eq!(req.host().as_deref(), Some("app.test"));
+}
+
+#[test]
+fn forwarded_header_wins_over_x_forwarded() {
+    let mut req = req(
+        "10.0.0.2:5000",
+        &[
+            ("forwarded", "for=\"[2001:db8::1]:4711\";proto=https;host=app.example.com"),
+            ("x-forwarded-for", "1.1.1.1"),
+        ],
+    );
+    req.extensions.insert(TrustProxies::all());
+
+    assert_eq!(req.ip(), Some(ip("2001:db8::1")));
+    assert_eq!(req.scheme(), "https");
+    assert_eq!(req.host().as_deref(), Some("app.example.com"));
+}
+
+#[test]
+fn forwarded_port_is_honored() {
+    let mut req = req(
+        "10.0.0.2:5000",
+        &[
+            ("x-forwarded-host", "app.test"),
+            ("x-forwarded-port", "8443"),
+            ("x-forwarded-proto", "https"),
+        ],
+    );
+    req.extensions.insert(TrustProxies::all());
+
+    assert_eq!(req.full_url(), "https://app.test:8443/users?page=2");
+}
+
+#[test]
+fn ip_is_none_without_a_socket() {
+    let req = request(Method::GET, "/", &[], b"");
+
+    assert_eq!(req.ip(), None);
+    assert_eq!(req.full_url(), "http://localhost/");
+}
+
+fn req(peer: &str, headers: &[(&str, &str)]) -> Request {
+    let mut req = request(Method::GET, "/users?page=2", headers, b"");
+    req.remote_addr = Some(peer.parse().unwrap());
+    req
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use ketzal_http::rate_limit::{Limit, MemoryStore, RateLimitStore};
+
+/// Tests for the in-memory rate limit store
+
+#[tokio::test]
+async fn fixed_window_allows_max_attempts() {
+    let store = MemoryStore::new();
+    let limit = Limit::per_minute(2);
+
+    let first = store.hit("k", &limit).await.unwrap();
+    let second = store.hit("k", &limit).await.unwrap();
+    let third = store.hit("k", &limit).await.unwrap();
+
+    assert!(first.allowed && second.allowed);
+    assert_eq!((first.remaining, second.remaining), (1, 0));
+    assert!(!third.allowed);
+    assert!(third.retry_after > Duration::from_secs(58));
+    assert!(third.retry_after <= Duration::from_secs(60));
+}
+
+#[tokio::test]
+async fn fixed_window_resets_after_the_window() {
+    let store = MemoryStore::new();
+    let limit = Limit::new(1, Duration::from_millis(20));
+
+    assert!(store.hit("k", &limit).await.unwrap().allowed);
+    assert!(!store.hit("k", &limit).await.unwrap().allowed);
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    assert!(store.hit("k", &limit).await.unwrap().allowed);
+}
+
+#[tokio::test]
+async fn keys_are_counted_separately() {
+    let store = MemoryStore::new();
+    let limit = Limit::per_minute(1);
+
+    assert!(store.hit("a", &limit).await.unwrap().allowed);
+    assert!(store.hit("b", &limit).await.unwrap().allowed);
+    assert!(!store.hit("a", &limit).await.unwrap().allowed);
+}
+
+#[tokio::test]
+async fn refunds_give_an_attempt_back() {
+    let store = MemoryStore::new();
+
+    for limit in [Limit::per_minute(1), Limit::per_minute(1).token_bucket()] {
+        let key = format!("{:?}", limit.algorithm());
+        assert!(store.hit(&key, &limit).await.unwrap().allowed);
+        store.refund(&key, &limit).await.unwrap();
+
+        assert!(store.hit(&key, &limit).await.unwrap().allowed, "{key}");
+        assert!(!store.hit(&key, &limit).await.unwrap().allowed, "{key}");
+    }
+}
+
+#[tokio::test]
+async fn token_bucket_allows_bursts_then_refills() {
+    let store = MemoryStore::new();
+    let limit = Limit::new(2, Duration::from_millis(40)).token_bucket();
+
+    assert!(store.hit("k", &limit).await.unwrap().allowed);
+    assert!(store.hit("k", &limit).await.unwrap().allowed);
+    let denied = store.hit("k", &limit).await.unwrap();
+    assert!(!denied.allowed);
+    assert!(denied.retry_after <= Duration::from_millis(20));
+
+    tokio::time::sleep(Duration::from_millis(25)).await;
+    assert!(store.hit("k", &limit).await.unwrap().allowed);
+}
+
+#[tokio::test]
+async fn token_bucket_without_attempts_denies() {
+    let store = MemoryStore::new();
+
+    for limit in [Limit::per_minute(0), Limit::new(0, Duration::ZERO)] {
+        let denied = store.hit("k", &limit.token_bucket()).await.unwrap();
+
+        assert!(!denied.allowed);
+        assert_eq!(denied.remaining, 0);
+    }
+    let limit = Limit::new(5, Duration::ZERO).token_bucket();
+    assert!(store.hit("k", &limit).await.unwrap().allowed);
+}
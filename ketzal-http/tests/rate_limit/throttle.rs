@@ -0,0 +1,107 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use http::{Method, StatusCode};
+use ketzal_http::middleware::{Endpoint, Next};
+use ketzal_http::rate_limit::{Limit, MemoryStore, RateLimiter, Throttle};
+use ketzal_http::{Request, Response};
+
+use crate::helpers::request;
+
+/// Tests for the Throttle middleware
+
+#[tokio::test]
+async fn named_limiter_answers_429_with_headers() {
+    let limiter = limiter();
+    limiter.add("api", |req: &Request| Limit::per_minute(2).by_ip(req));
+    let throttle = Throttle::named("api").using(limiter);
+
+    let first = run(&throttle, "10.0.0.1:1000").await;
+    let second = run(&throttle, "10.0.0.1:1000").await;
+    let third = run(&throttle, "10.0.0.1:1000").await;
+
+    assert_eq!(first.status, StatusCode::OK);
+    assert_eq!(first.headers["x-ratelimit-limit"], "2");
+    assert_eq!(first.headers["x-ratelimit-remaining"], "1");
+    assert_eq!(second.headers["x-ratelimit-remaining"], "0");
+
+    assert_eq!(third.status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(third.headers["x-ratelimit-remaining"], "0");
+    let retry_after: u64 = third.headers["retry-after"].to_str().unwrap().parse().unwrap();
+    assert!((59..=60).contains(&retry_after));
+    assert!(third.headers.contains_key("x-ratelimit-reset"));
+}
+
+#[tokio::test]
+async fn limits_are_segmented_by_key() {
+    let limiter = limiter();
+    limiter.add("api", |req: &Request| Limit::per_minute(1).by_ip(req));
+    let throttle = Throttle::named("api").using(limiter);
+
+    assert_eq!(run(&throttle, "10.0.0.1:1").await.status, StatusCode::OK);
+    assert_eq!(run(&throttle, "10.0.0.2:1").await.status, StatusCode::OK);
+    assert_eq!(run(&throttle, "10.0.0.1:2").await.status, StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn every_limit_of_a_limiter_applies() {
+    let limiter = limiter();
+    limiter.add("uploads", |_: &Request| vec![Limit::per_minute(5), Limit::per_hour(1)]);
+    let throttle = Throttle::named("uploads").using(limiter);
+
+    assert_eq!(run(&throttle, "10.0.0.1:1").await.status, StatusCode::OK);
+    assert_eq!(run(&throttle, "10.0.0.1:1").await.status, StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn rejected_requests_do_not_count_against_other_limits() {
+    let limiter = limiter();
+    limiter.add("uploads", |_: &Request| vec![Limit::per_minute(2), Limit::per_minute(1).by("b")]);
+    let throttle = Throttle::named("uploads").using(limiter.clone());
+
+    assert_eq!(run(&throttle, "10.0.0.1:1").await.status, StatusCode::OK);
+    assert_eq!(run(&throttle, "10.0.0.1:1").await.status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(run(&throttle, "10.0.0.1:1").await.status, StatusCode::TOO_MANY_REQUESTS);
+
+    limiter.add("uploads", |_: &Request| Limit::per_minute(2));
+    let res = run(&throttle, "10.0.0.1:1").await;
+
+    assert_eq!(res.status, StatusCode::OK);
+    assert_eq!(res.headers["x-ratelimit-remaining"], "0");
+}
+
+#[tokio::test]
+async fn unlimited_and_undefined_limiters_pass() {
+    let limiter = limiter();
+    limiter.add("internal", |_: &Request| Limit::none());
+
+    for name in ["internal", "missing"] {
+        let throttle = Throttle::named(name).using(limiter.clone());
+        for _ in 0..3 {
+            let res = run(&throttle, "10.0.0.1:1").await;
+            assert_eq!(res.status, StatusCode::OK);
+            assert!(!res.headers.contains_key("x-ratelimit-limit"));
+        }
+    }
+}
+
+#[tokio::test]
+async fn direct_limits_default_to_client_ip() {
+    let throttle = Throttle::limit(Limit::per_minute(1)).using(limiter());
+
+    assert_eq!(run(&throttle, "10.0.0.1:1").await.status, StatusCode::OK);
+    assert_eq!(run(&throttle, "10.0.0.2:1").await.status, StatusCode::OK);
+    assert_eq!(run(&throttle, "10.0.0.1:1").await.status, StatusCode::TOO_MANY_REQUESTS);
+}
+
+fn limiter() -> Arc<RateLimiter> {
+    Arc::new(RateLimiter::new(MemoryStore::new()))
+}
+
+async fn run(throttle: &Throttle, peer: &str) -> Response {
+    let mut req = request(Method::GET, "/", &[], b"");
+    req.remote_addr = Some(peer.parse::<SocketAddr>().unwrap());
+
+    let endpoint: Endpoint = Arc::new(|_| Box::pin(async { Response::ok("ok") }));
+    Next::new(Arc::new([Arc::new(throttle.clone()) as _]), endpoint).run(req).await
+}
@@ -0,0 +1,34 @@
+use http::{header, HeaderValue, StatusCode};
+use ketzal_http::response::FileBody;
+use ketzal_http::Response;
+
+/// Tests for HTTP/1.1 response encoding
+
+#[test]
+fn binary_bodies_are_written_verbatim() {
+    let body = vec![0xff, 0x00, 0xfe, b'\n'];
+
+    let bytes = Response::ok(body.clone()).to_bytes();
+
+    assert!(bytes.ends_with(&body));
+    assert!(String::from_utf8_lossy(&bytes).contains("Content-Length: 4\r\n"));
+}
+
+#[test]
+fn explicit_content_length_is_not_duplicated() {
+    let mut resp = Response::new(StatusCode::OK);
+    resp.headers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("99"));
+
+    let head = resp.head_string();
+
+    assert_eq!(head.matches("content-length").count() + head.matches("Content-Length").count(), 1);
+    assert!(head.contains("content-length: 99\r\n"));
+}
+
+#[test]
+fn file_bodies_count_towards_content_length() {
+    let resp = Response::with_file(StatusCode::OK, FileBody::new("/tmp/x", 10, 25));
+
+    assert_eq!(resp.content_length(), 25);
+    assert!(resp.head_string().contains("Content-Length: 25\r\n"));
+}
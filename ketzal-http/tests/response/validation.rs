@@ -0,0 +1,47 @@
+use http::header;
+use ketzal_http::config::ErrorFormat;
+use ketzal_http::Response;
+use ketzal_validation::{FieldError, ValidationErrors};
+use serde_json::Value;
+
+/// Tests for validation error responses
+
+#[test]
+fn laravel_format_answers_message_and_errors() {
+    let resp = Response::json_validation_error_as(ErrorFormat::Laravel, &errors());
+
+    assert_eq!(resp.status, 422);
+    assert_eq!(resp.headers[header::CONTENT_TYPE], "application/json");
+    let body: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(body["message"], "The field age must be at least 18.");
+    assert_eq!(body["errors"]["age"][0], "The field age must be at least 18.");
+}
+
+#[test]
+fn problem_format_answers_problem_details() {
+    let resp = Response::json_validation_error_as(ErrorFormat::Problem, &errors());
+
+    assert_eq!(resp.status, 422);
+    assert_eq!(resp.headers[header::CONTENT_TYPE], "application/problem+json");
+    let body: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(body["status"], 422);
+    assert_eq!(body["title"], "Unprocessable Content");
+    assert_eq!(body["errors"][0]["rule"], "min");
+    assert_eq!(body["errors"][0]["params"][0], "18");
+}
+
+#[test]
+fn laravel_is_the_default_format() {
+    assert_eq!(ErrorFormat::default(), ErrorFormat::Laravel);
+}
+
+fn errors() -> ValidationErrors {
+    [FieldError {
+        field: "age".into(),
+        rule: "min".into(),
+        params: vec!["18".into()],
+        message: "The field age must be at least 18.".into(),
+    }]
+    .into_iter()
+    .collect()
+}
@@ -0,0 +1,188 @@
+use std::time::Duration;
+
+use http::header;
+use ketzal_http::session::{MemoryStore, SessionMiddleware, SESSION_COOKIE};
+use ketzal_http::Response;
+
+use super::{keys, run, session_cookie};
+use crate::helpers::request;
+
+/// Tests for SessionMiddleware and the Request session API
+
+#[tokio::test]
+async fn values_survive_between_requests() {
+    let sessions = SessionMiddleware::new(MemoryStore::new(), keys());
+
+    let first = run(&sessions, None, |req| {
+        req.session().put("user_id", 42);
+        Response::ok("")
+    })
+    .await;
+    let cookie = session_cookie(&first).unwrap();
+
+    let second = run(&sessions, Some(&cookie), |req| {
+        Response::ok(req.session().get::<u32>("user_id").unwrap().to_string())
+    })
+    .await;
+
+    assert_eq!(second.body, b"42");
+}
+
+#[tokio::test]
+async fn session_cookie_is_encrypted_and_http_only() {
+    let store = MemoryStore::new();
+    let sessions = SessionMiddleware::new(store, keys());
+
+    let res = run(&sessions, None, |req| {
+        req.session().put("a", 1);
+        Response::ok("")
+    })
+    .await;
+
+    let set_cookie = res.headers.get(header::SET_COOKIE).unwrap().to_str().unwrap();
+    assert!(set_cookie.starts_with(&format!("{SESSION_COOKIE}=")));
+    assert!(set_cookie.contains("HttpOnly"));
+    assert!(set_cookie.contains("SameSite=Lax"));
+    assert!(set_cookie.contains("Max-Age=7200"));
+}
+
+#[tokio::test]
+async fn untouched_new_sessions_set_no_cookie() {
+    let sessions = SessionMiddleware::new(MemoryStore::new(), keys());
+
+    let res = run(&sessions, None, |req| {
+        let _ = req.session().get::<String>("missing");
+        Response::ok("")
+    })
+    .await;
+
+    assert!(res.headers.get(header::SET_COOKIE).is_none());
+}
+
+#[tokio::test]
+async fn flash_data_lasts_one_more_request() {
+    let sessions = SessionMiddleware::new(MemoryStore::new(), keys());
+    let read = |req: ketzal_http::Request| {
+        Response::ok(req.session().get::<String>("status").unwrap_or_default())
+    };
+
+    let first = run(&sessions, None, |req| {
+        req.session().flash("status", "saved");
+        Response::ok("")
+    })
+    .await;
+    let cookie = session_cookie(&first).unwrap();
+
+    let second = run(&sessions, Some(&cookie), read).await;
+    let third = run(&sessions, Some(&cookie), read).await;
+
+    assert_eq!(second.body, b"saved");
+    assert_eq!(third.body, b"");
+}
+
+#[tokio::test]
+async fn regenerate_discards_the_old_id() {
+    let store = MemoryStore::new();
+    let sessions = SessionMiddleware::new(store.clone(), keys());
+
+    let first = run(&sessions, None, |req| {
+        req.session().put("cart", 3);
+        Response::ok("")
+    })
+    .await;
+    let old = session_cookie(&first).unwrap();
+
+    let login = run(&sessions, Some(&old), |req| {
+        let session = req.session();
+        let before = session.id();
+        session.regenerate();
+        assert_ne!(before, session.id());
+        Response::ok("")
+    })
+    .await;
+    let new = session_cookie(&login).unwrap();
+
+    let with_old = run(&sessions, Some(&old), |req| {
+        Response::ok(req.session().get::<u32>("cart").map(|c| c.to_string()).unwrap_or_default())
+    })
+    .await;
+    let with_new = run(&sessions, Some(&new), |req| {
+        Response::ok(req.session().get::<u32>("cart").unwrap().to_string())
+    })
+    .await;
+
+    assert_eq!(store.len(), 1);
+    assert_eq!(with_old.body, b"");
+    assert_eq!(with_new.body, b"3");
+}
+
+#[tokio::test]
+async fn expired_sessions_start_over() {
+    let sessions = SessionMiddleware::new(MemoryStore::new(), keys()).lifetime(Duration::ZERO);
+
+    let first = run(&sessions, None, |req| {
+        req.session().put("a", 1);
+        Response::ok("")
+    })
+    .await;
+    let cookie = session_cookie(&first).unwrap();
+
+    let second =
+        run(&sessions, Some(&cookie), |req| Response::ok(req.session().is_new().to_string())).await;
+
+    assert_eq!(second.body, b"true");
+}
+
+#[tokio::test]
+async fn rolling_sessions_are_refreshed_on_every_request() {
+    let rolling = SessionMiddleware::new(MemoryStore::new(), keys());
+    let fixed = SessionMiddleware::new(MemoryStore::new(), keys()).rolling(false);
+
+    for (sessions, refreshed) in [(rolling, true), (fixed, false)] {
+        let first = run(&sessions, None, |req| {
+            req.session().put("a", 1);
+            Response::ok("")
+        })
+        .await;
+        let cookie = session_cookie(&first).unwrap();
+
+        let second = run(&sessions, Some(&cookie), |_| Response::ok("")).await;
+
+        assert_eq!(second.headers.contains_key(header::SET_COOKIE), refreshed);
+    }
+}
+
+#[tokio::test]
+async fn forged_cookies_start_a_new_session() {
+    let sessions = SessionMiddleware::new(MemoryStore::new(), keys());
+    let cookie = format!("{SESSION_COOKIE}=not-a-valid-value");
+
+    let res =
+        run(&sessions, Some(&cookie), |req| Response::ok(req.session().is_new().to_string())).await;
+
+    assert_eq!(res.body, b"true");
+}
+
+#[tokio::test]
+async fn pull_and_forget_remove_values() {
+    let sessions = SessionMiddleware::new(MemoryStore::new(), keys());
+
+    let res = run(&sessions, None, |req| {
+        let session = req.session();
+        session.put("a", "x");
+        session.put("b", "y");
+        let pulled: String = session.pull("a").unwrap();
+        session.forget("b");
+        Response::ok(format!("{pulled} {} {}", session.has("a"), session.has("b")))
+    })
+    .await;
+
+    assert_eq!(res.body, b"x false false");
+}
+
+#[test]
+fn session_is_absent_without_middleware() {
+    let req = request(http::Method::GET, "/", &[], b"");
+
+    assert!(req.try_session().is_none());
+}
@@ -0,0 +1,39 @@
+pub mod middleware;
+pub mod stores;
+
+use std::sync::Arc;
+
+use http::{header, Method};
+use ketzal_http::cookie::{Key, Keyring};
+use ketzal_http::middleware::{Endpoint, Middleware, Next};
+use ketzal_http::session::SessionMiddleware;
+use ketzal_http::{Request, Response};
+
+use crate::helpers::request;
+
+pub fn keys() -> Keyring {
+    Keyring::new(Key::from_app_key("session-test-key"))
+}
+
+/// Runs `handler` behind `sessions`, sending `cookie` if given
+pub async fn run<F>(sessions: &SessionMiddleware, cookie: Option<&str>, handler: F) -> Response
+where
+    F: Fn(Request) -> Response + Send + Sync + 'static,
+{
+    let headers: Vec<_> = cookie.map(|c| ("cookie", c)).into_iter().collect();
+    let req = request(Method::GET, "/", &headers, b"");
+
+    let endpoint: Endpoint = Arc::new(move |req| {
+        let res = handler(req);
+        Box::pin(async move { res })
+    });
+    let stack: Arc<[Arc<dyn Middleware>]> = Arc::new([Arc::new(sessions.clone()) as _]);
+
+    Next::new(stack, endpoint).run(req).await
+}
+
+/// The `name=value` part of the session cookie set by `res`
+pub fn session_cookie(res: &Response) -> Option<String> {
+    let value = res.headers.get(header::SET_COOKIE)?.to_str().ok()?;
+    value.split(';').next().map(str::to_string)
+}
@@ -0,0 +1,69 @@
+use ketzal_http::session::{
+    CookieStore, FileStore, SessionMiddleware, SessionRecord, SessionStore,
+};
+use ketzal_http::Response;
+use std::time::{Duration, SystemTime};
+
+use super::{keys, run, session_cookie};
+
+/// Tests for the bundled session stores
+
+#[tokio::test]
+async fn file_store_round_trips_records() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = FileStore::new(dir.path().join("sessions"));
+    let mut record = SessionRecord::new("abc_DEF-123");
+    record.data.insert("user_id".to_string(), 7.into());
+    record.set_expires_at(SystemTime::now() + Duration::from_secs(60));
+
+    let handle = store.save(&record).await.unwrap();
+
+    assert_eq!(handle, "abc_DEF-123");
+    assert_eq!(store.load(&handle).await.unwrap(), Some(record));
+
+    store.destroy(&handle).await.unwrap();
+    assert_eq!(store.load(&handle).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn file_store_rejects_path_like_handles() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("secret"), b"{}").unwrap();
+    let store = FileStore::new(dir.path().join("sessions"));
+
+    assert_eq!(store.load("../secret").await.unwrap(), None);
+    assert!(store.save(&SessionRecord::new("../x")).await.is_err());
+}
+
+#[tokio::test]
+async fn file_store_prunes_expired_sessions() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = FileStore::new(dir.path());
+    let mut live = SessionRecord::new("live");
+    live.set_expires_at(SystemTime::now() + Duration::from_secs(60));
+    store.save(&live).await.unwrap();
+    store.save(&SessionRecord::new("dead")).await.unwrap();
+
+    assert_eq!(store.prune().await.unwrap(), 1);
+    assert!(store.load("live").await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn cookie_store_keeps_data_client_side() {
+    let sessions = SessionMiddleware::new(CookieStore::new(), keys());
+
+    let first = run(&sessions, None, |req| {
+        req.session().put("theme", "dark");
+        Response::ok("")
+    })
+    .await;
+    let cookie = session_cookie(&first).unwrap();
+
+    let second = run(&sessions, Some(&cookie), |req| {
+        Response::ok(req.session().get::<String>("theme").unwrap())
+    })
+    .await;
+
+    assert!(!cookie.contains("dark"));
+    assert_eq!(second.body, b"dark");
+}
@@ -0,0 +1,30 @@
+pub mod range;
+pub mod serve;
+
+use std::fs;
+use std::path::Path;
+
+use tempfile::TempDir;
+
+/// Creates a public directory with a few assets
+pub fn public_dir() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+
+    write(root, "app.css", b"body { color: red; }");
+    write(root, "index.html", b"<h1>home</h1>");
+    write(root, "docs/index.html", b"<h1>docs</h1>");
+    write(root, "logo.png", &[0x89, b'P', b'N', b'G', 0, 1, 2, 3]);
+    write(root, "app.js", b"console.log('plain');");
+    write(root, "app.js.gz", b"gzipped-bytes");
+    write(root, "app.js.br", b"brotli-bytes");
+    write(root, ".env", b"SECRET=1");
+
+    dir
+}
+
+fn write(root: &Path, name: &str, content: &[u8]) {
+    let path = root.join(name);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
@@ -0,0 +1,39 @@
+use ketzal_http::static_files::{parse_range, ByteRange};
+
+/// Tests for Range header parsing
+
+#[test]
+fn parses_closed_range() {
+    assert_eq!(parse_range("bytes=0-9", 100), ByteRange::Partial { start: 0, len: 10 });
+}
+
+#[test]
+fn parses_open_ended_range() {
+    assert_eq!(parse_range("bytes=90-", 100), ByteRange::Partial { start: 90, len: 10 });
+}
+
+#[test]
+fn parses_suffix_range() {
+    assert_eq!(parse_range("bytes=-5", 100), ByteRange::Partial { start: 95, len: 5 });
+}
+
+#[test]
+fn clamps_end_to_file_length() {
+    assert_eq!(parse_range("bytes=50-500", 100), ByteRange::Partial { start: 50, len: 50 });
+}
+
+#[test]
+fn start_past_end_is_unsatisfiable() {
+    assert_eq!(parse_range("bytes=100-", 100), ByteRange::Unsatisfiable);
+}
+
+#[test]
+fn multiple_ranges_fall_back_to_full() {
+    assert_eq!(parse_range("bytes=0-1,5-6", 100), ByteRange::Full);
+}
+
+#[test]
+fn unknown_units_fall_back_to_full() {
+    assert_eq!(parse_range("items=0-1", 100), ByteRange::Full);
+    assert_eq!(parse_range("bytes=9-1", 100), ByteRange::Full);
+}
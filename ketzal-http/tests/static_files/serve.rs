@@ -0,0 +1,227 @@
+use http::{header, Method, StatusCode};
+use ketzal_http::static_files::StaticFiles;
+use ketzal_http::Response;
+
+use super::public_dir;
+use crate::helpers::request;
+
+/// Tests for StaticFiles::serve
+
+#[tokio::test]
+async fn serves_file_with_mime_type() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    let resp = get(&files, "app.css", &[]).await;
+
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(header(&resp, header::CONTENT_TYPE), "text/css; charset=utf-8");
+    assert_eq!(header(&resp, header::ACCEPT_RANGES), "bytes");
+    assert!(resp.headers.contains_key(header::ETAG));
+    assert!(resp.headers.contains_key(header::LAST_MODIFIED));
+    assert_eq!(body(&resp).await, b"body { color: red; }");
+}
+
+#[tokio::test]
+async fn keeps_binary_bodies_intact() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    let resp = get(&files, "logo.png", &[]).await;
+
+    assert_eq!(header(&resp, header::CONTENT_TYPE), "image/png");
+    assert_eq!(body(&resp).await, [0x89, b'P', b'N', b'G', 0, 1, 2, 3]);
+}
+
+#[tokio::test]
+async fn serves_index_files_for_directories() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    assert_eq!(body(&get(&files, "", &[]).await).await, b"<h1>home</h1>");
+    assert_eq!(body(&get(&files, "docs/", &[]).await).await, b"<h1>docs</h1>");
+}
+
+#[tokio::test]
+async fn configurable_index_files() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path()).index_files(["default.htm"]);
+
+    assert_eq!(get(&files, "docs", &[]).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn missing_files_are_not_found() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    assert_eq!(get(&files, "nope.txt", &[]).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn rejects_path_traversal() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path().join("docs"));
+
+    for path in ["../app.css", "..%2Fapp.css", "a/../../app.css", "%2e%2e/app.css", "..\\app.css"] {
+        assert_eq!(get(&files, path, &[]).await.status, StatusCode::NOT_FOUND, "{path}");
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn rejects_symlinks_leaving_the_root() {
+    let dir = public_dir();
+    let outside = tempfile::tempdir().unwrap();
+    std::fs::write(outside.path().join("secret.txt"), b"secret").unwrap();
+    std::os::unix::fs::symlink(outside.path().join("secret.txt"), dir.path().join("link.txt"))
+        .unwrap();
+    let files = StaticFiles::new(dir.path());
+
+    assert_eq!(get(&files, "link.txt", &[]).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn hides_dotfiles_by_default() {
+    let dir = public_dir();
+
+    let hidden = StaticFiles::new(dir.path());
+    let visible = StaticFiles::new(dir.path()).dotfiles(true);
+
+    assert_eq!(get(&hidden, ".env", &[]).await.status, StatusCode::NOT_FOUND);
+    assert_eq!(get(&visible, ".env", &[]).await.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn rejects_other_methods() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    let resp = files.serve(&request(Method::POST, "app.css", &[], b""), "app.css").await;
+
+    assert_eq!(resp.status, StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn head_sends_length_without_body() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    let resp = files.serve(&request(Method::HEAD, "app.css", &[], b""), "app.css").await;
+
+    assert_eq!(resp.status, StatusCode::OK);
+    assert!(resp.file.is_none());
+    assert_eq!(header(&resp, header::CONTENT_LENGTH), "20");
+}
+
+#[tokio::test]
+async fn answers_ranges_with_partial_content() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    let resp = get(&files, "app.css", &[("range", "bytes=0-3")]).await;
+
+    assert_eq!(resp.status, StatusCode::PARTIAL_CONTENT);
+    assert_eq!(header(&resp, header::CONTENT_RANGE), "bytes 0-3/20");
+    assert_eq!(resp.content_length(), 4);
+    assert_eq!(body(&resp).await, b"body");
+}
+
+#[tokio::test]
+async fn unsatisfiable_range_is_416() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    let resp = get(&files, "app.css", &[("range", "bytes=500-")]).await;
+
+    assert_eq!(resp.status, StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(header(&resp, header::CONTENT_RANGE), "bytes */20");
+}
+
+#[tokio::test]
+async fn stale_if_range_serves_full_file() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    let resp = get(&files, "app.css", &[("range", "bytes=0-3"), ("if-range", "\"old\"")]).await;
+
+    assert_eq!(resp.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn matching_etag_is_not_modified() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+    let etag = header(&get(&files, "app.css", &[]).await, header::ETAG).to_string();
+
+    let resp = get(&files, "app.css", &[("if-none-match", &format!("W/{etag}"))]).await;
+
+    assert_eq!(resp.status, StatusCode::NOT_MODIFIED);
+    assert!(resp.file.is_none());
+    assert_eq!(header(&resp, header::ETAG), etag);
+}
+
+#[tokio::test]
+async fn if_modified_since_is_not_modified() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+    let modified = header(&get(&files, "app.css", &[]).await, header::LAST_MODIFIED).to_string();
+
+    let resp = get(&files, "app.css", &[("if-modified-since", &modified)]).await;
+    let stale =
+        get(&files, "app.css", &[("if-modified-since", "Thu, 01 Jan 1970 00:00:00 GMT")]).await;
+
+    assert_eq!(resp.status, StatusCode::NOT_MODIFIED);
+    assert_eq!(stale.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn serves_precompressed_siblings() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path());
+
+    let br = get(&files, "app.js", &[("accept-encoding", "gzip, br")]).await;
+    let gz = get(&files, "app.js", &[("accept-encoding", "gzip, br;q=0")]).await;
+    let plain = get(&files, "app.js", &[]).await;
+
+    assert_eq!(header(&br, header::CONTENT_ENCODING), "br");
+    assert_eq!(header(&br, header::CONTENT_TYPE), "text/javascript; charset=utf-8");
+    assert_eq!(body(&br).await, b"brotli-bytes");
+    assert_eq!(header(&gz, header::CONTENT_ENCODING), "gzip");
+    assert_eq!(body(&gz).await, b"gzipped-bytes");
+    assert!(!plain.headers.contains_key(header::CONTENT_ENCODING));
+    assert_eq!(header(&plain, header::VARY), "Accept-Encoding");
+}
+
+#[tokio::test]
+async fn precompressed_can_be_disabled() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path()).precompressed(false);
+
+    let resp = get(&files, "app.js", &[("accept-encoding", "br")]).await;
+
+    assert!(!resp.headers.contains_key(header::CONTENT_ENCODING));
+    assert!(!resp.headers.contains_key(header::VARY));
+}
+
+#[tokio::test]
+async fn adds_configured_cache_headers() {
+    let dir = public_dir();
+    let files = StaticFiles::new(dir.path()).max_age(std::time::Duration::from_secs(3600));
+
+    let resp = get(&files, "app.css", &[]).await;
+
+    assert_eq!(header(&resp, header::CACHE_CONTROL), "public, max-age=3600");
+}
+
+async fn get(files: &StaticFiles, path: &str, headers: &[(&str, &str)]) -> Response {
+    files.serve(&request(Method::GET, path, headers, b""), path).await
+}
+
+async fn body(resp: &Response) -> Vec<u8> {
+    resp.file.as_ref().unwrap().read().await.unwrap()
+}
+
+fn header(resp: &Response, name: header::HeaderName) -> &str {
+    resp.headers.get(name).unwrap().to_str().unwrap()
+}
@@ -33,12 +33,22 @@
 //!     Response::ok(format!("Request for user {}", id))
 //! }
 //! ```
+//!
+//! ### With an Extractor
+//!
+//! Any [`FromRequest`] type may come last, after up to two path parameters.
+//! ```ignore
+//! async fn handler(id: i32, Json(user): Json<UpdateUser>) -> Response {
+//!     Response::ok(format!("Updating user {}", id))
+//! }
+//! ```
 
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
 
 use crate::params::Params;
+use ketzal_http::extract::FromRequest;
 use ketzal_http::{Request, Response};
 
 /// The return type for all handlers - a pinned boxed future that produces a Response.
@@ -164,6 +174,15 @@ pub struct Three<T, U, V>(PhantomData<(T, U, V)>);
 /// Marker type for handlers with Request and three parameters: `fn(Request, T, U, V) -> Response`
 pub struct WithReqThree<T, U, V>(PhantomData<(T, U, V)>);
 
+/// Marker type for handlers with only an extractor: `fn(E) -> Response`
+pub struct Extract<E>(PhantomData<E>);
+
+/// Marker type for handlers with one parameter and an extractor: `fn(T, E) -> Response`
+pub struct OneExtract<T, E>(PhantomData<(T, E)>);
+
+/// Marker type for handlers with two parameters and an extractor: `fn(T, U, E) -> Response`
+pub struct TwoExtract<T, U, E>(PhantomData<(T, U, E)>);
+
 /// The core Handler trait.
 ///
 /// This trait is implemented automatically for async functions with various signatures.
@@ -284,6 +303,55 @@ where
     }
 }
 
+// ── fn(E) -> Response ─────────────────────────────────────────────────────────
+impl<F, Fut, E> Handler<Extract<E>> for F
+where
+    F: Fn(E) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Response> + Send + 'static,
+    E: FromRequest + 'static,
+{
+    fn call(&self, _: &Params, req: Option<Request>) -> HandlerFuture {
+        let req = req.expect("Extract handler requires a Request");
+        match E::from_request(&req) {
+            Ok(e) => Box::pin(self(e)),
+            Err(r) => Box::pin(async move { r }),
+        }
+    }
+}
+
+impl<F, Fut, T, E> Handler<OneExtract<T, E>> for F
+where
+    F: Fn(T, E) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Response> + Send + 'static,
+    T: FromParam + 'static,
+    E: FromRequest + 'static,
+{
+    fn call(&self, params: &Params, req: Option<Request>) -> HandlerFuture {
+        let req = req.expect("OneExtract handler requires a Request");
+        match (<(T,)>::from_params(params), E::from_request(&req)) {
+            (Ok((t,)), Ok(e)) => Box::pin(self(t, e)),
+            (Err(r), _) | (_, Err(r)) => Box::pin(async move { r }),
+        }
+    }
+}
+
+impl<F, Fut, T, U, E> Handler<TwoExtract<T, U, E>> for F
+where
+    F: Fn(T, U, E) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Response> + Send + 'static,
+    T: FromParam + 'static,
+    U: FromParam + 'static,
+    E: FromRequest + 'static,
+{
+    fn call(&self, params: &Params, req: Option<Request>) -> HandlerFuture {
+        let req = req.expect("TwoExtract handler requires a Request");
+        match (<(T, U)>::from_params(params), E::from_request(&req)) {
+            (Ok((t, u)), Ok(e)) => Box::pin(self(t, u, e)),
+            (Err(r), _) | (_, Err(r)) => Box::pin(async move { r }),
+        }
+    }
+}
+
 /// Trait for boxed handlers that can be stored dynamically.
 ///
 /// This is used internally to store handlers in the [`Route`](crate::route::Route) struct.
@@ -6,9 +6,12 @@
 //!
 //! - HTTP Method Routing (GET, POST, PUT, DELETE, PATCH)
 //! - Path Parameters with `:param` syntax
+//! - Catch-all segments with `*param` syntax
 //! - Type-safe parameter extraction
 //! - Flexible handler signatures
+//! - Typed body extractors (`Json`, `Form`)
 //! - Route naming support
+//! - Route groups and per-route middleware
 //!
 //! ## Quick Example
 //!
@@ -32,5 +35,7 @@ pub mod route_node;
 pub mod router;
 
 pub use handler::{BoxedHandler, FromParam, FromParams, Handler, HandlerFuture};
+pub use ketzal_http::extract::{Form, FromRequest, Json, Valid};
 pub use route::Route;
+pub use route_group::RouteGroup;
 pub use router::Router;
@@ -96,7 +96,9 @@ impl Params {
 
 /// Matches a path pattern against an actual path and extracts parameters.
 ///
-/// Path patterns use the `:param_name` syntax for dynamic segments.
+/// Path patterns use the `:param_name` syntax for dynamic segments. A final
+/// `*param_name` segment is a catch-all that captures the rest of the path,
+/// slashes included (possibly empty).
 ///
 /// # Arguments
 ///
@@ -126,18 +128,35 @@ impl Params {
 /// // No match (static segment doesn't match)
 /// let params = match_path("/users/:id", "/posts/42");
 /// // params = None
+///
+/// // Catch-all
+/// let params = match_path("/assets/*path", "/assets/css/app.css");
+/// // params = Some(Params with "path" -> "css/app.css")
 /// ```
 pub fn match_path(pattern: &str, actual: &str) -> Option<Params> {
     let pattern_parts: Vec<&str> = pattern.trim_matches('/').split('/').collect();
     let actual_parts: Vec<&str> = actual.trim_matches('/').split('/').collect();
 
-    if pattern_parts.len() != actual_parts.len() {
+    let is_catch_all = pattern_parts.last().is_some_and(|p| p.starts_with('*'));
+
+    if !is_catch_all && pattern_parts.len() != actual_parts.len() {
         return None;
     }
 
     let mut params = Params::new();
 
-    for (p, a) in pattern_parts.iter().zip(actual_parts.iter()) {
+    for (i, p) in pattern_parts.iter().enumerate() {
+        if let Some(key) = p.strip_prefix('*') {
+            if key.is_empty() || i + 1 != pattern_parts.len() {
+                return None;
+            }
+            let rest = actual_parts.get(i..).map(|rest| rest.join("/")).unwrap_or_default();
+            params.insert(key, rest);
+            break;
+        }
+
+        let a = actual_parts.get(i)?;
+
         if let Some(key) = p.strip_prefix(':') {
             if key.is_empty() {
                 return None;
@@ -5,6 +5,8 @@
 use crate::handler::{into_boxed, BoxedHandler, Handler, HandlerFuture};
 use crate::params::Params;
 use http::Method;
+use ketzal_http::middleware::{Endpoint, Middleware, Next};
+use ketzal_http::static_files::StaticFiles;
 use ketzal_http::Request;
 use std::sync::Arc;
 
@@ -42,12 +44,17 @@ use std::sync::Arc;
 pub struct Route {
     /// The HTTP method for this route
     pub method: Method,
+    /// Whether HEAD requests also go to the handler, which then answers
+    /// them itself
+    pub head: bool,
     /// The path pattern (e.g., "/users/:id")
     pub path: String,
     /// The handler function
     pub handler: Arc<dyn BoxedHandler>,
     /// Optional route name for identification
     pub name: Option<String>,
+    /// Middleware run around this route's handler only
+    pub middleware: Arc<[Arc<dyn Middleware>]>,
 }
 
 impl Route {
@@ -70,7 +77,14 @@ impl Route {
     /// let route = Route::new(Method::GET, "/", handler);
     /// ```
     pub fn new<M: 'static>(method: Method, path: &str, handler: impl Handler<M>) -> Self {
-        Self { method, path: path.to_string(), handler: Arc::from(into_boxed(handler)), name: None }
+        Self {
+            method,
+            head: false,
+            path: path.to_string(),
+            handler: Arc::from(into_boxed(handler)),
+            name: None,
+            middleware: Arc::new([]),
+        }
     }
 
     /// Creates a GET route.
@@ -128,6 +142,34 @@ impl Route {
         Self::new(Method::PATCH, path, handler)
     }
 
+    /// Creates a GET and HEAD route that serves files from `files` below
+    /// `prefix`.
+    ///
+    /// The route uses a `*path` catch-all, so `/public/css/app.css` is looked
+    /// up as `css/app.css` inside the [`StaticFiles`] root.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// Route::static_files("/public", StaticFiles::new("public"))
+    /// ```
+    pub fn static_files(prefix: &str, files: StaticFiles) -> Self {
+        let files = Arc::new(files);
+        let path = format!("{}/*path", prefix.trim_end_matches('/'));
+
+        let mut route = Self::get(&path, move |req: Request, path: String| {
+            let files = files.clone();
+            async move { files.serve(&req, &path).await }
+        });
+        route.head = true;
+        route
+    }
+
+    /// Whether the route handles requests with `method`.
+    pub fn handles(&self, method: &Method) -> bool {
+        self.method == method || (self.head && method == Method::HEAD)
+    }
+
     /// Sets the name of the route.
     ///
     /// # Arguments
@@ -144,6 +186,28 @@ impl Route {
         self
     }
 
+    /// Adds a middleware that only wraps this route.
+    ///
+    /// Route middleware run inside the router's middleware, in the order
+    /// they are added.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// Route::post("/login", login).middleware(Throttle::named("login"))
+    /// ```
+    pub fn middleware(self, middleware: impl Middleware) -> Self {
+        self.layer(Arc::new(middleware))
+    }
+
+    /// Adds an already shared middleware. See [`Route::middleware`].
+    pub fn layer(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        let mut stack = self.middleware.to_vec();
+        stack.push(middleware);
+        self.middleware = stack.into();
+        self
+    }
+
     /// Calls the route handler with the given parameters and request.
     ///
     /// # Arguments
@@ -151,6 +215,15 @@ impl Route {
     /// * `params` - The extracted route parameters
     /// * `req` - The incoming request (if available)
     pub fn call(&self, params: &Params, req: Option<Request>) -> HandlerFuture {
-        self.handler.call(params, req)
+        let req = match req {
+            Some(req) if !self.middleware.is_empty() => req,
+            req => return self.handler.call(params, req),
+        };
+
+        let handler = self.handler.clone();
+        let params = params.clone();
+        let endpoint: Endpoint = Arc::new(move |req| handler.call(&params, Some(req)));
+
+        Next::new(self.middleware.clone(), endpoint).run(req)
     }
 }
@@ -1,11 +1,77 @@
 //! Route Group module
 //!
-//! Provides functionality for grouping routes with common prefixes.
-//!
-//! This module is currently under development.
+//! Provides [`RouteGroup`] for sharing a path prefix and middleware
+//! between routes.
 //!
 //! # Planned Features
 //!
-//! - Group routes with common path prefixes
-//! - Apply middleware to grouped routes
-//! - Nested route groups
+//! - Shared route name prefixes
+
+use crate::route::Route;
+use ketzal_http::middleware::Middleware;
+use std::sync::Arc;
+
+/// A set of routes sharing a path prefix and middleware.
+///
+/// Group middleware wrap the middleware of each route. Groups can be
+/// nested; the outer group's prefix and middleware come first.
+///
+/// # Example
+///
+/// ```ignore
+/// let api = RouteGroup::new("/api")
+///     .middleware(Throttle::named("api"))
+///     .route(Route::get("/users", list_users))
+///     .group(RouteGroup::new("/admin").route(Route::delete("/users/:id", delete_user)));
+///
+/// router.register_group(api);
+/// ```
+#[derive(Clone, Default)]
+pub struct RouteGroup {
+    prefix: String,
+    middleware: Vec<Arc<dyn Middleware>>,
+    routes: Vec<Route>,
+}
+
+impl RouteGroup {
+    /// Creates an empty group mounted below `prefix`.
+    pub fn new(prefix: &str) -> Self {
+        Self { prefix: prefix.trim_end_matches('/').to_string(), ..Self::default() }
+    }
+
+    /// Adds a middleware applied to every route of the group.
+    pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Adds a route; its path is relative to the group prefix.
+    pub fn route(mut self, route: Route) -> Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Nests another group below this one.
+    pub fn group(mut self, group: RouteGroup) -> Self {
+        self.routes.extend(group.into_routes());
+        self
+    }
+
+    /// The routes with the prefix and middleware applied.
+    pub fn into_routes(self) -> Vec<Route> {
+        self.routes
+            .into_iter()
+            .map(|mut route| {
+                route.path = match route.path.as_str() {
+                    "/" if !self.prefix.is_empty() => self.prefix.clone(),
+                    path => format!("{}{}", self.prefix, path),
+                };
+
+                let mut stack = self.middleware.clone();
+                stack.extend(route.middleware.iter().cloned());
+                route.middleware = stack.into();
+                route
+            })
+            .collect()
+    }
+}
@@ -9,4 +9,4 @@
 //!
 //! - Radix tree for faster route matching
 //! - Efficient path resolution
-//! - Support for wildcard and catch-all routes
+//! - Support for wildcard routes
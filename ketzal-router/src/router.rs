@@ -5,8 +5,12 @@
 use crate::handler::HandlerFuture;
 use crate::params::{match_path, Params};
 use crate::route::Route;
-use http::Method;
-use ketzal_http::Request;
+use crate::route_group::RouteGroup;
+use http::header::ALLOW;
+use http::{Method, StatusCode};
+use ketzal_http::middleware::{AllowedMethods, Middleware, Next};
+use ketzal_http::{Request, Response};
+use std::sync::Arc;
 
 /// The main router struct that holds all registered routes.
 ///
@@ -32,6 +36,7 @@ use ketzal_http::Request;
 #[derive(Clone, Default)]
 pub struct Router {
     routes: Vec<Route>,
+    middleware: Arc<[Arc<dyn Middleware>]>,
 }
 
 impl Router {
@@ -62,6 +67,17 @@ impl Router {
         self.routes.push(route);
     }
 
+    /// Registers every route of a [`RouteGroup`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// router.register_group(RouteGroup::new("/api").route(Route::get("/users", list)));
+    /// ```
+    pub fn register_group(&mut self, group: RouteGroup) {
+        self.routes.extend(group.into_routes());
+    }
+
     /// Handles an incoming request by matching it against registered routes.
     ///
     /// # Arguments
@@ -84,8 +100,9 @@ impl Router {
     /// }
     /// ```
     pub fn handle(&self, method: &Method, path: &str, req: Request) -> Option<HandlerFuture> {
+        let path = strip_query(path);
         for route in &self.routes {
-            if route.method != method {
+            if !route.handles(method) {
                 continue;
             }
             if route.path == path {
@@ -97,4 +114,104 @@ impl Router {
         }
         None
     }
+
+    /// Methods registered for routes matching `path`, in registration order.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// router.register(Route::get("/users/:id", show));
+    /// router.register(Route::delete("/users/:id", destroy));
+    ///
+    /// assert_eq!(router.allowed_methods("/users/42"), [Method::GET, Method::DELETE]);
+    /// ```
+    pub fn allowed_methods(&self, path: &str) -> Vec<Method> {
+        let path = strip_query(path);
+        let mut methods = Vec::new();
+        for route in &self.routes {
+            let matches = route.path == path || match_path(&route.path, path).is_some();
+            if !matches {
+                continue;
+            }
+            for method in
+                [Some(&route.method), route.head.then_some(&Method::HEAD)].into_iter().flatten()
+            {
+                if !methods.contains(method) {
+                    methods.push(method.clone());
+                }
+            }
+        }
+        methods
+    }
+
+    /// Adds a middleware that wraps every request dispatched by this router.
+    ///
+    /// Middleware run in registration order: the first one added is the
+    /// outermost layer.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut router = Router::new();
+    /// router.middleware(SessionMiddleware::new(MemoryStore::new(), keys));
+    /// ```
+    pub fn middleware(&mut self, middleware: impl Middleware) {
+        self.layer(Arc::new(middleware));
+    }
+
+    /// Adds an already shared middleware. See [`Router::middleware`].
+    pub fn layer(&mut self, middleware: Arc<dyn Middleware>) {
+        let mut stack = self.middleware.to_vec();
+        stack.push(middleware);
+        self.middleware = stack.into();
+    }
+
+    /// Runs the middleware stack around route dispatch.
+    ///
+    /// Unlike [`Router::handle`], unmatched requests still pass through the
+    /// middleware and end in a `404 Not Found` response. `OPTIONS` requests
+    /// carry the path's [`AllowedMethods`] for middleware such as CORS, and
+    /// are answered with `204` and an `Allow` header when no route takes them.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let router = Arc::new(router);
+    /// let response = router.dispatch(req).await;
+    /// ```
+    pub fn dispatch(self: Arc<Self>, mut req: Request) -> HandlerFuture {
+        if req.method == Method::OPTIONS {
+            let allowed = self.allowed_methods(&req.path);
+            req.extensions.insert(AllowedMethods(allowed));
+        }
+
+        let stack = self.middleware.clone();
+        let endpoint = Arc::new(move |req: Request| {
+            let (method, path) = (req.method.clone(), req.path.clone());
+            let allowed = req.extensions.get::<AllowedMethods>().cloned();
+
+            self.handle(&method, &path, req).unwrap_or_else(|| {
+                Box::pin(async move {
+                    match allowed {
+                        Some(allowed) if !allowed.0.is_empty() => options_response(&allowed),
+                        _ => Response::not_found(),
+                    }
+                })
+            })
+        });
+
+        Next::new(stack, endpoint).run(req)
+    }
+}
+
+/// The path of a request target, without its query string.
+fn strip_query(target: &str) -> &str {
+    target.split_once('?').map_or(target, |(path, _)| path)
+}
+
+/// Answer to an `OPTIONS` request no route handles.
+fn options_response(allowed: &AllowedMethods) -> Response {
+    let mut methods: Vec<&str> = allowed.0.iter().map(Method::as_str).collect();
+    methods.push(Method::OPTIONS.as_str());
+    Response::new(StatusCode::NO_CONTENT).with_header(ALLOW, methods.join(", "))
 }
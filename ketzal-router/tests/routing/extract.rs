@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use ketzal_http::{Request, Response};
+use ketzal_router::{Json, Route, Router};
+use serde::Deserialize;
+
+/// Tests for handlers taking request extractors
+
+#[derive(Deserialize)]
+struct Rename {
+    name: String,
+}
+
+#[tokio::test]
+async fn extractor_follows_path_params() {
+    let mut router = Router::new();
+    router.register(Route::post("/users/:id", |id: u64, Json(body): Json<Rename>| async move {
+        Response::ok(format!("{id}:{}", body.name))
+    }));
+
+    let resp = Arc::new(router).dispatch(post("/users/7", r#"{"name": "Ada"}"#)).await;
+
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.body, b"7:Ada");
+}
+
+#[tokio::test]
+async fn extractor_alone() {
+    let mut router = Router::new();
+    router.register(Route::post("/users", |Json(body): Json<Rename>| async move {
+        Response::ok(body.name)
+    }));
+
+    let resp = Arc::new(router).dispatch(post("/users", r#"{"name": "Grace"}"#)).await;
+
+    assert_eq!(resp.body, b"Grace");
+}
+
+#[tokio::test]
+async fn failed_extraction_skips_the_handler() {
+    let mut router = Router::new();
+    router.register(Route::post("/users", |_: Json<Rename>| async { Response::ok("called") }));
+
+    let resp = Arc::new(router).dispatch(post("/users", r#"{"name": 1}"#)).await;
+
+    assert_eq!(resp.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+fn post(path: &str, body: &str) -> Request {
+    let mut headers = HeaderMap::new();
+    headers.insert("content-type", HeaderValue::from_static("application/json"));
+    let body = body.as_bytes().to_vec();
+    Request::new(Method::POST, path.to_string(), HashMap::new(), headers, body, HashMap::new())
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use http::{HeaderMap, Method, StatusCode};
+use ketzal_http::middleware::{Next, ResponseFuture};
+use ketzal_http::{Request, Response};
+use ketzal_router::{Route, RouteGroup, Router};
+
+/// Tests for route groups and per-route middleware
+
+#[tokio::test]
+async fn group_prefixes_route_paths() {
+    let mut router = Router::new();
+    router.register_group(
+        RouteGroup::new("/api/")
+            .route(Route::get("/", || async { Response::ok("index") }))
+            .route(Route::get("/users/:id", |id: u64| async move { Response::ok(id.to_string()) }))
+            .group(
+                RouteGroup::new("/admin")
+                    .route(Route::get("/stats", || async { Response::ok("stats") })),
+            ),
+    );
+    let router = Arc::new(router);
+
+    assert_eq!(router.clone().dispatch(get("/api")).await.body, b"index");
+    assert_eq!(router.clone().dispatch(get("/api/users/7")).await.body, b"7");
+    assert_eq!(router.clone().dispatch(get("/api/admin/stats")).await.body, b"stats");
+    assert_eq!(router.dispatch(get("/users/7")).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn route_middleware_only_wraps_its_route() {
+    let mut router = Router::new();
+    router.register(Route::get("/open", || async { Response::ok("open") }));
+    router.register(Route::get("/closed", || async { Response::ok("closed") }).middleware(deny));
+    let router = Arc::new(router);
+
+    assert_eq!(router.clone().dispatch(get("/open")).await.status, StatusCode::OK);
+    assert_eq!(router.dispatch(get("/closed")).await.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn group_middleware_wraps_route_middleware() {
+    let mut router = Router::new();
+    router.register_group(
+        RouteGroup::new("/g").middleware(tag("group")).route(
+            Route::get("/", |req: Request| async move {
+                Response::ok(req.headers["x-trail"].to_str().unwrap().to_string())
+            })
+            .middleware(tag("route")),
+        ),
+    );
+
+    let resp = Arc::new(router).dispatch(get("/g")).await;
+
+    assert_eq!(resp.body, b"group,route");
+}
+
+fn deny(_: Request, _: Next) -> ResponseFuture {
+    Box::pin(async { Response::new(StatusCode::FORBIDDEN) })
+}
+
+fn tag(name: &'static str) -> impl Fn(Request, Next) -> ResponseFuture + Send + Sync {
+    move |mut req: Request, next: Next| -> ResponseFuture {
+        let trail = match req.headers.get("x-trail") {
+            Some(v) => format!("{},{name}", v.to_str().unwrap()),
+            None => name.to_string(),
+        };
+        req.headers.insert("x-trail", trail.parse().unwrap());
+        next.run(req)
+    }
+}
+
+fn get(path: &str) -> Request {
+    Request::new(
+        Method::GET,
+        path.to_string(),
+        HashMap::new(),
+        HeaderMap::new(),
+        Vec::new(),
+        HashMap::new(),
+    )
+}
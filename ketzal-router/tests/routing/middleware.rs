@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use http::{HeaderMap, Method, StatusCode};
+use ketzal_http::middleware::{Next, ResponseFuture};
+use ketzal_http::{Request, Response};
+use ketzal_router::{Route, Router};
+
+/// Tests for running middleware around route dispatch
+
+#[tokio::test]
+async fn middleware_wrap_handlers_in_registration_order() {
+    let mut router = Router::new();
+    router.register(Route::get("/", |req: Request| async move {
+        let trail = req.headers.get("x-trail").unwrap().to_str().unwrap().to_string();
+        Response::ok(trail)
+    }));
+    router.middleware(tag("outer"));
+    router.middleware(tag("inner"));
+
+    let resp = Arc::new(router).dispatch(get("/")).await;
+
+    assert_eq!(resp.body, b"outer,inner");
+    assert_eq!(resp.headers.get("x-after").unwrap(), "inner,outer");
+}
+
+#[tokio::test]
+async fn middleware_can_answer_early() {
+    let mut router = Router::new();
+    router.register(Route::get("/", || async { Response::ok("handler") }));
+    router.middleware(|_: Request, _: Next| -> ResponseFuture {
+        Box::pin(async { Response::new(StatusCode::FORBIDDEN) })
+    });
+
+    let resp = Arc::new(router).dispatch(get("/")).await;
+
+    assert_eq!(resp.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn unmatched_requests_still_pass_through_middleware() {
+    let mut router = Router::new();
+    router.middleware(tag("only"));
+
+    let resp = Arc::new(router).dispatch(get("/missing")).await;
+
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+    assert_eq!(resp.headers.get("x-after").unwrap(), "only");
+}
+
+#[tokio::test]
+async fn query_string_does_not_affect_matching() {
+    let mut router = Router::new();
+    router.register(Route::get("/search", || async { Response::ok("results") }));
+
+    let resp = Arc::new(router).dispatch(get("/search?q=rust&page=2")).await;
+
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.body, b"results");
+}
+
+/// Appends `name` to `x-trail` on the way in and to `x-after` on the way out
+fn tag(name: &'static str) -> impl Fn(Request, Next) -> ResponseFuture + Send + Sync {
+    move |mut req: Request, next: Next| -> ResponseFuture {
+        Box::pin(async move {
+            let trail = match req.headers.get("x-trail") {
+                Some(v) => format!("{},{name}", v.to_str().unwrap()),
+                None => name.to_string(),
+            };
+            req.headers.insert("x-trail", trail.parse().unwrap());
+
+            let resp = next.run(req).await;
+            let after = match resp.headers.get("x-after") {
+                Some(v) => format!("{},{name}", v.to_str().unwrap()),
+                None => name.to_string(),
+            };
+            resp.with_header("x-after", after)
+        })
+    }
+}
+
+fn get(path: &str) -> Request {
+    Request::new(
+        Method::GET,
+        path.to_string(),
+        HashMap::new(),
+        HeaderMap::new(),
+        Vec::new(),
+        HashMap::new(),
+    )
+}
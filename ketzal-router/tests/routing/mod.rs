@@ -0,0 +1,6 @@
+pub mod extract;
+pub mod groups;
+pub mod middleware;
+pub mod options;
+pub mod params;
+pub mod static_files;
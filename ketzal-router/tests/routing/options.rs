@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use ketzal_http::cors::Cors;
+use ketzal_http::{Request, Response};
+use ketzal_router::{Route, Router};
+
+/// Tests for OPTIONS handling and CORS preflight through the router
+
+#[test]
+fn lists_methods_registered_for_a_path() {
+    let router = users_router();
+
+    assert_eq!(router.allowed_methods("/users/1"), [Method::GET, Method::DELETE]);
+    assert_eq!(router.allowed_methods("/users/1?tab=posts"), [Method::GET, Method::DELETE]);
+    assert!(router.allowed_methods("/posts").is_empty());
+}
+
+#[tokio::test]
+async fn answers_options_with_allow_header() {
+    let resp = Arc::new(users_router()).dispatch(request(Method::OPTIONS, &[])).await;
+
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+    assert_eq!(resp.headers["allow"], "GET, DELETE, OPTIONS");
+}
+
+#[tokio::test]
+async fn cors_preflight_advertises_routed_methods() {
+    let mut router = users_router();
+    router.middleware(Cors::default());
+    let headers = [("origin", "https://spa.test"), ("access-control-request-method", "DELETE")];
+
+    let resp = Arc::new(router).dispatch(request(Method::OPTIONS, &headers)).await;
+
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+    assert_eq!(resp.headers["access-control-allow-origin"], "*");
+    assert_eq!(resp.headers["access-control-allow-methods"], "GET, DELETE");
+}
+
+#[tokio::test]
+async fn cors_preflight_ignores_the_query_string() {
+    let mut router = users_router();
+    router.middleware(Cors::default());
+    let headers = [("origin", "https://spa.test"), ("access-control-request-method", "DELETE")];
+    let mut req = request(Method::OPTIONS, &headers);
+    req.path = "/users/1?tab=posts".to_string();
+
+    let resp = Arc::new(router).dispatch(req).await;
+
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+    assert_eq!(resp.headers["access-control-allow-methods"], "GET, DELETE");
+}
+
+fn users_router() -> Router {
+    let mut router = Router::new();
+    router.register(Route::get("/users/:id", || async { Response::ok("show") }));
+    router.register(Route::delete("/users/:id", || async { Response::ok("destroy") }));
+    router.register(Route::get("/users/:id", || async { Response::ok("duplicate") }));
+    router
+}
+
+fn request(method: Method, headers: &[(&'static str, &'static str)]) -> Request {
+    let mut map = HeaderMap::new();
+    for (key, value) in headers {
+        map.insert(*key, HeaderValue::from_static(value));
+    }
+    Request::new(method, "/users/1".to_string(), HashMap::new(), map, Vec::new(), HashMap::new())
+}
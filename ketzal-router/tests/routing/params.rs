@@ -0,0 +1,54 @@
+use ketzal_router::params::match_path;
+
+/// Tests for path pattern matching
+
+#[test]
+fn matches_static_paths() {
+    assert!(match_path("/users", "/users").is_some());
+    assert!(match_path("/users", "/posts").is_none());
+}
+
+#[test]
+fn extracts_named_params() {
+    let params = match_path("/users/:id/posts/:post", "/users/1/posts/2").unwrap();
+
+    assert_eq!(params.get("id").unwrap(), "1");
+    assert_eq!(params.get("post").unwrap(), "2");
+}
+
+#[test]
+fn segment_count_must_match() {
+    assert!(match_path("/users/:id", "/users/42/posts").is_none());
+}
+
+#[test]
+fn catch_all_captures_rest_of_path() {
+    let params = match_path("/assets/*path", "/assets/css/app.css").unwrap();
+
+    assert_eq!(params.get("path").unwrap(), "css/app.css");
+}
+
+#[test]
+fn catch_all_may_be_empty() {
+    let params = match_path("/assets/*path", "/assets").unwrap();
+
+    assert_eq!(params.get("path").unwrap(), "");
+}
+
+#[test]
+fn catch_all_combines_with_named_params() {
+    let params = match_path("/sites/:site/*path", "/sites/blog/a/b").unwrap();
+
+    assert_eq!(params.get("site").unwrap(), "blog");
+    assert_eq!(params.get("path").unwrap(), "a/b");
+}
+
+#[test]
+fn catch_all_must_be_last_segment() {
+    assert!(match_path("/*path/edit", "/a/edit").is_none());
+}
+
+#[test]
+fn catch_all_still_checks_prefix() {
+    assert!(match_path("/assets/*path", "/public/app.css").is_none());
+}
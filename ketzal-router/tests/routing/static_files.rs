@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use http::{HeaderMap, Method, StatusCode};
+use ketzal_http::static_files::StaticFiles;
+use ketzal_http::Request;
+use ketzal_router::{Route, Router};
+
+/// Tests for mounting StaticFiles on a router
+
+#[tokio::test]
+async fn serves_files_below_prefix() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("css")).unwrap();
+    std::fs::write(dir.path().join("css/app.css"), b"body{}").unwrap();
+
+    let mut router = Router::new();
+    router.register(Route::static_files("/public/", StaticFiles::new(dir.path())));
+
+    let resp = router.handle(&Method::GET, "/public/css/app.css", get("/public/css/app.css"));
+    let resp = resp.unwrap().await;
+
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.file.unwrap().read().await.unwrap(), b"body{}");
+}
+
+#[tokio::test]
+async fn head_requests_are_served() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("app.js"), b"run()").unwrap();
+
+    let mut router = Router::new();
+    router.register(Route::static_files("/public", StaticFiles::new(dir.path())));
+
+    let mut req = get("/public/app.js");
+    req.method = Method::HEAD;
+    let resp = router.handle(&Method::HEAD, "/public/app.js", req).unwrap().await;
+
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.headers.get("content-length").unwrap(), "5");
+    assert!(resp.file.is_none());
+    assert_eq!(router.allowed_methods("/public/app.js"), [Method::GET, Method::HEAD]);
+}
+
+#[tokio::test]
+async fn query_strings_are_ignored() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("app.js"), b"run()").unwrap();
+
+    let mut router = Router::new();
+    router.register(Route::static_files("/public", StaticFiles::new(dir.path())));
+
+    let resp = Arc::new(router).dispatch(get("/public/app.js?v=3")).await;
+
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.file.unwrap().read().await.unwrap(), b"run()");
+}
+
+#[tokio::test]
+async fn other_prefixes_do_not_match() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut router = Router::new();
+    router.register(Route::static_files("/public", StaticFiles::new(dir.path())));
+
+    assert!(router.handle(&Method::GET, "/private/a.txt", get("/private/a.txt")).is_none());
+}
+
+fn get(path: &str) -> Request {
+    Request::new(
+        Method::GET,
+        path.to_string(),
+        HashMap::new(),
+        HeaderMap::new(),
+        Vec::new(),
+        HashMap::new(),
+    )
+}
@@ -0,0 +1,339 @@
+//! # ketzal-validation-derive
+//!
+//! `#[derive(FormRequest)]` for `ketzal-validation`. Use it through the
+//! re-export `ketzal_validation::FormRequest`.
+//!
+//! ```
+//! use ketzal_validation::{FormRequest, FromValidated};
+//! use serde::Deserialize;
+//! use serde_json::json;
+//!
+//! #[derive(Deserialize, FormRequest)]
+//! struct CreateUser {
+//!     #[rule("required|min:3")]
+//!     #[message(min = "Pick a longer name.")]
+//!     name: String,
+//!
+//!     #[rule("required|email")]
+//!     #[attribute("e-mail address")]
+//!     email: String,
+//! }
+//!
+//! let data = json!({"name": "Ada", "email": "ada@example.com"});
+//! let data = data.as_object().unwrap().clone().into_iter().collect();
+//!
+//! let user = CreateUser::from_validated(data).unwrap();
+//! assert_eq!(user.name, "Ada");
+//! ```
+//!
+//! Rule strings are checked while compiling: misspelled rule names, missing or
+//! unexpected parameters and empty rules are reported on the attribute. Other
+//! names are taken as custom rules registered at runtime.
+//!
+//! ```compile_fail
+//! #[derive(serde::Deserialize, ketzal_validation::FormRequest)]
+//! struct Login {
+//!     #[rule("requird|email")] // unknown validation rule `requird`, did you mean `required`?
+//!     email: String,
+//! }
+//! ```
+//!
+//! A custom rule whose name is close to a built-in one is listed in
+//! `#[custom_rules(...)]` on the struct:
+//!
+//! ```
+//! #[derive(serde::Deserialize, ketzal_validation::FormRequest)]
+//! #[custom_rules("zip")]
+//! struct Address {
+//!     #[rule("required|zip")]
+//!     code: String,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(serde::Deserialize, ketzal_validation::FormRequest)]
+//! struct Login {
+//!     #[rule("required|min")] // rule `min` requires a parameter
+//!     password: String,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(serde::Deserialize, ketzal_validation::FormRequest)]
+//! struct Login {
+//!     #[rule("required")]
+//!     #[message(email = "Not an e-mail.")] // message for rule `email`, which `login` does not use
+//!     login: String,
+//! }
+//! ```
+
+mod rules;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    bracketed, parse_macro_input, Data, DeriveInput, Error, Expr, Fields, Ident, Lit, LitStr, Meta,
+    Token,
+};
+
+/// Implements `FormRequest` and `FromValidated` from field attributes.
+///
+/// - `#[rule("required|email")]`: rules of the field; repeated attributes are
+///   joined with `|`.
+/// - `#[message(email = "...")]`: messages per rule, keyed `field.rule`.
+/// - `#[attribute("e-mail address")]`: the field's name in messages.
+/// - `#[custom_rules("zip")]` on the struct: custom rules whose names would
+///   otherwise be taken for typos of built-in ones.
+///
+/// The key of a field is its name, or its `#[serde(rename = "...")]`. Fields
+/// without rules are not part of the validated data, so they need a serde
+/// default to deserialize.
+#[proc_macro_derive(FormRequest, attributes(rule, message, attribute, custom_rules))]
+pub fn derive_form_request(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Checks rule strings at compile time and expands to nothing.
+///
+/// Used by the `validate_json!`, `validate_form!` and `validate_multipart!`
+/// macros of `ketzal`. Problems are reported on the offending literal.
+/// Custom rules whose names are close to built-in ones are listed first.
+///
+/// ```
+/// ketzal_validation::check_rules!("required|email", "min:8", "in:a,b");
+/// ketzal_validation::check_rules!("required|regex:/^(red|green):\\d+$/i|max:12");
+/// ketzal_validation::check_rules!(custom_rules = ["zip"]; "required|zip");
+/// ```
+///
+/// ```compile_fail
+/// ketzal_validation::check_rules!("requird|email"); // did you mean `required`?
+/// ```
+///
+/// ```compile_fail
+/// ketzal_validation::check_rules!("required|min:abc"); // rule `min` expects a number
+/// ```
+///
+/// ```compile_fail
+/// ketzal_validation::check_rules!("regex:/^[a-z+$/"); // rule `regex` has an invalid pattern
+/// ```
+#[proc_macro]
+pub fn check_rules(input: TokenStream) -> TokenStream {
+    let CheckRules { custom, specs } = parse_macro_input!(input as CheckRules);
+
+    let errors = specs.iter().filter_map(|lit| {
+        let checked = rules::check(&lit.value(), &custom);
+        checked.err().map(|msg| Error::new(lit.span(), msg).into_compile_error())
+    });
+
+    quote! { #(#errors)* }.into()
+}
+
+/// `[custom_rules = ["zip", ...];] "rules", ...`
+struct CheckRules {
+    custom: Vec<String>,
+    specs: Punctuated<LitStr, Token![,]>,
+}
+
+impl Parse for CheckRules {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut custom = Vec::new();
+        if input.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            if ident != "custom_rules" {
+                return Err(Error::new(ident.span(), "expected `custom_rules` or a rule string"));
+            }
+            input.parse::<Token![=]>()?;
+            let names;
+            bracketed!(names in input);
+            let names = Punctuated::<LitStr, Token![,]>::parse_terminated(&names)?;
+            custom = names.iter().map(LitStr::value).collect();
+            input.parse::<Token![;]>()?;
+        }
+
+        Ok(Self { custom, specs: Punctuated::parse_terminated(input)? })
+    }
+}
+
+/// The built-in rules known at compile time, as `&[(name, param kind)]`.
+///
+/// Lets `ketzal-validation` test that its registry matches this table.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __builtin_rules(_: TokenStream) -> TokenStream {
+    let entries = rules::BUILTIN.iter().map(|(name, param)| {
+        let kind = param.name();
+        quote! { (#name, #kind) }
+    });
+
+    quote! { &[#(#entries),*] }.into()
+}
+
+struct FieldSpec {
+    key: String,
+    rules: Vec<String>,
+    messages: Vec<(String, String, Span)>,
+    attribute: Option<String>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "FormRequest can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "FormRequest can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut custom = Vec::new();
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("custom_rules")) {
+        let names = attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+        custom.extend(names.iter().map(LitStr::value));
+    }
+
+    let specs = fields
+        .named
+        .iter()
+        .map(|field| field_spec(field, &custom))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let rules = specs
+        .iter()
+        .filter(|spec| !spec.rules.is_empty())
+        .map(|spec| {
+            let (key, rules) = (&spec.key, spec.rules.join("|"));
+            quote! { (#key, #rules) }
+        })
+        .collect::<Vec<_>>();
+    let messages = specs
+        .iter()
+        .flat_map(|spec| {
+            spec.messages.iter().map(|(rule, msg, _)| (format!("{}.{rule}", spec.key), msg))
+        })
+        .map(|(key, msg)| quote! { (#key, #msg) })
+        .collect::<Vec<_>>();
+    let attributes = specs
+        .iter()
+        .filter_map(|spec| spec.attribute.as_ref().map(|attr| (&spec.key, attr)))
+        .map(|(key, attr)| quote! { (#key, #attr) })
+        .collect::<Vec<_>>();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let map = quote! { ::std::collections::HashMap };
+
+    Ok(quote! {
+        impl #impl_generics ::ketzal_validation::FormRequest for #name #ty_generics #where_clause {
+            fn rules(&self) -> #map<&'static str, &'static str> {
+                #map::from([#(#rules),*])
+            }
+
+            fn messages(&self) -> #map<&'static str, &'static str> {
+                #map::from([#(#messages),*])
+            }
+
+            fn attributes(&self) -> #map<&'static str, &'static str> {
+                #map::from([#(#attributes),*])
+            }
+        }
+
+        impl #impl_generics ::ketzal_validation::FromValidated for #name #ty_generics #where_clause {
+            fn from_validated(
+                data: #map<::std::string::String, ::ketzal_validation::__private::Value>,
+            ) -> ::std::result::Result<Self, ::ketzal_validation::ValidationErrors> {
+                let validated = ::ketzal_validation::traits::validate_in(
+                    data,
+                    #map::from([#(#rules),*]),
+                    #map::from([#(#messages),*]),
+                    #map::from([#(#attributes),*]),
+                    ::std::option::Option::None,
+                )?;
+                ::ketzal_validation::traits::deserialize_validated(validated)
+            }
+
+            fn from_validated_in(
+                data: #map<::std::string::String, ::ketzal_validation::__private::Value>,
+                locale: &str,
+            ) -> ::std::result::Result<Self, ::ketzal_validation::ValidationErrors> {
+                let validated = ::ketzal_validation::traits::validate_in(
+                    data,
+                    #map::from([#(#rules),*]),
+                    #map::from([#(#messages),*]),
+                    #map::from([#(#attributes),*]),
+                    ::std::option::Option::Some(locale),
+                )?;
+                ::ketzal_validation::traits::deserialize_validated(validated)
+            }
+        }
+    })
+}
+
+fn field_spec(field: &syn::Field, custom: &[String]) -> syn::Result<FieldSpec> {
+    let ident = field.ident.as_ref().expect("named fields have identifiers");
+    let mut spec = FieldSpec {
+        key: ident.to_string().trim_start_matches("r#").to_string(),
+        rules: Vec::new(),
+        messages: Vec::new(),
+        attribute: None,
+    };
+
+    for attr in &field.attrs {
+        if attr.path().is_ident("rule") {
+            let lit: LitStr = attr.parse_args()?;
+            rules::check(&lit.value(), custom).map_err(|msg| Error::new(lit.span(), msg))?;
+            spec.rules.push(lit.value());
+        } else if attr.path().is_ident("message") {
+            attr.parse_nested_meta(|meta| {
+                let rule = meta.path.require_ident()?;
+                let name = rule.to_string().trim_start_matches("r#").to_string();
+                let msg: LitStr = meta.value()?.parse()?;
+                spec.messages.push((name, msg.value(), rule.span()));
+                Ok(())
+            })?;
+        } else if attr.path().is_ident("attribute") {
+            let lit: LitStr = attr.parse_args()?;
+            spec.attribute = Some(lit.value());
+        } else if attr.path().is_ident("serde") {
+            if let Some(rename) = serde_rename(attr)? {
+                spec.key = rename;
+            }
+        }
+    }
+
+    for (rule, _, span) in &spec.messages {
+        let used = spec.rules.iter().flat_map(|rules| rules::split(rules)).any(|r| {
+            let name = r.trim().split(':').next().unwrap_or_default();
+            name == rule
+        });
+        if !used {
+            let msg = format!("message for rule `{rule}`, which `{}` does not use", spec.key);
+            return Err(Error::new(*span, msg));
+        }
+    }
+
+    Ok(spec)
+}
+
+/// The value of `#[serde(rename = "...")]`, ignoring other serde options.
+fn serde_rename(attr: &syn::Attribute) -> syn::Result<Option<String>> {
+    let items = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+    Ok(items.into_iter().find_map(|item| match item {
+        Meta::NameValue(nv) if nv.path.is_ident("rename") => match nv.value {
+            Expr::Lit(expr) => match expr.lit {
+                Lit::Str(s) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }))
+}
@@ -0,0 +1,354 @@
+//! Compile-time checks for rule strings such as `"required|min:3"`.
+
+/// What a rule expects after `:`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Param {
+    None,
+    /// A number, possibly negative or decimal: `min:0.5`.
+    Number,
+    /// Two numbers, the first not above the second: `between:1,10`.
+    Range,
+    /// One or two whole numbers, the first not above the second: `decimal:2,4`.
+    Places,
+    /// A non-empty comma-separated list: `in:a,b`.
+    List,
+    /// Another field followed by one or more values: `required_if:role,admin`.
+    FieldValues,
+    /// Another field: `same:password`.
+    Field,
+    /// Another field or a literal: `gt:min_price`, `after:2024-01-01`.
+    Operand,
+    /// A delimited regular expression with optional flags: `regex:/^[a-z]+$/i`.
+    Pattern,
+    /// A PHP-style date format: `date_format:d/m/Y H:i`.
+    DateFormat,
+    /// A table, then optional columns and values: `unique:users,email,5`.
+    Table,
+    /// Nothing, or `allow_svg`: `image:allow_svg`.
+    Flag,
+}
+
+impl Param {
+    /// Name shared with `ketzal_validation::rules::spec::ParamKind`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Number => "number",
+            Self::Range => "range",
+            Self::Places => "places",
+            Self::List => "list",
+            Self::FieldValues => "field_values",
+            Self::Field => "field",
+            Self::Operand => "operand",
+            Self::Pattern => "pattern",
+            Self::DateFormat => "date_format",
+            Self::Table => "table",
+            Self::Flag => "flag",
+        }
+    }
+}
+
+/// Rules known to `ketzal_validation::rules_registry` and
+/// `ketzal_validation::async_rules_registry`.
+pub const BUILTIN: &[(&str, Param)] = &[
+    ("required", Param::None),
+    ("nullable", Param::None),
+    ("string", Param::None),
+    ("numeric", Param::None),
+    ("email", Param::None),
+    ("min", Param::Number),
+    ("max", Param::Number),
+    ("size", Param::Number),
+    ("between", Param::Range),
+    ("in", Param::List),
+    ("array", Param::None),
+    ("distinct", Param::None),
+    ("confirmed", Param::None),
+    ("required_if", Param::FieldValues),
+    ("required_unless", Param::FieldValues),
+    ("required_with", Param::List),
+    ("required_with_all", Param::List),
+    ("required_without", Param::List),
+    ("required_without_all", Param::List),
+    ("present", Param::None),
+    ("filled", Param::None),
+    ("sometimes", Param::None),
+    ("prohibited", Param::None),
+    ("prohibited_if", Param::FieldValues),
+    ("prohibited_unless", Param::FieldValues),
+    ("exclude_if", Param::FieldValues),
+    ("exclude_unless", Param::FieldValues),
+    ("same", Param::Field),
+    ("different", Param::Field),
+    ("gt", Param::Operand),
+    ("gte", Param::Operand),
+    ("lt", Param::Operand),
+    ("lte", Param::Operand),
+    ("before", Param::Operand),
+    ("before_or_equal", Param::Operand),
+    ("after", Param::Operand),
+    ("after_or_equal", Param::Operand),
+    ("accepted", Param::None),
+    ("accepted_if", Param::FieldValues),
+    ("file", Param::None),
+    ("image", Param::Flag),
+    ("mimes", Param::List),
+    ("mimetypes", Param::List),
+    ("url", Param::None),
+    ("uuid", Param::None),
+    ("ulid", Param::None),
+    ("ip", Param::None),
+    ("ipv4", Param::None),
+    ("ipv6", Param::None),
+    ("alpha", Param::None),
+    ("alpha_num", Param::None),
+    ("alpha_dash", Param::None),
+    ("regex", Param::Pattern),
+    ("not_regex", Param::Pattern),
+    ("starts_with", Param::List),
+    ("ends_with", Param::List),
+    ("json", Param::None),
+    ("lowercase", Param::None),
+    ("uppercase", Param::None),
+    ("date", Param::None),
+    ("date_format", Param::DateFormat),
+    ("date_equals", Param::Operand),
+    ("timezone", Param::None),
+    ("integer", Param::None),
+    ("decimal", Param::Places),
+    ("boolean", Param::None),
+    ("list", Param::None),
+    ("declined", Param::None),
+    ("declined_if", Param::FieldValues),
+    ("multiple_of", Param::Number),
+    ("bail", Param::None),
+    ("unique", Param::Table),
+    ("exists", Param::Table),
+];
+
+/// Checks every `|`-separated rule in `spec`, returning the first problem.
+pub fn check(spec: &str, custom: &[String]) -> Result<(), String> {
+    if spec.trim().is_empty() {
+        return Err("rule string is empty".to_string());
+    }
+
+    for rule in split(spec) {
+        check_rule(rule, custom)?;
+    }
+
+    Ok(())
+}
+
+/// Splits a rule string on `|`, keeping `regex:` patterns whole, as
+/// `ketzal_validation::split_rules` does.
+pub fn split(spec: &str) -> Vec<&str> {
+    let mut rules = Vec::new();
+    let mut start = 0;
+
+    while start <= spec.len() {
+        let segment = &spec[start..];
+        let indent = segment.len() - segment.trim_start().len();
+        let skip = pattern_len(segment.trim_start()).map_or(0, |len| indent + len);
+        let end = segment[skip..].find('|').map_or(spec.len(), |i| start + skip + i);
+
+        rules.push(spec[start..end].trim());
+        start = end + 1;
+    }
+
+    rules
+}
+
+/// Length of `regex:/.../` at the start of `segment`, up to the closing
+/// delimiter.
+fn pattern_len(segment: &str) -> Option<usize> {
+    let prefix = ["regex:", "not_regex:"].into_iter().find(|p| segment.starts_with(p))?;
+    let body = &segment[prefix.len()..];
+
+    let delimiter = body.chars().next()?;
+    if delimiter.is_alphanumeric() || delimiter.is_whitespace() || delimiter == '\\' {
+        return None;
+    }
+
+    let mut escaped = false;
+    for (i, c) in body.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == delimiter => return Some(prefix.len() + i + c.len_utf8()),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn check_rule(rule: &str, custom: &[String]) -> Result<(), String> {
+    if rule.is_empty() {
+        return Err("empty rule between `|` separators".to_string());
+    }
+
+    let (name, param) = match rule.split_once(':') {
+        Some((name, param)) => (name, Some(param)),
+        None => (rule, None),
+    };
+
+    if name.is_empty() {
+        return Err(format!("rule `{rule}` has no name"));
+    }
+
+    // Rules registered at runtime are unknown here. A name close to a
+    // built-in one is taken as a typo unless declared custom; any other
+    // name as a custom rule.
+    let Some((_, kind)) = BUILTIN.iter().find(|(known, _)| *known == name) else {
+        return match suggest(name) {
+            Some(known) if !custom.iter().any(|c| c == name) => Err(format!(
+                "unknown validation rule `{name}`, did you mean `{known}`? \
+                 A custom rule with this name must be listed in `custom_rules`"
+            )),
+            _ => Ok(()),
+        };
+    };
+
+    let param = match (kind, param) {
+        (Param::None, None) => return Ok(()),
+        (Param::None, Some(_)) => return Err(format!("rule `{name}` does not take a parameter")),
+        (Param::Flag, None) => return Ok(()),
+        (Param::Flag, Some(param)) if param.trim() == "allow_svg" => return Ok(()),
+        (Param::Flag, Some(param)) => {
+            return Err(format!("rule `{name}` only takes `allow_svg`, got `{param}`"));
+        }
+        (_, None | Some("")) => return Err(format!("rule `{name}` requires a parameter")),
+        (_, Some(param)) => param,
+    };
+
+    if *kind == Param::Pattern {
+        return pattern(param).map_err(|e| format!("rule `{name}` has an invalid pattern: {e}"));
+    }
+
+    if *kind == Param::DateFormat {
+        return date_format(param).map_err(|e| format!("rule `{name}` has an invalid format: {e}"));
+    }
+
+    let items: Vec<&str> = param.split(',').map(str::trim).collect();
+
+    match kind {
+        Param::Number if number(param).is_none() => {
+            Err(format!("rule `{name}` expects a number, got `{param}`"))
+        }
+        Param::Range => match items.as_slice() {
+            [low, high] => match (number(low), number(high)) {
+                (Some(low), Some(high)) if low <= high => Ok(()),
+                (Some(_), Some(_)) => Err(format!("rule `{name}` has a minimum above its maximum")),
+                _ => Err(format!("rule `{name}` expects two numbers, like `{name}:1,10`")),
+            },
+            _ => Err(format!("rule `{name}` expects two numbers, like `{name}:1,10`")),
+        },
+        Param::Places => match items.as_slice() {
+            [places] | [places, _] if places.parse::<usize>().is_err() => {
+                Err(format!("rule `{name}` expects whole numbers, like `{name}:2,4`"))
+            }
+            [_] => Ok(()),
+            [low, high] => match (low.parse::<usize>(), high.parse::<usize>()) {
+                (Ok(low), Ok(high)) if low <= high => Ok(()),
+                (Ok(_), Ok(_)) => Err(format!("rule `{name}` has a minimum above its maximum")),
+                _ => Err(format!("rule `{name}` expects whole numbers, like `{name}:2,4`")),
+            },
+            _ => Err(format!("rule `{name}` expects one or two whole numbers, like `{name}:2,4`")),
+        },
+        Param::List if items.iter().any(|item| item.is_empty()) => {
+            Err(format!("rule `{name}` has an empty item in `{param}`"))
+        }
+        Param::FieldValues if items.len() < 2 || items[0].is_empty() => {
+            Err(format!("rule `{name}` expects a field and a value, like `{name}:role,admin`"))
+        }
+        Param::Field | Param::Operand if items.len() > 1 => {
+            Err(format!("rule `{name}` expects a single field or value, got `{param}`"))
+        }
+        Param::Table if items[0].is_empty() => {
+            Err(format!("rule `{name}` expects a table, like `{name}:users,email`"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn number(param: &str) -> Option<f64> {
+    param.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+/// Compiles `/pattern/flags` the way `ketzal_validation::utils::pattern_regex`
+/// does.
+fn pattern(param: &str) -> Result<(), String> {
+    let mut chars = param.chars();
+    let delimiter = chars
+        .next()
+        .filter(|d| !d.is_alphanumeric() && !d.is_whitespace() && *d != '\\')
+        .ok_or_else(|| "pattern must start with a delimiter, like `/^[a-z]+$/`".to_string())?;
+
+    let body = &param[delimiter.len_utf8()..];
+    let end =
+        body.rfind(delimiter).ok_or_else(|| format!("pattern has no closing `{delimiter}`"))?;
+    let (pattern, flags) = (&body[..end], &body[end + delimiter.len_utf8()..]);
+
+    let mut builder = regex::RegexBuilder::new(pattern);
+    for flag in flags.chars() {
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'm' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'x' => builder.ignore_whitespace(true),
+            'u' => builder.unicode(true),
+            other => return Err(format!("unknown regex flag `{other}`")),
+        };
+    }
+
+    builder.build().map(|_| ()).map_err(|e| e.to_string())
+}
+
+/// Checks a PHP date format the way `ketzal_validation::utils::date::strftime`
+/// translates it.
+fn date_format(format: &str) -> Result<(), String> {
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.next().is_none() => {
+                return Err("date format ends with `\\`".to_string());
+            }
+            c if c.is_ascii_alphabetic() && !"djDlNwmnMFyYaAgGhHisuvOPUcr".contains(c) => {
+                return Err(format!("unsupported date format character `{c}`"));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// The closest known rule name, if it is a likely typo.
+fn suggest(name: &str) -> Option<&'static str> {
+    BUILTIN
+        .iter()
+        .map(|(known, _)| (*known, distance(name, known)))
+        .filter(|(_, d)| *d <= 2)
+        .min_by_key(|(_, d)| *d)
+        .map(|(known, _)| known)
+}
+
+/// Levenshtein distance.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            let next = (row[j + 1] + 1).min(row[j] + 1).min(prev + cost);
+            prev = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+
+    row[b.len()]
+}
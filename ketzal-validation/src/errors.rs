@@ -1,3 +1,189 @@
-use std::collections::HashMap;
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::ops::Index;
 
-pub type ValidationErrors = HashMap<String, Vec<String>>;
+/// One failed rule: the field, the rule name and parameters as written in
+/// the rule string, and the rendered message.
+///
+/// `rule` and `params` let clients localize the error themselves:
+/// `min:3` fails with rule `"min"` and params `["3"]`. Rule objects added
+/// with [`Validator::rule`](crate::Validator::rule) have the rule `"custom"`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub rule: String,
+    pub params: Vec<String>,
+    pub message: String,
+}
+
+/// Failed rules of a validation, in the order they were checked.
+///
+/// Fields are checked in the order of their keys, so the order is the same
+/// on every run. Messages are read per field like a map:
+/// `errors["email"]` is the list of messages of `email`.
+///
+/// Serializes as an object of fields to messages; see
+/// [`ValidationErrors::to_laravel`] and [`ValidationErrors::to_problem`] for
+/// response bodies.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+    /// Messages per field, in the order the fields first failed.
+    messages: Vec<(String, Vec<String>)>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a failed rule.
+    pub fn add(&mut self, error: FieldError) {
+        match self.messages.iter_mut().find(|(field, _)| *field == error.field) {
+            Some((_, messages)) => messages.push(error.message.clone()),
+            None => self.messages.push((error.field.clone(), vec![error.message.clone()])),
+        }
+        self.errors.push(error);
+    }
+
+    /// Every failed rule, in order.
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// The messages of `field`.
+    pub fn get(&self, field: &str) -> Option<&Vec<String>> {
+        self.messages.iter().find(|(f, _)| f == field).map(|(_, messages)| messages)
+    }
+
+    /// The first message of `field`.
+    pub fn first(&self, field: &str) -> Option<&str> {
+        self.get(field)?.first().map(String::as_str)
+    }
+
+    pub fn contains_key(&self, field: &str) -> bool {
+        self.get(field).is_some()
+    }
+
+    /// Removes `field`, returning its messages.
+    pub fn remove(&mut self, field: &str) -> Option<Vec<String>> {
+        let index = self.messages.iter().position(|(f, _)| f == field)?;
+        self.errors.retain(|error| error.field != field);
+        Some(self.messages.remove(index).1)
+    }
+
+    /// Number of fields with errors.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.errors.clear();
+        self.messages.clear();
+    }
+
+    /// Fields with errors, in order.
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.messages.iter().map(|(field, _)| field)
+    }
+
+    /// Messages of each field, in order.
+    pub fn values(&self) -> impl Iterator<Item = &Vec<String>> {
+        self.messages.iter().map(|(_, messages)| messages)
+    }
+
+    /// Fields and their messages, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Vec<String>)> {
+        self.messages.iter().map(|(field, messages)| (field, messages))
+    }
+
+    /// The first message, followed by how many more there are:
+    /// `The field name is required. (and 2 more errors)`.
+    pub fn summary(&self) -> String {
+        let Some(first) = self.errors.first() else {
+            return String::new();
+        };
+
+        match self.errors.len() - 1 {
+            0 => first.message.clone(),
+            1 => format!("{} (and 1 more error)", first.message),
+            more => format!("{} (and {more} more errors)", first.message),
+        }
+    }
+
+    /// Laravel's response body: `{"message": summary, "errors": {field: [messages]}}`.
+    pub fn to_laravel(&self) -> Value {
+        json!({ "message": self.summary(), "errors": self })
+    }
+
+    /// An RFC 9457 problem details body for a `422` response, with each
+    /// failed rule in `errors`:
+    ///
+    /// ```json
+    /// {
+    ///   "type": "about:blank",
+    ///   "title": "Unprocessable Content",
+    ///   "status": 422,
+    ///   "detail": "The field name is required.",
+    ///   "errors": [{ "field": "name", "rule": "required", "params": [], "message": "..." }]
+    /// }
+    /// ```
+    pub fn to_problem(&self) -> Value {
+        json!({
+            "type": "about:blank",
+            "title": "Unprocessable Content",
+            "status": 422,
+            "detail": self.summary(),
+            "errors": self.errors,
+        })
+    }
+}
+
+impl Index<&str> for ValidationErrors {
+    type Output = Vec<String>;
+
+    fn index(&self, field: &str) -> &Vec<String> {
+        self.get(field).unwrap_or_else(|| panic!("no errors for field `{field}`"))
+    }
+}
+
+impl FromIterator<FieldError> for ValidationErrors {
+    fn from_iter<I: IntoIterator<Item = FieldError>>(iter: I) -> Self {
+        let mut errors = Self::new();
+        for error in iter {
+            errors.add(error);
+        }
+        errors
+    }
+}
+
+impl Serialize for ValidationErrors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.messages.len()))?;
+        for (field, messages) in &self.messages {
+            map.serialize_entry(field, messages)?;
+        }
+        map.end()
+    }
+}
+
+/// A rule string that cannot be evaluated, reported by strict mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleError {
+    pub field: String,
+    pub rule: String,
+    pub reason: String,
+}
+
+impl std::fmt::Display for RuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid rule `{}` for field `{}`: {}", self.rule, self.field, self.reason)
+    }
+}
+
+impl std::error::Error for RuleError {}
@@ -0,0 +1,120 @@
+use serde_json::{Map, Value};
+
+/// Number of leading bytes [`FileMeta::with_head`] looks at.
+pub const SNIFF_LEN: usize = 64;
+
+/// Metadata of an uploaded file as seen by the validator.
+///
+/// Uploads are not JSON values, so transports (e.g. multipart parsing in
+/// `ketzal-http`) hand each file's `FileMeta` to
+/// [`Validator::set_files`](crate::Validator::set_files), keyed by field.
+/// The file rules only trust that table: a JSON object in the input that
+/// looks like a file is not one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileMeta {
+    pub name: String,
+    pub content_type: String,
+    pub size: u64,
+    /// The content type read from the file's leading bytes, if known.
+    pub detected: Option<&'static str>,
+}
+
+/// File types recognised by their leading bytes, with the extensions they
+/// may be uploaded under.
+const SIGNATURES: [(&str, &[&str]); 10] = [
+    ("image/png", &["png"]),
+    ("image/jpeg", &["jpg", "jpeg", "jpe"]),
+    ("image/gif", &["gif"]),
+    ("image/bmp", &["bmp"]),
+    ("image/webp", &["webp"]),
+    ("image/svg+xml", &["svg", "svgz"]),
+    ("application/pdf", &["pdf"]),
+    ("application/zip", &["zip", "docx", "xlsx", "pptx", "odt", "ods", "odp", "jar"]),
+    ("application/gzip", &["gz", "tgz"]),
+    ("video/mp4", &["mp4", "m4v", "m4a", "mov"]),
+];
+
+impl FileMeta {
+    pub fn new(name: impl Into<String>, content_type: impl Into<String>, size: u64) -> Self {
+        Self { name: name.into(), content_type: content_type.into(), size, detected: None }
+    }
+
+    /// Sets [`detected`](FileMeta::detected) from the first bytes of the
+    /// content; [`SNIFF_LEN`] bytes are enough.
+    pub fn with_head(mut self, head: &[u8]) -> Self {
+        self.detected = sniff(head);
+        self
+    }
+
+    /// Lower-cased extension of the client file name, without the dot.
+    pub fn extension(&self) -> Option<String> {
+        let (stem, ext) = self.name.rsplit_once('.')?;
+        if stem.is_empty() || ext.is_empty() {
+            return None;
+        }
+        Some(ext.to_ascii_lowercase())
+    }
+
+    /// Extensions the content may be uploaded under. For content with no
+    /// known signature, the client extension, unless it claims a type that
+    /// has one: a `.png` that does not start like a PNG has none.
+    pub fn content_extensions(&self) -> Vec<String> {
+        if let Some(detected) = self.detected {
+            let known = SIGNATURES.iter().find(|(mime, _)| *mime == detected);
+            return known.map_or_else(Vec::new, |(_, exts)| {
+                exts.iter().map(|ext| ext.to_string()).collect()
+            });
+        }
+
+        self.extension()
+            .filter(|ext| !SIGNATURES.iter().any(|(_, exts)| exts.contains(&ext.as_str())))
+            .into_iter()
+            .collect()
+    }
+
+    /// The content type of the content: the detected one, or for content
+    /// with no known signature the client's, unless it claims a type that
+    /// has one.
+    pub fn content_mime(&self) -> Option<String> {
+        if let Some(detected) = self.detected {
+            return Some(detected.to_string());
+        }
+
+        let declared = self.content_type.to_ascii_lowercase();
+        let declared = declared.split(';').next().unwrap_or_default().trim().to_string();
+        let known = SIGNATURES.iter().any(|(mime, _)| *mime == declared);
+        (!known && !declared.is_empty()).then_some(declared)
+    }
+
+    /// The value standing for the file in the validation data, so that
+    /// `required` and `validated_data` see it.
+    pub fn to_value(&self) -> Value {
+        let mut map = Map::new();
+        map.insert("name".to_string(), Value::String(self.name.clone()));
+        map.insert("content_type".to_string(), Value::String(self.content_type.clone()));
+        map.insert("size".to_string(), Value::from(self.size));
+        Value::Object(map)
+    }
+}
+
+fn sniff(head: &[u8]) -> Option<&'static str> {
+    let text = String::from_utf8_lossy(head);
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+
+    let mime = match head {
+        [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', ..] => "image/png",
+        [0xff, 0xd8, 0xff, ..] => "image/jpeg",
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => "image/gif",
+        [b'B', b'M', ..] => "image/bmp",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => "image/webp",
+        [b'%', b'P', b'D', b'F', b'-', ..] => "application/pdf",
+        [b'P', b'K', 0x03, 0x04, ..] => "application/zip",
+        [0x1f, 0x8b, ..] => "application/gzip",
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => "video/mp4",
+        _ if text.starts_with("<svg") || (text.starts_with("<?xml") && text.contains("<svg")) => {
+            "image/svg+xml"
+        }
+        _ => return None,
+    };
+    Some(mime)
+}
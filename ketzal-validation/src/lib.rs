@@ -1,10 +1,31 @@
 pub mod errors;
+pub mod file;
+pub mod messages;
+pub mod presence;
 pub mod rules;
 pub mod traits;
 pub mod utils;
 pub mod validator;
 
-pub use errors::ValidationErrors;
-pub use rules::{rules_registry, split_rule, Rule};
-pub use traits::FormRequest;
+pub use errors::{FieldError, RuleError, ValidationErrors};
+pub use file::FileMeta;
+pub use messages::{load_catalogs, register_catalog, Catalog};
+pub use presence::{MemoryVerifier, PresenceVerifier};
+pub use rules::{
+    async_rules_registry, register_rule, rules_registry, split_rule, split_rules,
+    AsyncValidationRule, Rule, RuleFn, ValidationRule,
+};
+pub use traits::{FormRequest, FromValidated};
 pub use validator::Validator;
+
+#[cfg(feature = "derive")]
+pub use ketzal_validation_derive::{check_rules, FormRequest};
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use ketzal_validation_derive::__builtin_rules;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json::Value;
+}
@@ -0,0 +1,167 @@
+//! Messages of a locale, loaded from JSON or TOML.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use arc_swap::ArcSwap;
+use serde::Deserialize;
+
+/// The messages of one locale, keyed by rule, and the names of fields.
+///
+/// Rules measured by size (`min`, `max`, `size`, `between`, `gt`, ...) can
+/// have one message per kind of value, like Laravel's language files:
+///
+/// ```json
+/// {
+///     "required": "Le champ :attribute est obligatoire.",
+///     "min": {
+///         "string": "Le champ :attribute doit contenir au moins :min caractères.",
+///         "numeric": "La valeur de :attribute doit être au moins :min."
+///     },
+///     "attributes": { "email": "adresse e-mail" }
+/// }
+/// ```
+///
+/// The kinds are `numeric`, `string`, `array` and `file`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct Catalog {
+    /// Names of fields in messages, keyed by field as in the rules.
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
+    #[serde(flatten)]
+    pub messages: HashMap<String, Message>,
+}
+
+/// The message of a rule in a [`Catalog`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Message {
+    Text(String),
+    /// One message per kind of value: `numeric`, `string`, `array`, `file`.
+    ByKind(HashMap<String, String>),
+}
+
+/// A catalog file that cannot be read.
+#[derive(Debug)]
+pub enum CatalogError {
+    Io(std::io::Error),
+    Parse(String),
+    /// A file that is neither `.json` nor `.toml`.
+    Extension(String),
+}
+
+impl std::fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "cannot read catalog: {err}"),
+            Self::Parse(err) => write!(f, "invalid catalog: {err}"),
+            Self::Extension(path) => write!(f, "catalog `{path}` is not a .json or .toml file"),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+impl From<std::io::Error> for CatalogError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl Catalog {
+    pub fn from_json(text: &str) -> Result<Self, CatalogError> {
+        serde_json::from_str(text).map_err(|err| CatalogError::Parse(err.to_string()))
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(text: &str) -> Result<Self, CatalogError> {
+        toml::from_str(text).map_err(|err| CatalogError::Parse(err.to_string()))
+    }
+
+    /// Reads a `.json` or `.toml` file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CatalogError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&text),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&text),
+            _ => Err(CatalogError::Extension(path.display().to_string())),
+        }
+    }
+
+    /// The message of `rule`, picking the one for `kind` when the rule has
+    /// a message per kind of value.
+    pub fn message(&self, rule: &str, kind: &str) -> Option<&str> {
+        match self.messages.get(rule)? {
+            Message::Text(text) => Some(text),
+            Message::ByKind(messages) => messages.get(kind).map(String::as_str),
+        }
+    }
+}
+
+type CatalogMap = HashMap<String, Arc<Catalog>>;
+
+static CATALOGS: LazyLock<ArcSwap<CatalogMap>> = LazyLock::new(ArcSwap::default);
+
+/// Serializes registrations; readers never take it.
+static REGISTER: Mutex<()> = Mutex::new(());
+
+/// Registers the messages of `locale`, replacing any catalog it had.
+pub fn register_catalog(locale: &str, catalog: Catalog) {
+    let _guard = REGISTER.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let mut catalogs = CatalogMap::clone(&CATALOGS.load());
+    catalogs.insert(normalize(locale), Arc::new(catalog));
+    CATALOGS.store(Arc::new(catalogs));
+}
+
+/// Registers every `.json` and `.toml` file of `dir` under its file name:
+/// `lang/fr.json` holds the `fr` catalog. Returns the registered locales,
+/// sorted.
+pub fn load_catalogs(dir: impl AsRef<Path>) -> Result<Vec<String>, CatalogError> {
+    let mut locales = Vec::new();
+
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        let locale = path.file_stem().and_then(|stem| stem.to_str());
+
+        let (Some("json" | "toml"), Some(locale)) = (extension, locale) else {
+            continue;
+        };
+
+        register_catalog(locale, Catalog::from_file(&path)?);
+        locales.push(normalize(locale));
+    }
+
+    locales.sort();
+    Ok(locales)
+}
+
+/// The registered locale that serves `locale`: the locale itself, or its
+/// language (`fr` for `fr-CA`). Tags compare case-insensitively, with `_`
+/// read as `-`.
+pub fn resolve_locale(locale: &str) -> Option<String> {
+    let catalogs = CATALOGS.load();
+    let locale = normalize(locale);
+
+    if catalogs.contains_key(&locale) {
+        return Some(locale);
+    }
+
+    let language = locale.split('-').next()?;
+    catalogs.contains_key(language).then(|| language.to_string())
+}
+
+/// The catalog serving `locale`, see [`resolve_locale`].
+pub fn catalog(locale: &str) -> Option<Arc<Catalog>> {
+    let locale = resolve_locale(locale)?;
+    CATALOGS.load().get(&locale).cloned()
+}
+
+fn normalize(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_ascii_lowercase()
+}
@@ -0,0 +1,169 @@
+//! Message templates and the locale catalogs they come from.
+//!
+//! Custom messages and catalog messages are templates. `:attribute` is the
+//! field's name (`:Attribute` capitalized, `:ATTRIBUTE` in capitals),
+//! `:input` its value, and the parameters of the rule have names of their
+//! own:
+//!
+//! - `:min`, `:max`, `:size` for `min`, `max`, `size` and `between`;
+//! - `:values` for lists such as `in:a,b` or `required_with:a,b`;
+//! - `:other` for the field named by `same`, `gt`, `required_if`, ..., and
+//!   `:value` / `:values` for the values after it;
+//! - `:date` for `after` and `before`, `:format` for `date_format`,
+//!   `:decimal` for `decimal` and `:value` for `multiple_of`.
+//!
+//! Other fields are shown with their custom attribute when they have one.
+
+mod catalog;
+
+pub use catalog::{
+    catalog, load_catalogs, register_catalog, resolve_locale, Catalog, CatalogError, Message,
+};
+
+use serde_json::Value;
+
+use crate::rules::basic::size::{measure, Unit};
+use crate::rules::spec::{self, ParamKind};
+use crate::utils::path;
+use crate::validator::Validator;
+
+/// Replaces the placeholders of `template` in one left-to-right pass, so
+/// text coming from a value is never read as a placeholder. Names start
+/// with `:`, and the longest one wins: `:values` is not read as `:value`
+/// followed by `s`.
+pub fn render(template: &str, placeholders: &[(String, String)]) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(at) = rest.find(':') {
+        message.push_str(&rest[..at]);
+        rest = &rest[at..];
+
+        let found = placeholders
+            .iter()
+            .filter(|(name, _)| rest.starts_with(name.as_str()))
+            .max_by_key(|(name, _)| name.len());
+        match found {
+            Some((name, value)) => {
+                message.push_str(value);
+                rest = &rest[name.len()..];
+            }
+            None => {
+                message.push(':');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    message.push_str(rest);
+    message
+}
+
+/// Whether the message key `pattern` applies to `key`, where `*` stands for
+/// any text, dots included: `*.required` covers `name.required` and
+/// `items.0.sku.required`.
+pub(crate) fn key_matches(pattern: &str, key: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+
+    let Some(mut rest) = key.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+/// The kind of `value` that picks a catalog message per kind, as measured
+/// by the size rules.
+pub(crate) fn kind(validator: &Validator, field: &str, value: Option<&Value>) -> &'static str {
+    match value.map(|value| measure(field, value, validator).1) {
+        Some(Unit::Number) => "numeric",
+        Some(Unit::Kilobytes) => "file",
+        Some(Unit::Items) => "array",
+        Some(Unit::Characters) | None => "string",
+    }
+}
+
+/// The placeholders of `rule` failing on `field`.
+pub(crate) fn placeholders(
+    validator: &Validator,
+    field: &str,
+    rule: &str,
+    param: Option<&str>,
+) -> Vec<(String, String)> {
+    let attribute = validator.attribute(field);
+    let mut capitalized = attribute.chars();
+    let capitalized = capitalized
+        .next()
+        .map(|first| first.to_uppercase().chain(capitalized).collect())
+        .unwrap_or_default();
+
+    let input = match validator.value(field) {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    };
+
+    let mut placeholders = vec![
+        (":ATTRIBUTE".to_string(), attribute.to_uppercase()),
+        (":Attribute".to_string(), capitalized),
+        (":attribute".to_string(), attribute),
+        (":input".to_string(), input),
+    ];
+
+    let Some(param) = param else {
+        return placeholders;
+    };
+
+    let params: Vec<&str> = param.split(',').map(str::trim).collect();
+    let name = |other: &str| validator.attribute(&path::substitute(other, field));
+    let mut add = |key: &str, value: String| placeholders.push((key.to_string(), value));
+
+    match (rule, spec::builtin_param(rule)) {
+        (_, Some(ParamKind::Range)) => {
+            add(":min", params[0].to_string());
+            add(":max", params.get(1).copied().unwrap_or_default().to_string());
+        }
+        ("multiple_of", _) => add(":value", param.trim().to_string()),
+        (_, Some(ParamKind::Number)) => add(&format!(":{rule}"), param.trim().to_string()),
+        (_, Some(ParamKind::Places)) => add(":decimal", params.join("-")),
+        (
+            "required_with" | "required_with_all" | "required_without" | "required_without_all",
+            _,
+        ) => add(":values", params.iter().map(|other| name(other)).collect::<Vec<_>>().join(", ")),
+        (_, Some(ParamKind::List)) => add(":values", params.join(", ")),
+        (_, Some(ParamKind::FieldValues)) => {
+            let values = params[1..].join(", ");
+            add(":other", name(params[0]));
+            add(":value", values.clone());
+            add(":values", values);
+        }
+        (_, Some(ParamKind::Field | ParamKind::Operand)) => {
+            let param = param.trim();
+            let shown = match validator.value(&path::substitute(param, field)) {
+                Some(_) => name(param),
+                None => param.to_string(),
+            };
+            add(":other", shown.clone());
+            add(":value", shown.clone());
+            add(":date", shown);
+        }
+        (_, Some(ParamKind::DateFormat)) => add(":format", param.to_string()),
+        (_, Some(ParamKind::None | ParamKind::Pattern | ParamKind::Table | ParamKind::Flag)) => {}
+        // Rules registered at runtime.
+        (_, None) => add(":values", params.join(", ")),
+    }
+
+    placeholders
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use serde_json::{Map, Value};
+
+use super::{Except, PresenceFuture, PresenceVerifier};
+
+/// Tables of JSON rows in process memory.
+///
+/// Values compare as text, so the row `{"id": 5}` matches `"5"` as a
+/// database would.
+///
+/// # Example
+///
+/// ```
+/// use ketzal_validation::presence::MemoryVerifier;
+/// use serde_json::json;
+///
+/// let users = MemoryVerifier::new().with_rows("users", [json!({ "id": 1, "email": "ada@example.com" })]);
+/// users.insert("users", json!({ "id": 2, "email": "alan@example.com" }));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoryVerifier {
+    tables: Arc<RwLock<HashMap<String, Vec<Row>>>>,
+}
+
+type Row = Map<String, Value>;
+
+impl MemoryVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `rows` to `table`.
+    pub fn with_rows(self, table: &str, rows: impl IntoIterator<Item = Value>) -> Self {
+        for row in rows {
+            self.insert(table, row);
+        }
+        self
+    }
+
+    /// Adds a row to `table`. Rows are JSON objects; other values are
+    /// ignored.
+    pub fn insert(&self, table: &str, row: Value) {
+        let Value::Object(row) = row else {
+            return;
+        };
+
+        let mut tables = self.tables.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        tables.entry(table.to_string()).or_default().push(row);
+    }
+}
+
+impl PresenceVerifier for MemoryVerifier {
+    fn count<'a>(
+        &'a self,
+        table: &'a str,
+        column: &'a str,
+        value: &'a Value,
+        except: Option<Except<'a>>,
+    ) -> PresenceFuture<'a> {
+        let tables = self.tables.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let expected = text(value);
+
+        let count = tables.get(table).map_or(0, |rows| {
+            rows.iter()
+                .filter(|row| row.get(column).is_some_and(|v| text(v) == expected))
+                .filter(|row| {
+                    except.is_none_or(|except| {
+                        row.get(except.column).is_none_or(|v| text(v) != except.value)
+                    })
+                })
+                .count()
+        });
+
+        Box::pin(std::future::ready(Ok(count as u64)))
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,61 @@
+//! Where `unique` and `exists` look values up.
+//!
+//! The rules ask a [`PresenceVerifier`] how many rows hold a value, so they
+//! work against any data source: [`MemoryVerifier`] for tests, or
+//! `SqlVerifier` with the `sql` feature. Set one with
+//! [`Validator::set_presence_verifier`](crate::Validator::set_presence_verifier).
+
+mod memory;
+#[cfg(feature = "sql")]
+mod sql;
+
+pub use memory::MemoryVerifier;
+#[cfg(feature = "sql")]
+pub use sql::SqlVerifier;
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+
+use serde_json::Value;
+
+/// The future returned by [`PresenceVerifier::count`].
+pub type PresenceFuture<'a> = Pin<Box<dyn Future<Output = io::Result<u64>> + Send + 'a>>;
+
+/// A row left out of a count: the one whose `column` is `value`, such as the
+/// record being updated in `unique:users,email,5`. Rows where `column` is
+/// null or missing are counted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Except<'a> {
+    pub column: &'a str,
+    pub value: &'a str,
+}
+
+/// Counts rows for the `unique` and `exists` rules.
+///
+/// # Example
+///
+/// ```ignore
+/// impl PresenceVerifier for ApiVerifier {
+///     fn count<'a>(
+///         &'a self,
+///         table: &'a str,
+///         column: &'a str,
+///         value: &'a Value,
+///         except: Option<Except<'a>>,
+///     ) -> PresenceFuture<'a> {
+///         Box::pin(async move { self.client.count(table, column, value, except).await })
+///     }
+/// }
+/// ```
+pub trait PresenceVerifier: Send + Sync {
+    /// Number of rows of `table` whose `column` equals `value`, without the
+    /// row matching `except`.
+    fn count<'a>(
+        &'a self,
+        table: &'a str,
+        column: &'a str,
+        value: &'a Value,
+        except: Option<Except<'a>>,
+    ) -> PresenceFuture<'a>;
+}
@@ -0,0 +1,147 @@
+use std::io;
+
+use serde_json::Value;
+use sqlx::any::AnyArguments;
+use sqlx::query::QueryScalar;
+use sqlx::{Any, AnyPool};
+
+use super::{Except, PresenceFuture, PresenceVerifier};
+
+/// Counts rows with `SELECT COUNT(*)` through an `sqlx` pool.
+///
+/// Works with the PostgreSQL, MySQL and SQLite drivers enabled in `sqlx`;
+/// other backends fail the check with an error.
+/// Tables and columns come from rule strings and must be plain identifiers
+/// (`users`, `auth.users`); values are bound with their JSON type.
+///
+/// # Example
+///
+/// ```ignore
+/// sqlx::any::install_default_drivers();
+/// let pool = AnyPool::connect("postgres://localhost/app").await?;
+/// validator.set_presence_verifier(Arc::new(SqlVerifier::new(pool)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SqlVerifier {
+    pool: AnyPool,
+}
+
+impl SqlVerifier {
+    pub fn new(pool: AnyPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// How the backend writes identifiers and parameters.
+#[derive(Clone, Copy)]
+enum Dialect {
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+impl Dialect {
+    fn of(backend: &str) -> io::Result<Self> {
+        match backend {
+            "PostgreSQL" => Ok(Self::Postgres),
+            "MySQL" => Ok(Self::MySql),
+            "SQLite" => Ok(Self::Sqlite),
+            other => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("the `{other}` database is not supported"),
+            )),
+        }
+    }
+
+    fn identifier(self, name: &str) -> io::Result<String> {
+        let valid = |part: &str| {
+            part.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        };
+        if !name.split('.').all(valid) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("`{name}` is not a valid table or column name"),
+            ));
+        }
+
+        let quote = match self {
+            Self::MySql => '`',
+            Self::Postgres | Self::Sqlite => '"',
+        };
+        Ok(name
+            .split('.')
+            .map(|part| format!("{quote}{part}{quote}"))
+            .collect::<Vec<_>>()
+            .join("."))
+    }
+
+    /// `column` as text, so it compares with a text parameter whatever its
+    /// type.
+    fn as_text(self, column: &str) -> String {
+        match self {
+            Self::MySql => format!("CAST({column} AS CHAR)"),
+            Self::Postgres | Self::Sqlite => format!("CAST({column} AS TEXT)"),
+        }
+    }
+
+    fn parameter(self, index: usize) -> String {
+        match self {
+            Self::Postgres => format!("${index}"),
+            Self::MySql | Self::Sqlite => "?".to_string(),
+        }
+    }
+}
+
+impl PresenceVerifier for SqlVerifier {
+    fn count<'a>(
+        &'a self,
+        table: &'a str,
+        column: &'a str,
+        value: &'a Value,
+        except: Option<Except<'a>>,
+    ) -> PresenceFuture<'a> {
+        Box::pin(async move {
+            let mut conn = self.pool.acquire().await.map_err(io::Error::other)?;
+            let dialect = Dialect::of(conn.backend_name())?;
+
+            let mut sql = format!(
+                "SELECT COUNT(*) FROM {} WHERE {} = {}",
+                dialect.identifier(table)?,
+                dialect.identifier(column)?,
+                dialect.parameter(1),
+            );
+            // `<>` alone would also leave out rows where the column is NULL.
+            // The except value comes from the rule string, so it is compared
+            // as text.
+            if let Some(except) = except {
+                let column = dialect.identifier(except.column)?;
+                let text = dialect.as_text(&column);
+                let param = dialect.parameter(2);
+                sql.push_str(&format!(" AND ({column} IS NULL OR {text} <> {param})"));
+            }
+
+            let mut query = bind(sqlx::query_scalar::<_, i64>(&sql), value);
+            if let Some(except) = except {
+                query = query.bind(except.value);
+            }
+
+            let count = query.fetch_one(&mut *conn).await.map_err(io::Error::other)?;
+            Ok(u64::try_from(count).unwrap_or_default())
+        })
+    }
+}
+
+type CountQuery<'q> = QueryScalar<'q, Any, i64, AnyArguments<'q>>;
+
+fn bind<'q>(query: CountQuery<'q>, value: &'q Value) -> CountQuery<'q> {
+    match value {
+        Value::String(s) => query.bind(s.as_str()),
+        Value::Bool(b) => query.bind(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(n) => query.bind(n),
+            None => query.bind(n.as_f64().unwrap_or_default()),
+        },
+        other => query.bind(other.to_string()),
+    }
+}
@@ -0,0 +1,100 @@
+//! Rules that wait on I/O, run by [`Validator::validate_async`].
+//!
+//! Built-in: `unique` and `exists`, which ask the validator's
+//! [`PresenceVerifier`](crate::presence::PresenceVerifier). Others are added
+//! with [`Validator::add_async_rule`]. [`Validator::validate`] skips async
+//! rules.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+
+use serde_json::Value;
+
+use super::database;
+use crate::validator::Validator;
+
+/// The future returned by [`AsyncValidationRule::passes`].
+pub type RuleFuture<'a> = Pin<Box<dyn Future<Output = io::Result<bool>> + Send + 'a>>;
+
+/// A validation rule that waits on I/O, such as a lookup in another service.
+///
+/// An error of the future ends validation: it is returned by
+/// [`Validator::validate_async`] rather than reported as a failed rule.
+///
+/// # Example
+///
+/// ```ignore
+/// struct NotBanned(Arc<BanList>);
+///
+/// impl AsyncValidationRule for NotBanned {
+///     fn passes<'a>(
+///         &'a self,
+///         _: &'a str,
+///         value: Option<&'a Value>,
+///         _: &'a [&'a str],
+///         _: &'a Validator,
+///     ) -> RuleFuture<'a> {
+///         Box::pin(async move { Ok(!self.0.contains(value).await?) })
+///     }
+/// }
+///
+/// validator.add_async_rule("not_banned", NotBanned(bans));
+/// ```
+pub trait AsyncValidationRule: Send + Sync {
+    /// Whether `value` passes. `params` are the comma-separated values after
+    /// `:` in the rule string.
+    fn passes<'a>(
+        &'a self,
+        field: &'a str,
+        value: Option<&'a Value>,
+        params: &'a [&'a str],
+        validator: &'a Validator,
+    ) -> RuleFuture<'a>;
+
+    /// The message when the rule fails, unless a custom message is set.
+    fn message(&self, field_name: &str, _params: &[&str]) -> String {
+        format!("The field {field_name} is invalid.")
+    }
+}
+
+pub(crate) type AsyncRuleMap = HashMap<String, Arc<dyn AsyncValidationRule>>;
+
+static ASYNC_RULES_REGISTRY: OnceLock<HashMap<&'static str, Arc<dyn AsyncValidationRule>>> =
+    OnceLock::new();
+
+/// The built-in async rules.
+pub fn async_rules_registry() -> &'static HashMap<&'static str, Arc<dyn AsyncValidationRule>> {
+    ASYNC_RULES_REGISTRY.get_or_init(|| {
+        let mut map: HashMap<&'static str, Arc<dyn AsyncValidationRule>> = HashMap::new();
+
+        map.insert("unique", Arc::new(database::Unique));
+        map.insert("exists", Arc::new(database::Exists));
+
+        map
+    })
+}
+
+/// Runs `rule`, rendering its message on failure. The outer error is the
+/// rule's own.
+pub(crate) async fn run(
+    rule: &dyn AsyncValidationRule,
+    name: &str,
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> io::Result<Result<(), String>> {
+    let params: Vec<&str> =
+        param.map(|p| p.split(',').map(str::trim).collect()).unwrap_or_default();
+
+    if rule.passes(field, value, &params, validator).await? {
+        return Ok(Ok(()));
+    }
+
+    let message = validator.message(field, name);
+    Ok(Err(message.unwrap_or_else(|| rule.message(field_name, &params))))
+}
@@ -0,0 +1,21 @@
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// Passes for JSON arrays and objects, which are keyed arrays.
+pub fn array(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if let Some(v) = value {
+        if !v.is_array() && !v.is_object() {
+            return Err(validator
+                .message(field, "array")
+                .unwrap_or_else(|| format!("The field {field_name} must be an array.")));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::utils::cast;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// `true`, `false`, `1`, `0`, `"1"`, `"0"`, `"true"` or `"false"`.
+pub fn boolean(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if let Some(v) = value {
+        if cast::as_bool(v).is_none() {
+            return Err(validator
+                .message(field, "boolean")
+                .unwrap_or_else(|| format!("The field {field_name} must be true or false.")));
+        }
+    }
+
+    Ok(())
+}
@@ -12,23 +12,18 @@ pub fn confirmed(
         return Ok(());
     };
 
-    let custom_message =
-        validator.custom_messages.get(&format!("{field}.confirmed")).map(String::as_str);
-
     let confirmation_key = format!("{field}_confirmation");
 
-    let Some(conf) = validator.data.get(&confirmation_key) else {
-        return Err(custom_message.map_or_else(
-            || format!("The confirmation of {field_name} does not match."),
-            ToString::to_string,
-        ));
+    let Some(conf) = validator.value(&confirmation_key) else {
+        return Err(validator
+            .message(field, "confirmed")
+            .unwrap_or_else(|| format!("The confirmation of {field_name} does not match.")));
     };
 
     if normalize(v) != normalize(conf) {
-        return Err(custom_message.map_or_else(
-            || format!("The confirmation of {field_name} does not match."),
-            ToString::to_string,
-        ));
+        return Err(validator
+            .message(field, "confirmed")
+            .unwrap_or_else(|| format!("The confirmation of {field_name} does not match.")));
     }
 
     Ok(())
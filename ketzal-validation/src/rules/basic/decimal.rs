@@ -0,0 +1,57 @@
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// A number with a number of decimal places: exactly 2 with `decimal:2`,
+/// from 2 to 4 with `decimal:2,4`.
+///
+/// Places are counted on the text of the value. JSON numbers lose trailing
+/// zeros, so `1.50` sent as a number has one place.
+pub fn decimal(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let (Some(v), Some((min, max))) = (value, param.and_then(places)) else {
+        return Ok(());
+    };
+
+    let text = match v {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        _ => String::new(),
+    };
+
+    if !decimal_places(&text).is_some_and(|n| n >= min && n <= max) {
+        let places = if min == max { min.to_string() } else { format!("{min}-{max}") };
+        return Err(validator.message(field, "decimal").unwrap_or_else(|| {
+            format!("The field {field_name} must have {places} decimal places.")
+        }));
+    }
+
+    Ok(())
+}
+
+/// `2` or `2,4`.
+fn places(param: &str) -> Option<(usize, usize)> {
+    let mut items = param.split(',').map(|p| p.trim().parse::<usize>());
+
+    let min = items.next()?.ok()?;
+    let max = items.next().transpose().ok()?.unwrap_or(min);
+
+    Some((min, max))
+}
+
+/// Decimal places of `[+-]digits[.digits]`, `None` for other text.
+fn decimal_places(text: &str) -> Option<usize> {
+    let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+    let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if whole.len() + fraction.len() == 0 || !digits(whole) || !digits(fraction) {
+        return None;
+    }
+
+    Some(fraction.len())
+}
@@ -0,0 +1,38 @@
+use crate::utils::path;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// On a wildcard key (`items.*.sku`), fails for values shared with another
+/// matched path. On an array, fails when two of its elements are equal.
+pub fn distinct(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    let Some(v) = value else {
+        return Ok(());
+    };
+
+    let pattern = validator.pattern_of(field);
+
+    let duplicated = if pattern.contains('*') {
+        path::expand(&validator.data, pattern)
+            .iter()
+            .filter(|other| other.as_str() != field)
+            .any(|other| validator.value(other) == Some(v))
+    } else if let Value::Array(items) = v {
+        items.iter().enumerate().any(|(i, item)| items[..i].contains(item))
+    } else {
+        false
+    };
+
+    if duplicated {
+        return Err(validator
+            .message(field, "distinct")
+            .unwrap_or_else(|| format!("The field {field_name} has a duplicate value.")));
+    }
+
+    Ok(())
+}
@@ -17,9 +17,7 @@ pub fn email(
 
     let s = v.as_str().ok_or_else(|| {
         validator
-            .custom_messages
-            .get(&format!("{field}.string"))
-            .cloned()
+            .message(field, "string")
             .unwrap_or_else(|| format!("The field {field_name} must be a string."))
     })?;
 
@@ -27,9 +25,7 @@ pub fn email(
 
     if !email_regex.is_match(s) {
         return Err(validator
-            .custom_messages
-            .get(&format!("{field}.email"))
-            .cloned()
+            .message(field, "email")
             .unwrap_or_else(|| format!("The field {field_name} must be a valid email.")));
     }
 
@@ -0,0 +1,22 @@
+use crate::utils::cast;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// A whole number, as a JSON number or as text: `42`, `"-7"`.
+pub fn integer(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if let Some(v) = value {
+        if cast::as_integer(v).is_none() {
+            return Err(validator
+                .message(field, "integer")
+                .unwrap_or_else(|| format!("The field {field_name} must be an integer.")));
+        }
+    }
+
+    Ok(())
+}
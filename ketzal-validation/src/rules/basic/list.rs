@@ -0,0 +1,21 @@
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// Passes for JSON arrays only; unlike `array`, objects fail.
+pub fn list(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if let Some(v) = value {
+        if !v.is_array() {
+            return Err(validator
+                .message(field, "list")
+                .unwrap_or_else(|| format!("The field {field_name} must be a list.")));
+        }
+    }
+
+    Ok(())
+}
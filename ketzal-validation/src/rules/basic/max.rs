@@ -1,3 +1,4 @@
+use super::size::{display_param, measure, parse_param, Unit};
 use crate::validator::Validator;
 use serde_json::Value;
 
@@ -8,18 +9,20 @@ pub fn max(
     validator: &Validator,
     param: Option<&str>,
 ) -> Result<(), String> {
-    let custom_message = validator.custom_messages.get(&format!("{field}.max")).map(String::as_str);
-    let max_val = param.unwrap_or("0").parse().unwrap_or(0);
+    let max_val = parse_param(param);
     if let Some(v) = value {
-        let size = match v {
-            Value::String(s) => s.len(),
-            Value::Number(n) => n.as_f64().unwrap_or(0.0) as usize,
-            Value::Array(arr) => arr.len(),
-            _ => 0,
-        };
+        let (size, unit) = measure(field, v, validator);
         if size > max_val {
-            return Err(custom_message.map(ToString::to_string).unwrap_or_else(|| {
-                format!("The field {field_name} may not be greater than {max_val} characters.")
+            let n = display_param(param);
+            return Err(validator.message(field, "max").unwrap_or_else(|| match unit {
+                Unit::Number => format!("The field {field_name} may not be greater than {n}."),
+                Unit::Kilobytes => {
+                    format!("The field {field_name} may not be greater than {n} kilobytes.")
+                }
+                Unit::Items => format!("The field {field_name} may not have more than {n} items."),
+                Unit::Characters => {
+                    format!("The field {field_name} may not be greater than {n} characters.")
+                }
             }));
         }
     }
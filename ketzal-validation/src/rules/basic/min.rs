@@ -1,3 +1,4 @@
+use super::size::{display_param, measure, parse_param, Unit};
 use crate::validator::Validator;
 use serde_json::Value;
 
@@ -8,18 +9,20 @@ pub fn min(
     validator: &Validator,
     param: Option<&str>,
 ) -> Result<(), String> {
-    let custom_message = validator.custom_messages.get(&format!("{field}.min")).map(String::as_str);
-    let min_val = param.unwrap_or("0").parse().unwrap_or(0);
+    let min_val = parse_param(param);
     if let Some(v) = value {
-        let size = match v {
-            Value::String(s) => s.len(),
-            Value::Number(n) => n.as_f64().unwrap_or(0.0) as usize,
-            Value::Array(arr) => arr.len(),
-            _ => 0,
-        };
+        let (size, unit) = measure(field, v, validator);
         if size < min_val {
-            return Err(custom_message.map(ToString::to_string).unwrap_or_else(|| {
-                format!("The field {field_name} must be at least {min_val} characters.")
+            let n = display_param(param);
+            return Err(validator.message(field, "min").unwrap_or_else(|| match unit {
+                Unit::Number => format!("The field {field_name} must be at least {n}."),
+                Unit::Kilobytes => {
+                    format!("The field {field_name} must be at least {n} kilobytes.")
+                }
+                Unit::Items => format!("The field {field_name} must have at least {n} items."),
+                Unit::Characters => {
+                    format!("The field {field_name} must be at least {n} characters.")
+                }
             }));
         }
     }
@@ -1,8 +1,16 @@
+pub mod array;
+pub mod boolean;
 pub mod confirmed;
+pub mod decimal;
+pub mod distinct;
 pub mod email;
 pub mod in_;
+pub mod integer;
+pub mod list;
 pub mod max;
 pub mod min;
+pub mod multiple_of;
 pub mod numeric;
 pub mod required;
+pub mod size;
 pub mod string;
@@ -0,0 +1,38 @@
+use super::size::display_param;
+use crate::utils::cast;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// A number that is a multiple of the parameter: `multiple_of:5`,
+/// `multiple_of:0.25`. Nothing is a multiple of 0.
+pub fn multiple_of(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let Some(v) = value else {
+        return Ok(());
+    };
+
+    let step = param.and_then(|p| p.trim().parse::<f64>().ok()).filter(|n| n.is_finite());
+    let number = cast::as_number(v).and_then(|n| n.as_f64());
+
+    let is_multiple = match (number, step) {
+        (Some(n), Some(step)) if step != 0.0 => {
+            let quotient = n / step;
+            (quotient - quotient.round()).abs() < 1e-9 * quotient.abs().max(1.0)
+        }
+        _ => false,
+    };
+
+    if !is_multiple {
+        let step = display_param(param);
+        return Err(validator
+            .message(field, "multiple_of")
+            .unwrap_or_else(|| format!("The field {field_name} must be a multiple of {step}.")));
+    }
+
+    Ok(())
+}
@@ -8,9 +8,6 @@ pub fn required(
     validator: &Validator,
     _: Option<&str>,
 ) -> Result<(), String> {
-    let custom_message =
-        validator.custom_messages.get(&format!("{field}.required")).map(String::as_str);
-
     let is_empty = match value {
         None | Some(Value::Null) => true,
         Some(Value::String(s)) if s.is_empty() => true,
@@ -18,8 +15,8 @@ pub fn required(
     };
 
     if is_empty {
-        return Err(custom_message
-            .map(ToString::to_string)
+        return Err(validator
+            .message(field, "required")
             .unwrap_or_else(|| format!("The field {field_name} is required.")));
     }
 
@@ -0,0 +1,113 @@
+//! Size of a value, as compared by `min`, `max`, `between` and `size`.
+
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// How the size of a value was measured, which phrases its messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    /// The value itself: JSON numbers, and numeric strings of fields with
+    /// a `numeric`, `integer` or `decimal` rule.
+    Number,
+    /// Kilobytes of an uploaded file.
+    Kilobytes,
+    /// Elements of an array, or keys of an object.
+    Items,
+    /// Characters of a string, not bytes.
+    Characters,
+}
+
+/// The size of `value` and its unit. Other values (`null`, booleans) have
+/// size 0 in characters.
+pub fn measure(field: &str, value: &Value, validator: &Validator) -> (f64, Unit) {
+    if let Some(meta) = validator.file(field) {
+        return (meta.size as f64 / 1024.0, Unit::Kilobytes);
+    }
+
+    match value {
+        Value::Number(n) => (n.as_f64().unwrap_or(0.0), Unit::Number),
+        Value::String(s) if is_numeric_field(field, validator) => match s.parse::<f64>() {
+            Ok(n) => (n, Unit::Number),
+            Err(_) => (s.chars().count() as f64, Unit::Characters),
+        },
+        Value::String(s) => (s.chars().count() as f64, Unit::Characters),
+        Value::Array(items) => (items.len() as f64, Unit::Items),
+        Value::Object(map) => (map.len() as f64, Unit::Items),
+        _ => (0.0, Unit::Characters),
+    }
+}
+
+/// A numeric rule parameter; invalid ones count as 0.
+pub fn parse_param(param: Option<&str>) -> f64 {
+    param.and_then(|p| p.trim().parse::<f64>().ok()).filter(|n| n.is_finite()).unwrap_or(0.0)
+}
+
+/// A rule parameter as written, for messages: `min:5` says "5".
+pub fn display_param(param: Option<&str>) -> String {
+    param.map(str::trim).filter(|p| p.parse::<f64>().is_ok()).unwrap_or("0").to_string()
+}
+
+fn is_numeric_field(field: &str, validator: &Validator) -> bool {
+    ["numeric", "integer", "decimal"].iter().any(|rule| validator.has_rule(field, rule))
+}
+
+pub fn size(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let Some(v) = value else {
+        return Ok(());
+    };
+
+    let expected = parse_param(param);
+    let (size, unit) = measure(field, v, validator);
+
+    if size != expected {
+        let n = display_param(param);
+        return Err(validator.message(field, "size").unwrap_or_else(|| match unit {
+            Unit::Number => format!("The field {field_name} must be {n}."),
+            Unit::Kilobytes => format!("The field {field_name} must be {n} kilobytes."),
+            Unit::Items => format!("The field {field_name} must contain {n} items."),
+            Unit::Characters => format!("The field {field_name} must be {n} characters."),
+        }));
+    }
+
+    Ok(())
+}
+
+pub fn between(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let Some(v) = value else {
+        return Ok(());
+    };
+
+    let (low, high) = param.and_then(|p| p.split_once(',')).unwrap_or(("0", "0"));
+    let (min, max) = (parse_param(Some(low)), parse_param(Some(high)));
+    let (size, unit) = measure(field, v, validator);
+
+    if size < min || size > max {
+        let (a, b) = (display_param(Some(low)), display_param(Some(high)));
+        return Err(validator.message(field, "between").unwrap_or_else(|| match unit {
+            Unit::Number => format!("The field {field_name} must be between {a} and {b}."),
+            Unit::Kilobytes => {
+                format!("The field {field_name} must be between {a} and {b} kilobytes.")
+            }
+            Unit::Items => {
+                format!("The field {field_name} must have between {a} and {b} items.")
+            }
+            Unit::Characters => {
+                format!("The field {field_name} must be between {a} and {b} characters.")
+            }
+        }));
+    }
+
+    Ok(())
+}
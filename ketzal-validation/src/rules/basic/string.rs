@@ -8,12 +8,10 @@ pub fn string(
     validator: &Validator,
     _: Option<&str>,
 ) -> Result<(), String> {
-    let custom_message =
-        validator.custom_messages.get(&format!("{field}.string")).map(String::as_str);
     if let Some(v) = value {
         if !v.is_string() {
-            return Err(custom_message
-                .map(ToString::to_string)
+            return Err(validator
+                .message(field, "string")
                 .unwrap_or_else(|| format!("The field {field_name} must be a string.")));
         }
     }
@@ -0,0 +1,158 @@
+use chrono::NaiveDateTime;
+
+use super::{message, operand, other_name, Operand};
+use crate::rules::datetime;
+use crate::utils::date;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The field must be a date before another date field or a literal date:
+/// `before:end_date`, `before:2030-01-01`, `before:tomorrow`.
+pub fn before(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Order::Before)
+}
+
+/// Before or on the same instant, see [`before`].
+pub fn before_or_equal(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Order::BeforeOrEqual)
+}
+
+/// After another date, see [`before`].
+pub fn after(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Order::After)
+}
+
+/// After or on the same instant, see [`before`].
+pub fn after_or_equal(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Order::AfterOrEqual)
+}
+
+/// The same instant as another date, see [`before`]: a plain date is
+/// midnight, so `date_equals:today` takes a date without a time.
+pub fn date_equals(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Order::Equal)
+}
+
+#[derive(Clone, Copy)]
+enum Order {
+    Before,
+    BeforeOrEqual,
+    After,
+    AfterOrEqual,
+    Equal,
+}
+
+impl Order {
+    fn rule(self) -> &'static str {
+        match self {
+            Self::Before => "before",
+            Self::BeforeOrEqual => "before_or_equal",
+            Self::After => "after",
+            Self::AfterOrEqual => "after_or_equal",
+            Self::Equal => "date_equals",
+        }
+    }
+
+    fn holds(self, a: NaiveDateTime, b: NaiveDateTime) -> bool {
+        match self {
+            Self::Before => a < b,
+            Self::BeforeOrEqual => a <= b,
+            Self::After => a > b,
+            Self::AfterOrEqual => a >= b,
+            Self::Equal => a == b,
+        }
+    }
+
+    fn phrase(self) -> &'static str {
+        match self {
+            Self::Before => "before",
+            Self::BeforeOrEqual => "before or equal to",
+            Self::After => "after",
+            Self::AfterOrEqual => "after or equal to",
+            Self::Equal => "equal to",
+        }
+    }
+}
+
+/// Fails when the value or the date it is compared with is not a date.
+/// Fields are read with their `date_format`, and so are literals when they
+/// match it.
+fn compare(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+    order: Order,
+) -> Result<(), String> {
+    let (Some(v), Some(param)) = (value, param) else {
+        return Ok(());
+    };
+
+    let (other, other_label) = match operand(validator, field, param) {
+        Operand::Field(path, other) => (
+            datetime::read(validator, &path, other).map(|d| d.at),
+            other_name(validator, field, param),
+        ),
+        Operand::Literal(literal) => match literal_date(validator, field, literal) {
+            Some(other) => (Some(other), literal.to_string()),
+            // A missing date field.
+            None => (None, other_name(validator, field, param)),
+        },
+    };
+
+    let holds = match (datetime::read(validator, field, v).map(|d| d.at), other) {
+        (Some(a), Some(b)) => order.holds(a, b),
+        _ => false,
+    };
+
+    if !holds {
+        let phrase = order.phrase();
+        return Err(message(
+            validator,
+            field,
+            order.rule(),
+            format!("The field {field_name} must be a date {phrase} {other_label}."),
+        ));
+    }
+
+    Ok(())
+}
+
+fn literal_date(validator: &Validator, field: &str, literal: &str) -> Option<NaiveDateTime> {
+    validator
+        .rule_param(field, "date_format")
+        .and_then(|format| date::read_format(literal, format))
+        .or_else(|| date::read(literal))
+        .map(|parsed| parsed.at)
+}
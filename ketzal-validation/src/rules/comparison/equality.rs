@@ -0,0 +1,54 @@
+use super::{message, other_name};
+use crate::utils::path;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The field must equal another field: `same:password`.
+pub fn same(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let (Some(v), Some(other)) = (value, param) else {
+        return Ok(());
+    };
+
+    if validator.value(&path::substitute(other.trim(), field)) != Some(v) {
+        let other = other_name(validator, field, other);
+        return Err(message(
+            validator,
+            field,
+            "same",
+            format!("The field {field_name} must match {other}."),
+        ));
+    }
+
+    Ok(())
+}
+
+/// The field must differ from another field: `different:old_password`.
+pub fn different(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let (Some(v), Some(other)) = (value, param) else {
+        return Ok(());
+    };
+
+    if validator.value(&path::substitute(other.trim(), field)) == Some(v) {
+        let other = other_name(validator, field, other);
+        return Err(message(
+            validator,
+            field,
+            "different",
+            format!("The fields {field_name} and {other} must be different."),
+        ));
+    }
+
+    Ok(())
+}
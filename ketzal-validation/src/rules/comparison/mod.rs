@@ -0,0 +1,48 @@
+//! Rules that compare a field with another field or with a literal.
+//!
+//! The parameter names another field when the input has a value there, and
+//! is taken as a literal otherwise: `gt:min_price` reads `min_price`, `gt:10`
+//! compares with 10. Like the conditional rules, a `*` in a field parameter
+//! is resolved against the field being validated.
+
+mod date;
+mod equality;
+mod size;
+
+pub use date::{after, after_or_equal, before, before_or_equal, date_equals};
+pub use equality::{different, same};
+pub use size::{gt, gte, lt, lte};
+
+use crate::utils::path;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The right-hand side of a comparison.
+enum Operand<'a> {
+    /// Another field, with its concrete path and value.
+    Field(String, &'a Value),
+    /// The parameter itself.
+    Literal(&'a str),
+}
+
+fn operand<'a>(validator: &'a Validator, field: &str, param: &'a str) -> Operand<'a> {
+    let other = path::substitute(param.trim(), field);
+
+    match validator.value(&other) {
+        Some(value) => Operand::Field(other, value),
+        None => Operand::Literal(param.trim()),
+    }
+}
+
+/// The name of another field in messages: its custom attribute, set on the
+/// parameter as written or on the concrete path, or the concrete path.
+fn other_name(validator: &Validator, field: &str, param: &str) -> String {
+    let other = path::substitute(param.trim(), field);
+    let attributes = &validator.custom_attributes;
+
+    attributes.get(param.trim()).or_else(|| attributes.get(&other)).cloned().unwrap_or(other)
+}
+
+fn message(validator: &Validator, field: &str, rule: &str, default: String) -> String {
+    validator.message(field, rule).unwrap_or(default)
+}
@@ -0,0 +1,134 @@
+use super::{message, operand, other_name, Operand};
+use crate::rules::basic::size::{measure, Unit};
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The field's size must be greater than another field's, or than a number:
+/// `gt:min_price`, `gt:0`. Sizes are measured as by `min` and `max`; two
+/// fields of different types never compare.
+pub fn gt(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Comparison::Gt)
+}
+
+/// Greater than or equal, see [`gt`].
+pub fn gte(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Comparison::Gte)
+}
+
+/// Less than, see [`gt`].
+pub fn lt(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Comparison::Lt)
+}
+
+/// Less than or equal, see [`gt`].
+pub fn lte(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    compare(field, field_name, value, validator, param, Comparison::Lte)
+}
+
+#[derive(Clone, Copy)]
+enum Comparison {
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl Comparison {
+    fn rule(self) -> &'static str {
+        match self {
+            Self::Gt => "gt",
+            Self::Gte => "gte",
+            Self::Lt => "lt",
+            Self::Lte => "lte",
+        }
+    }
+
+    fn holds(self, a: f64, b: f64) -> bool {
+        match self {
+            Self::Gt => a > b,
+            Self::Gte => a >= b,
+            Self::Lt => a < b,
+            Self::Lte => a <= b,
+        }
+    }
+
+    fn phrase(self) -> &'static str {
+        match self {
+            Self::Gt => "greater than",
+            Self::Gte => "greater than or equal to",
+            Self::Lt => "less than",
+            Self::Lte => "less than or equal to",
+        }
+    }
+}
+
+fn compare(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+    comparison: Comparison,
+) -> Result<(), String> {
+    let (Some(v), Some(param)) = (value, param) else {
+        return Ok(());
+    };
+
+    let (size, unit) = measure(field, v, validator);
+    let phrase = comparison.phrase();
+
+    let (holds, default) = match operand(validator, field, param) {
+        Operand::Field(other, other_value) => {
+            let (other_size, other_unit) = measure(&other, other_value, validator);
+            let holds = unit == other_unit && comparison.holds(size, other_size);
+            let other = other_name(validator, field, param);
+            (holds, format!("The field {field_name} must be {phrase} {other}."))
+        }
+        Operand::Literal(literal) => {
+            let holds = literal.parse::<f64>().is_ok_and(|n| comparison.holds(size, n));
+            let default = match unit {
+                Unit::Number => format!("The field {field_name} must be {phrase} {literal}."),
+                Unit::Kilobytes => {
+                    format!("The field {field_name} must be {phrase} {literal} kilobytes.")
+                }
+                Unit::Items => {
+                    format!("The field {field_name} must have {phrase} {literal} items.")
+                }
+                Unit::Characters => {
+                    format!("The field {field_name} must be {phrase} {literal} characters.")
+                }
+            };
+            (holds, default)
+        }
+    };
+
+    if !holds {
+        return Err(message(validator, field, comparison.rule(), default));
+    }
+
+    Ok(())
+}
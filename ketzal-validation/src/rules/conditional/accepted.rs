@@ -0,0 +1,102 @@
+use super::{display, field_and_values, matching_value, message};
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The field must be `true`, `1`, `"yes"`, `"on"`, `"1"` or `"true"`, as a
+/// terms-of-service checkbox.
+pub fn accepted(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if !is_accepted(value) {
+        let default = format!("The field {field_name} must be accepted.");
+        return Err(message(validator, field, "accepted", default));
+    }
+
+    Ok(())
+}
+
+/// Must be accepted when another field has one of the given values.
+pub fn accepted_if(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let Some((other, values)) = field_and_values(params) else {
+        return Ok(());
+    };
+
+    if let Some(matched) = matching_value(validator, field, other, &values) {
+        if !is_accepted(value) {
+            let other = display(validator, other);
+            let default =
+                format!("The field {field_name} must be accepted when {other} is {matched}.");
+            return Err(message(validator, field, "accepted_if", default));
+        }
+    }
+
+    Ok(())
+}
+
+/// The field must be `false`, `0`, `"no"`, `"off"`, `"0"` or `"false"`.
+pub fn declined(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if !is_declined(value) {
+        let default = format!("The field {field_name} must be declined.");
+        return Err(message(validator, field, "declined", default));
+    }
+
+    Ok(())
+}
+
+/// Must be declined when another field has one of the given values.
+pub fn declined_if(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let Some((other, values)) = field_and_values(params) else {
+        return Ok(());
+    };
+
+    if let Some(matched) = matching_value(validator, field, other, &values) {
+        if !is_declined(value) {
+            let other = display(validator, other);
+            let default =
+                format!("The field {field_name} must be declined when {other} is {matched}.");
+            return Err(message(validator, field, "declined_if", default));
+        }
+    }
+
+    Ok(())
+}
+
+fn is_accepted(value: Option<&Value>) -> bool {
+    match value {
+        Some(Value::Bool(b)) => *b,
+        Some(Value::Number(n)) => n.as_u64() == Some(1),
+        Some(Value::String(s)) => matches!(s.trim(), "yes" | "on" | "1" | "true"),
+        _ => false,
+    }
+}
+
+fn is_declined(value: Option<&Value>) -> bool {
+    match value {
+        Some(Value::Bool(b)) => !*b,
+        Some(Value::Number(n)) => n.as_u64() == Some(0),
+        Some(Value::String(s)) => matches!(s.trim(), "no" | "off" | "0" | "false"),
+        _ => false,
+    }
+}
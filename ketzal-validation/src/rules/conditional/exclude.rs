@@ -0,0 +1,25 @@
+use super::{field_and_values, matching_value};
+use crate::rules::split_rule;
+use crate::validator::Validator;
+
+/// Whether `exclude_if` or `exclude_unless` in `rules` drop `field`: it is
+/// then neither validated nor part of the validated data.
+pub fn is_excluded<'r>(
+    field: &str,
+    mut rules: impl Iterator<Item = &'r str>,
+    validator: &Validator,
+) -> bool {
+    rules.any(|rule| {
+        let (name, params) = split_rule(rule);
+        let condition = || {
+            let (other, values) = field_and_values(params)?;
+            Some(matching_value(validator, field, other, &values).is_some())
+        };
+
+        match name {
+            "exclude_if" => condition() == Some(true),
+            "exclude_unless" => condition() == Some(false),
+            _ => false,
+        }
+    })
+}
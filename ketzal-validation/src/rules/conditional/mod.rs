@@ -0,0 +1,83 @@
+//! Rules that depend on the value or presence of other fields.
+//!
+//! Other fields are named in dot notation. A `*` in them is resolved against
+//! the field being validated, so `required_if:items.*.type,physical` on
+//! `items.2.weight` reads `items.2.type`.
+
+mod accepted;
+mod exclude;
+mod presence;
+mod prohibited;
+mod required_if;
+mod required_with;
+
+pub use accepted::{accepted, accepted_if, declined, declined_if};
+pub use exclude::is_excluded;
+pub use presence::{filled, present};
+pub use prohibited::{prohibited, prohibited_if, prohibited_unless};
+pub use required_if::{required_if, required_unless};
+pub use required_with::{required_with, required_with_all, required_without, required_without_all};
+
+use crate::utils::path;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// Splits `other,value1,value2` into the other field and its values.
+fn field_and_values(params: Option<&str>) -> Option<(&str, Vec<&str>)> {
+    let mut split = params?.split(',').map(str::trim);
+    let other = split.next().filter(|other| !other.is_empty())?;
+    Some((other, split.collect()))
+}
+
+/// The fields of `a,b,c`.
+fn fields(params: Option<&str>) -> Vec<&str> {
+    params.unwrap_or("").split(',').map(str::trim).filter(|f| !f.is_empty()).collect()
+}
+
+/// The value of `other` next to `field`.
+fn other_value<'a>(validator: &'a Validator, field: &str, other: &str) -> Option<&'a Value> {
+    validator.value(&path::substitute(other, field))
+}
+
+/// The first of `values` that `other` equals, compared as trimmed,
+/// case-insensitive text.
+fn matching_value<'v>(
+    validator: &Validator,
+    field: &str,
+    other: &str,
+    values: &[&'v str],
+) -> Option<&'v str> {
+    let actual = normalize(other_value(validator, field, other)?);
+    values.iter().copied().find(|expected| expected.trim().to_lowercase() == actual)
+}
+
+fn normalize(v: &Value) -> String {
+    match v {
+        Value::String(s) => s.trim().to_lowercase(),
+        _ => v.to_string().trim().to_lowercase(),
+    }
+}
+
+/// Missing, `null`, an empty string or an empty array.
+fn is_empty(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => true,
+        Some(Value::String(s)) => s.is_empty(),
+        Some(Value::Array(items)) => items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Whether the field `other` next to `field` has a non-empty value.
+fn is_filled(validator: &Validator, field: &str, other: &str) -> bool {
+    !is_empty(other_value(validator, field, other))
+}
+
+/// The name of `other` in messages.
+fn display<'a>(validator: &'a Validator, other: &'a str) -> &'a str {
+    validator.custom_attributes.get(other).map_or(other, String::as_str)
+}
+
+fn message(validator: &Validator, field: &str, rule: &str, default: String) -> String {
+    validator.message(field, rule).unwrap_or(default)
+}
@@ -0,0 +1,35 @@
+use super::{is_empty, message};
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The field must be in the input, even if empty or `null`.
+pub fn present(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if value.is_none() {
+        let default = format!("The field {field_name} must be present.");
+        return Err(message(validator, field, "present", default));
+    }
+
+    Ok(())
+}
+
+/// The field may be missing, but must not be empty when present.
+pub fn filled(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if value.is_some() && is_empty(value) {
+        let default = format!("The field {field_name} must have a value.");
+        return Err(message(validator, field, "filled", default));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use super::{display, field_and_values, is_empty, matching_value, message};
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The field must be missing or empty.
+pub fn prohibited(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if !is_empty(value) {
+        let default = format!("The field {field_name} is prohibited.");
+        return Err(message(validator, field, "prohibited", default));
+    }
+
+    Ok(())
+}
+
+/// Prohibited when another field has one of the given values.
+pub fn prohibited_if(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let Some((other, values)) = field_and_values(params) else {
+        return Ok(());
+    };
+
+    if let Some(matched) = matching_value(validator, field, other, &values) {
+        if !is_empty(value) {
+            let other = display(validator, other);
+            let default =
+                format!("The field {field_name} is prohibited when {other} is {matched}.");
+            return Err(message(validator, field, "prohibited_if", default));
+        }
+    }
+
+    Ok(())
+}
+
+/// Prohibited unless another field has one of the given values.
+pub fn prohibited_unless(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let Some((other, values)) = field_and_values(params) else {
+        return Ok(());
+    };
+
+    if !is_empty(value) && matching_value(validator, field, other, &values).is_none() {
+        let (other, values) = (display(validator, other), values.join(", "));
+        let default =
+            format!("The field {field_name} is prohibited unless {other} is in {values}.");
+        return Err(message(validator, field, "prohibited_unless", default));
+    }
+
+    Ok(())
+}
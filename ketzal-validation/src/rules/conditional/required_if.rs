@@ -0,0 +1,64 @@
+use super::{display, field_and_values, matching_value, message};
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// Required when another field has one of the given values:
+/// `required_if:role,admin,owner`. Like the other conditional rules, only a
+/// missing or `null` value fails.
+pub fn required_if(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let Some((other, values)) = field_and_values(params) else {
+        return Ok(());
+    };
+
+    let Some(matched) = matching_value(validator, field, other, &values) else {
+        return Ok(());
+    };
+
+    if is_missing(value) {
+        let other = display(validator, other);
+        return Err(message(
+            validator,
+            field,
+            "required_if",
+            format!("The field {field_name} is required when {other} is {matched}."),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Required unless another field has one of the given values:
+/// `required_unless:role,guest`.
+pub fn required_unless(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let Some((other, values)) = field_and_values(params) else {
+        return Ok(());
+    };
+
+    if is_missing(value) && matching_value(validator, field, other, &values).is_none() {
+        let (other, values) = (display(validator, other), values.join(", "));
+        return Err(message(
+            validator,
+            field,
+            "required_unless",
+            format!("The field {field_name} is required unless {other} is in {values}."),
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_missing(value: Option<&Value>) -> bool {
+    value.is_none_or(Value::is_null)
+}
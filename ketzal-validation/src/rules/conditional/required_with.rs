@@ -0,0 +1,90 @@
+use super::{display, fields, is_empty, is_filled, message};
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// Required when any of the listed fields has a value: `required_with:a,b`.
+pub fn required_with(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let others = fields(params);
+    let triggered = others.iter().any(|other| is_filled(validator, field, other));
+
+    check(field, value, validator, triggered, "required_with", || {
+        format!("The field {field_name} is required when {} is present.", list(validator, &others))
+    })
+}
+
+/// Required when all of the listed fields have a value.
+pub fn required_with_all(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let others = fields(params);
+    let triggered =
+        !others.is_empty() && others.iter().all(|other| is_filled(validator, field, other));
+
+    check(field, value, validator, triggered, "required_with_all", || {
+        format!("The field {field_name} is required when {} are present.", list(validator, &others))
+    })
+}
+
+/// Required when any of the listed fields is missing or empty.
+pub fn required_without(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let others = fields(params);
+    let triggered = others.iter().any(|other| !is_filled(validator, field, other));
+
+    check(field, value, validator, triggered, "required_without", || {
+        let others = list(validator, &others);
+        format!("The field {field_name} is required when {others} is not present.")
+    })
+}
+
+/// Required when all of the listed fields are missing or empty.
+pub fn required_without_all(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    params: Option<&str>,
+) -> Result<(), String> {
+    let others = fields(params);
+    let triggered =
+        !others.is_empty() && others.iter().all(|other| !is_filled(validator, field, other));
+
+    check(field, value, validator, triggered, "required_without_all", || {
+        let others = list(validator, &others);
+        format!("The field {field_name} is required when none of {others} are present.")
+    })
+}
+
+fn check(
+    field: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    triggered: bool,
+    rule: &str,
+    default: impl FnOnce() -> String,
+) -> Result<(), String> {
+    if triggered && is_empty(value) {
+        return Err(message(validator, field, rule, default()));
+    }
+
+    Ok(())
+}
+
+fn list(validator: &Validator, others: &[&str]) -> String {
+    others.iter().map(|other| display(validator, other)).collect::<Vec<_>>().join(" / ")
+}
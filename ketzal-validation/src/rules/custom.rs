@@ -0,0 +1,167 @@
+//! Rules registered at runtime.
+//!
+//! A rule is anything implementing [`ValidationRule`]; closures become rules
+//! through [`RuleFn`]. Rules are registered globally with [`register_rule`],
+//! for one validator with [`Validator::add_rule`], or attached to a single
+//! field with [`Validator::rule`].
+//!
+//! The global table is swapped atomically on registration, so validation
+//! reads it without taking a lock.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use arc_swap::ArcSwap;
+use serde_json::Value;
+
+use crate::messages;
+use crate::validator::Validator;
+
+/// A validation rule defined outside this crate.
+///
+/// # Example
+///
+/// ```
+/// use ketzal_validation::{ValidationRule, Validator};
+/// use serde_json::Value;
+///
+/// struct Divisible;
+///
+/// impl ValidationRule for Divisible {
+///     fn passes(&self, _: &str, value: Option<&Value>, params: &[&str], _: &Validator) -> bool {
+///         let by: u64 = params.first().and_then(|p| p.parse().ok()).unwrap_or(1);
+///         value.and_then(Value::as_u64).is_some_and(|n| n % by == 0)
+///     }
+///
+///     fn message(&self, field_name: &str, params: &[&str]) -> String {
+///         format!("The field {field_name} must be divisible by {}.", params.join(", "))
+///     }
+/// }
+///
+/// ketzal_validation::register_rule("divisible", Divisible);
+/// ```
+pub trait ValidationRule: Send + Sync {
+    /// Whether `value` passes. `params` are the comma-separated values after
+    /// `:` in the rule string, empty for inline rules.
+    fn passes(
+        &self,
+        field: &str,
+        value: Option<&Value>,
+        params: &[&str],
+        validator: &Validator,
+    ) -> bool;
+
+    /// The message when the rule fails, unless a custom message is set.
+    fn message(&self, field_name: &str, _params: &[&str]) -> String {
+        format!("The field {field_name} is invalid.")
+    }
+
+    /// Whether the rule also runs when the value is missing or blank, as
+    /// `required` does. Other rules are skipped for such values.
+    fn implicit(&self) -> bool {
+        false
+    }
+}
+
+/// A closure rule with its default message.
+///
+/// `:attribute` in the message is replaced by the field's display name, as
+/// in custom messages.
+///
+/// # Example
+///
+/// ```
+/// use ketzal_validation::RuleFn;
+///
+/// ketzal_validation::register_rule(
+///     "trimmed",
+///     RuleFn::new("The field :attribute must not start or end with spaces.", |_, value, _, _| {
+///         value.and_then(|v| v.as_str()).is_some_and(|s| s == s.trim())
+///     }),
+/// );
+/// ```
+pub struct RuleFn<F> {
+    message: String,
+    check: F,
+}
+
+impl<F> RuleFn<F>
+where
+    F: Fn(&str, Option<&Value>, &[&str], &Validator) -> bool + Send + Sync,
+{
+    pub fn new(message: impl Into<String>, check: F) -> Self {
+        Self { message: message.into(), check }
+    }
+}
+
+impl<F> ValidationRule for RuleFn<F>
+where
+    F: Fn(&str, Option<&Value>, &[&str], &Validator) -> bool + Send + Sync,
+{
+    fn passes(
+        &self,
+        field: &str,
+        value: Option<&Value>,
+        params: &[&str],
+        validator: &Validator,
+    ) -> bool {
+        (self.check)(field, value, params, validator)
+    }
+
+    fn message(&self, field_name: &str, _params: &[&str]) -> String {
+        messages::render(&self.message, &[(":attribute".to_string(), field_name.to_string())])
+    }
+}
+
+pub(crate) type RuleMap = HashMap<String, Arc<dyn ValidationRule>>;
+
+static CUSTOM_RULES: LazyLock<ArcSwap<RuleMap>> = LazyLock::new(ArcSwap::default);
+
+/// Serializes registrations; readers never take it.
+static REGISTER: Mutex<()> = Mutex::new(());
+
+/// Registers `rule` under `name` for every validator.
+///
+/// A rule with the name of a built-in rule replaces it. Registering the same
+/// name again replaces the previous rule.
+pub fn register_rule(name: &str, rule: impl ValidationRule + 'static) {
+    let _guard = REGISTER.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let mut rules = RuleMap::clone(&CUSTOM_RULES.load());
+    rules.insert(name.to_string(), Arc::new(rule));
+    CUSTOM_RULES.store(Arc::new(rules));
+}
+
+/// Whether a rule was registered globally under `name`.
+pub fn is_registered(name: &str) -> bool {
+    CUSTOM_RULES.load().contains_key(name)
+}
+
+/// A snapshot of the globally registered rules.
+pub(crate) fn custom_rules() -> Arc<RuleMap> {
+    CUSTOM_RULES.load_full()
+}
+
+/// Runs `rule`, rendering its message on failure.
+///
+/// A custom message is looked up as `field.name` for named rules.
+pub(crate) fn run(
+    rule: &dyn ValidationRule,
+    name: Option<&str>,
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let params: Vec<&str> =
+        param.map(|p| p.split(',').map(str::trim).collect()).unwrap_or_default();
+
+    if rule.passes(field, value, &params, validator) {
+        return Ok(());
+    }
+
+    let custom = name.and_then(|name| validator.message(field, name));
+
+    Err(custom.unwrap_or_else(|| rule.message(field_name, &params)))
+}
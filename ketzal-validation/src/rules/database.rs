@@ -0,0 +1,102 @@
+//! `unique` and `exists`, checked against the validator's
+//! [`PresenceVerifier`](crate::presence::PresenceVerifier).
+//!
+//! The column defaults to the field's name: its last segment that is not an
+//! index, so `users.0.email` looks up `email` and `tags.2` looks up `tags`.
+
+use std::io;
+
+use serde_json::Value;
+
+use super::async_rule::{AsyncValidationRule, RuleFuture};
+use crate::presence::{Except, PresenceVerifier};
+use crate::validator::Validator;
+
+/// No other row may hold the value: `unique:users`, `unique:users,email`, or
+/// `unique:users,email,5` to leave out the row with id 5, and
+/// `unique:users,email,ada,username` to leave out the row whose `username`
+/// is `ada`.
+pub(crate) struct Unique;
+
+impl AsyncValidationRule for Unique {
+    fn passes<'a>(
+        &'a self,
+        field: &'a str,
+        value: Option<&'a Value>,
+        params: &'a [&'a str],
+        validator: &'a Validator,
+    ) -> RuleFuture<'a> {
+        Box::pin(async move {
+            let (Some(value), Some(table)) = (value, params.first()) else {
+                return Ok(true);
+            };
+
+            let except = params
+                .get(2)
+                .filter(|id| !id.is_empty() && !id.eq_ignore_ascii_case("null"))
+                .map(|id| Except { column: param(params, 3).unwrap_or("id"), value: id });
+
+            let column = column(params, field);
+            let count = verifier(validator, "unique")?.count(table, column, value, except).await?;
+            Ok(count == 0)
+        })
+    }
+
+    fn message(&self, field_name: &str, _params: &[&str]) -> String {
+        format!("The field {field_name} has already been taken.")
+    }
+}
+
+/// A row must hold the value: `exists:users` or `exists:users,email`. Each
+/// element of an array must exist.
+pub(crate) struct Exists;
+
+impl AsyncValidationRule for Exists {
+    fn passes<'a>(
+        &'a self,
+        field: &'a str,
+        value: Option<&'a Value>,
+        params: &'a [&'a str],
+        validator: &'a Validator,
+    ) -> RuleFuture<'a> {
+        Box::pin(async move {
+            let (Some(value), Some(table)) = (value, params.first()) else {
+                return Ok(true);
+            };
+
+            let verifier = verifier(validator, "exists")?;
+            let column = column(params, field);
+            let values = match value {
+                Value::Array(items) => items.iter().collect(),
+                value => vec![value],
+            };
+
+            for value in values {
+                if verifier.count(table, column, value, None).await? == 0 {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        })
+    }
+
+    fn message(&self, field_name: &str, _params: &[&str]) -> String {
+        format!("The selected {field_name} is invalid.")
+    }
+}
+
+fn param<'a>(params: &[&'a str], index: usize) -> Option<&'a str> {
+    params.get(index).copied().filter(|p| !p.is_empty())
+}
+
+fn column<'a>(params: &[&'a str], field: &'a str) -> &'a str {
+    param(params, 1).unwrap_or_else(|| {
+        field.rsplit('.').find(|segment| segment.parse::<usize>().is_err()).unwrap_or(field)
+    })
+}
+
+fn verifier<'a>(validator: &'a Validator, rule: &str) -> io::Result<&'a dyn PresenceVerifier> {
+    validator.presence_verifier().ok_or_else(|| {
+        io::Error::other(format!("rule `{rule}` needs a presence verifier on the validator"))
+    })
+}
@@ -0,0 +1,46 @@
+use crate::utils::date;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// A date in one of the formats of [`date::parse`], or matching the field's
+/// `date_format`.
+pub fn date(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    let Some(v) = value else {
+        return Ok(());
+    };
+
+    if super::read(validator, field, v).is_none() {
+        return Err(validator
+            .message(field, "date")
+            .unwrap_or_else(|| format!("The field {field_name} must be a valid date.")));
+    }
+
+    Ok(())
+}
+
+/// A date in a PHP-style format, matched exactly: `date_format:d/m/Y`.
+pub fn date_format(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let (Some(v), Some(format)) = (value, param) else {
+        return Ok(());
+    };
+
+    if v.as_str().and_then(|text| date::read_format(text, format)).is_none() {
+        return Err(validator
+            .message(field, "date_format")
+            .unwrap_or_else(|| format!("The field {field_name} must match the format {format}.")));
+    }
+
+    Ok(())
+}
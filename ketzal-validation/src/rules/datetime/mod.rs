@@ -0,0 +1,25 @@
+//! Rules on dates and time zones.
+//!
+//! A field with `date_format` is read with that format everywhere, including
+//! by `before`, `after` and `date_equals` and when validated data is
+//! normalized.
+
+mod date;
+mod timezone;
+
+pub use date::{date, date_format};
+pub use timezone::timezone;
+
+use crate::utils::date::{self as dates, Parsed};
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// Reads the value of `field` as a date, with its `date_format` if it has one.
+pub(crate) fn read(validator: &Validator, field: &str, value: &Value) -> Option<Parsed> {
+    let text = value.as_str()?;
+
+    match validator.rule_param(field, "date_format") {
+        Some(format) => dates::read_format(text, format),
+        None => dates::read(text),
+    }
+}
@@ -0,0 +1,25 @@
+use chrono_tz::Tz;
+
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// An IANA time zone name: `UTC`, `America/Mexico_City`.
+pub fn timezone(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    let Some(v) = value else {
+        return Ok(());
+    };
+
+    if v.as_str().and_then(|name| name.parse::<Tz>().ok()).is_none() {
+        return Err(validator
+            .message(field, "timezone")
+            .unwrap_or_else(|| format!("The field {field_name} must be a valid timezone.")));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use crate::validator::Validator;
+use serde_json::Value;
+
+const IMAGE_EXTENSIONS: [&str; 6] = ["jpg", "jpeg", "png", "bmp", "gif", "webp"];
+
+/// `image:allow_svg` also accepts SVG, which can carry script.
+pub const ALLOW_SVG: &str = "allow_svg";
+
+pub fn file(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    if value.is_some() && validator.file(field).is_none() {
+        return Err(validator
+            .message(field, "file")
+            .unwrap_or_else(|| format!("The field {field_name} must be a file.")));
+    }
+
+    Ok(())
+}
+
+pub fn image(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let allow_svg = param.is_some_and(|p| p.trim() == ALLOW_SVG);
+
+    if value.is_some() {
+        // The content decides; the extension must agree with it.
+        let is_image = validator.file(field).is_some_and(|meta| {
+            let svg = meta.detected == Some("image/svg+xml");
+            let extension = meta.extension().unwrap_or_default();
+
+            meta.detected.is_some_and(|mime| mime.starts_with("image/"))
+                && (!svg || allow_svg)
+                && (IMAGE_EXTENSIONS.contains(&extension.as_str()) || (svg && extension == "svg"))
+        });
+
+        if !is_image {
+            return Err(validator
+                .message(field, "image")
+                .unwrap_or_else(|| format!("The field {field_name} must be an image.")));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn mimes(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let allowed: Vec<String> =
+        param.unwrap_or("").split(',').map(|s| s.trim().to_ascii_lowercase()).collect();
+
+    if value.is_some() {
+        // Matched against the content, not the client's name or type.
+        let matches = validator
+            .file(field)
+            .is_some_and(|meta| meta.content_extensions().iter().any(|ext| allowed.contains(ext)));
+
+        if !matches {
+            return Err(validator.message(field, "mimes").unwrap_or_else(|| {
+                format!("The field {field_name} must be a file of type: {}.", allowed.join(", "))
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn mimetypes(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let allowed: Vec<&str> = param.unwrap_or("").split(',').map(str::trim).collect();
+
+    if value.is_some() {
+        // Matched against the content, like `mimes`.
+        let matches = validator.file(field).and_then(|meta| meta.content_mime()).is_some_and(
+            |content_type| {
+                allowed.iter().any(|pattern| match pattern.strip_suffix("/*") {
+                    Some(prefix) => content_type.split('/').next() == Some(prefix),
+                    None => content_type == pattern.to_ascii_lowercase(),
+                })
+            },
+        );
+
+        if !matches {
+            return Err(validator.message(field, "mimetypes").unwrap_or_else(|| {
+                format!("The field {field_name} must be a file of type: {}.", allowed.join(", "))
+            }));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use super::check_text;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The value must start with one of the given values: `starts_with:+1,+52`.
+pub fn starts_with(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let prefixes: Vec<&str> = param.unwrap_or_default().split(',').collect();
+
+    check_text(
+        field,
+        value,
+        validator,
+        "starts_with",
+        |s| prefixes.iter().any(|prefix| s.starts_with(prefix)),
+        || {
+            let prefixes = prefixes.join(", ");
+            format!("The field {field_name} must start with one of the following: {prefixes}.")
+        },
+    )
+}
+
+/// The value must end with one of the given values: `ends_with:.com,.org`.
+pub fn ends_with(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let suffixes: Vec<&str> = param.unwrap_or_default().split(',').collect();
+
+    check_text(
+        field,
+        value,
+        validator,
+        "ends_with",
+        |s| suffixes.iter().any(|suffix| s.ends_with(suffix)),
+        || {
+            let suffixes = suffixes.join(", ");
+            format!("The field {field_name} must end with one of the following: {suffixes}.")
+        },
+    )
+}
@@ -0,0 +1,61 @@
+use super::check_text;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// Only letters, in any script: `José`, `Ünal` and `東京` pass.
+pub fn alpha(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "alpha",
+        |s| !s.is_empty() && s.chars().all(char::is_alphabetic),
+        || format!("The field {field_name} must only contain letters."),
+    )
+}
+
+/// Only letters and digits, in any script.
+pub fn alpha_num(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "alpha_num",
+        |s| !s.is_empty() && s.chars().all(char::is_alphanumeric),
+        || format!("The field {field_name} must only contain letters and numbers."),
+    )
+}
+
+/// Letters, digits, dashes and underscores, as in slugs.
+pub fn alpha_dash(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "alpha_dash",
+        |s| !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_'),
+        || {
+            format!(
+                "The field {field_name} must only contain letters, numbers, dashes and underscores."
+            )
+        },
+    )
+}
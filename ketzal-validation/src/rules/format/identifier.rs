@@ -0,0 +1,110 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::check_text;
+use crate::utils::{get_ulid_regex, get_url_regex, get_uuid_regex};
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// An absolute URL with a scheme and a host: `https://example.com/a?b`.
+pub fn url(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "url",
+        |s| get_url_regex().is_match(s),
+        || format!("The field {field_name} must be a valid URL."),
+    )
+}
+
+pub fn uuid(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "uuid",
+        |s| get_uuid_regex().is_match(s),
+        || format!("The field {field_name} must be a valid UUID."),
+    )
+}
+
+pub fn ulid(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "ulid",
+        |s| get_ulid_regex().is_match(s),
+        || format!("The field {field_name} must be a valid ULID."),
+    )
+}
+
+/// An IPv4 or IPv6 address.
+pub fn ip(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "ip",
+        |s| s.parse::<IpAddr>().is_ok(),
+        || format!("The field {field_name} must be a valid IP address."),
+    )
+}
+
+pub fn ipv4(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "ipv4",
+        |s| s.parse::<Ipv4Addr>().is_ok(),
+        || format!("The field {field_name} must be a valid IPv4 address."),
+    )
+}
+
+pub fn ipv6(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "ipv6",
+        |s| s.parse::<Ipv6Addr>().is_ok(),
+        || format!("The field {field_name} must be a valid IPv6 address."),
+    )
+}
@@ -0,0 +1,50 @@
+//! Rules on the format of text: identifiers, addresses, character classes
+//! and patterns.
+//!
+//! Numbers are checked as their text, so `alpha_num` accepts `42`. Other
+//! non-string values fail.
+
+mod affix;
+mod alpha;
+mod identifier;
+mod pattern;
+mod text;
+
+pub use affix::{ends_with, starts_with};
+pub use alpha::{alpha, alpha_dash, alpha_num};
+pub use identifier::{ip, ipv4, ipv6, ulid, url, uuid};
+pub use pattern::{not_regex, regex};
+pub use text::{json, lowercase, uppercase};
+
+use std::borrow::Cow;
+
+use crate::validator::Validator;
+use serde_json::Value;
+
+fn as_text(value: &Value) -> Option<Cow<'_, str>> {
+    match value {
+        Value::String(s) => Some(Cow::Borrowed(s)),
+        Value::Number(n) => Some(Cow::Owned(n.to_string())),
+        _ => None,
+    }
+}
+
+/// Fails with the rule's message unless the value's text passes `check`.
+fn check_text(
+    field: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    rule: &str,
+    check: impl FnOnce(&str) -> bool,
+    default: impl FnOnce() -> String,
+) -> Result<(), String> {
+    let Some(v) = value else {
+        return Ok(());
+    };
+
+    if !as_text(v).is_some_and(|text| check(&text)) {
+        return Err(validator.message(field, rule).unwrap_or_else(default));
+    }
+
+    Ok(())
+}
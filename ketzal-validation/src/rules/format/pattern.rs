@@ -0,0 +1,47 @@
+use super::check_text;
+use crate::utils::pattern_regex;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// The value must match a pattern: `regex:/^[A-Z]{3}-\d+$/i`.
+///
+/// An invalid pattern fails every value; strict mode and the compile-time
+/// checks report it instead.
+pub fn regex(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let regex = pattern_regex(param.unwrap_or_default()).ok();
+
+    check_text(
+        field,
+        value,
+        validator,
+        "regex",
+        |s| regex.is_some_and(|regex| regex.is_match(s)),
+        || format!("The field {field_name} format is invalid."),
+    )
+}
+
+/// The value must not match a pattern, see [`regex`].
+pub fn not_regex(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    param: Option<&str>,
+) -> Result<(), String> {
+    let regex = pattern_regex(param.unwrap_or_default()).ok();
+
+    check_text(
+        field,
+        value,
+        validator,
+        "not_regex",
+        |s| regex.is_some_and(|regex| !regex.is_match(s)),
+        || format!("The field {field_name} format is invalid."),
+    )
+}
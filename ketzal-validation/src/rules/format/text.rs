@@ -0,0 +1,57 @@
+use super::check_text;
+use crate::validator::Validator;
+use serde_json::Value;
+
+/// A string holding a JSON document.
+pub fn json(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    let is_string = value.is_none_or(Value::is_string);
+
+    check_text(
+        field,
+        value,
+        validator,
+        "json",
+        |s| is_string && serde_json::from_str::<Value>(s).is_ok(),
+        || format!("The field {field_name} must be a valid JSON string."),
+    )
+}
+
+pub fn lowercase(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "lowercase",
+        |s| s == s.to_lowercase(),
+        || format!("The field {field_name} must be lowercase."),
+    )
+}
+
+pub fn uppercase(
+    field: &str,
+    field_name: &str,
+    value: Option<&Value>,
+    validator: &Validator,
+    _: Option<&str>,
+) -> Result<(), String> {
+    check_text(
+        field,
+        value,
+        validator,
+        "uppercase",
+        |s| s == s.to_uppercase(),
+        || format!("The field {field_name} must be uppercase."),
+    )
+}
@@ -1,6 +1,16 @@
+pub mod async_rule;
 pub mod basic;
-mod conditional;
+mod comparison;
+pub(crate) mod conditional;
+pub mod custom;
+mod database;
+mod datetime;
+mod file;
+mod format;
 pub mod registry;
+pub mod spec;
 
+pub use async_rule::{async_rules_registry, AsyncValidationRule, RuleFuture};
 pub use conditional::required_if;
-pub use registry::{rules_registry, split_rule, Rule};
+pub use custom::{register_rule, RuleFn, ValidationRule};
+pub use registry::{rules_registry, split_rule, split_rules, Rule};
@@ -6,7 +6,11 @@ use serde_json::Value;
 use crate::validator::Validator;
 
 use super::basic;
+use super::comparison;
 use super::conditional;
+use super::datetime;
+use super::file;
+use super::format;
 
 pub type Rule = Box<
     dyn Fn(&str, &str, Option<&Value>, &Validator, Option<&str>) -> Result<(), String>
@@ -47,6 +51,34 @@ pub fn rules_registry() -> &'static HashMap<&'static str, Rule> {
             }) as Rule,
         );
 
+        map.insert(
+            "integer",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                basic::integer::integer(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "decimal",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                basic::decimal::decimal(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "boolean",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                basic::boolean::boolean(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "multiple_of",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                basic::multiple_of::multiple_of(f, n, v, val, p)
+            }) as Rule,
+        );
+
         map.insert(
             "email",
             Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
@@ -68,6 +100,20 @@ pub fn rules_registry() -> &'static HashMap<&'static str, Rule> {
             }) as Rule,
         );
 
+        map.insert(
+            "size",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                basic::size::size(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "between",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                basic::size::between(f, n, v, val, p)
+            }) as Rule,
+        );
+
         map.insert(
             "in",
             Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
@@ -75,6 +121,27 @@ pub fn rules_registry() -> &'static HashMap<&'static str, Rule> {
             }) as Rule,
         );
 
+        map.insert(
+            "array",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                basic::array::array(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "list",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                basic::list::list(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "distinct",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                basic::distinct::distinct(f, n, v, val, None)
+            }) as Rule,
+        );
+
         map.insert(
             "confirmed",
             Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
@@ -89,6 +156,352 @@ pub fn rules_registry() -> &'static HashMap<&'static str, Rule> {
             }) as Rule,
         );
 
+        map.insert(
+            "required_unless",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::required_unless(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "required_with",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::required_with(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "required_with_all",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::required_with_all(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "required_without",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::required_without(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "required_without_all",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::required_without_all(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "present",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                conditional::present(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "filled",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                conditional::filled(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "prohibited",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                conditional::prohibited(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "prohibited_if",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::prohibited_if(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "prohibited_unless",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::prohibited_unless(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "accepted",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                conditional::accepted(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "accepted_if",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::accepted_if(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "declined",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                conditional::declined(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "declined_if",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                conditional::declined_if(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        // Markers handled by the validator itself.
+        for marker in ["sometimes", "bail", "exclude_if", "exclude_unless"] {
+            map.insert(
+                marker,
+                Box::new(|_: &str, _: &str, _: Option<&Value>, _: &Validator, _: Option<&str>| {
+                    Ok(())
+                }) as Rule,
+            );
+        }
+
+        map.insert(
+            "same",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::same(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "different",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::different(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "gt",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::gt(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "gte",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::gte(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "lt",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::lt(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "lte",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::lte(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "before",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::before(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "before_or_equal",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::before_or_equal(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "after",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::after(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "after_or_equal",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::after_or_equal(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "date_equals",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                comparison::date_equals(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "date",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                datetime::date(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "date_format",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                datetime::date_format(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "timezone",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                datetime::timezone(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "url",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::url(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "uuid",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::uuid(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "ulid",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::ulid(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "ip",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::ip(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "ipv4",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::ipv4(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "ipv6",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::ipv6(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "alpha",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::alpha(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "alpha_num",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::alpha_num(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "alpha_dash",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::alpha_dash(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "json",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::json(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "lowercase",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::lowercase(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "uppercase",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                format::uppercase(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "regex",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                format::regex(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "not_regex",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                format::not_regex(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "starts_with",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                format::starts_with(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "ends_with",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                format::ends_with(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "file",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, _: Option<&str>| {
+                file::file(f, n, v, val, None)
+            }) as Rule,
+        );
+
+        map.insert(
+            "image",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                file::image(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "mimes",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                file::mimes(f, n, v, val, p)
+            }) as Rule,
+        );
+
+        map.insert(
+            "mimetypes",
+            Box::new(|f: &str, n: &str, v: Option<&Value>, val: &Validator, p: Option<&str>| {
+                file::mimetypes(f, n, v, val, p)
+            }) as Rule,
+        );
+
         map
     })
 }
@@ -97,3 +510,46 @@ pub fn split_rule(rule: &str) -> (&str, Option<&str>) {
     let mut parts = rule.splitn(2, ':');
     (parts.next().unwrap(), parts.next())
 }
+
+/// Splits a rule string on `|`, keeping the patterns of `regex:` and
+/// `not_regex:` whole: `"required|regex:/^(a|b)$/|max:3"` has three rules.
+pub fn split_rules(spec: &str) -> Vec<&str> {
+    let mut rules = Vec::new();
+    let mut start = 0;
+
+    while start <= spec.len() {
+        let segment = &spec[start..];
+        let indent = segment.len() - segment.trim_start().len();
+        let skip = pattern_len(segment.trim_start()).map_or(0, |len| indent + len);
+        let end = segment[skip..].find('|').map_or(spec.len(), |i| start + skip + i);
+
+        rules.push(spec[start..end].trim());
+        start = end + 1;
+    }
+
+    rules
+}
+
+/// Length of `regex:/.../` at the start of `segment`, up to the closing
+/// delimiter. Escaped delimiters do not close the pattern.
+fn pattern_len(segment: &str) -> Option<usize> {
+    let prefix = ["regex:", "not_regex:"].into_iter().find(|p| segment.starts_with(p))?;
+    let body = &segment[prefix.len()..];
+
+    let delimiter = body.chars().next()?;
+    if delimiter.is_alphanumeric() || delimiter.is_whitespace() || delimiter == '\\' {
+        return None;
+    }
+
+    let mut escaped = false;
+    for (i, c) in body.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == delimiter => return Some(prefix.len() + i + c.len_utf8()),
+            _ => {}
+        }
+    }
+
+    None
+}
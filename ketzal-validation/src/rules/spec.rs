@@ -0,0 +1,212 @@
+//! What the validator knows about built-in rules: the parameters they
+//! expect, checked by strict mode, and which of them are implicit.
+
+use super::file::ALLOW_SVG;
+use super::{async_rules_registry, custom, rules_registry, split_rule};
+use crate::utils::{date, pattern_regex};
+
+/// What a rule expects after `:`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamKind {
+    None,
+    /// A number, possibly negative or decimal: `min:0.5`.
+    Number,
+    /// Two numbers, the first not above the second: `between:1,10`.
+    Range,
+    /// One or two whole numbers, the first not above the second: `decimal:2,4`.
+    Places,
+    /// A non-empty comma-separated list: `in:a,b`.
+    List,
+    /// Another field followed by one or more values: `required_if:role,admin`.
+    FieldValues,
+    /// Another field: `same:password`.
+    Field,
+    /// Another field or a literal: `gt:min_price`, `after:2024-01-01`.
+    Operand,
+    /// A delimited regular expression with optional flags: `regex:/^[a-z]+$/i`.
+    Pattern,
+    /// A PHP-style date format: `date_format:d/m/Y H:i`.
+    DateFormat,
+    /// A table, then optional columns and values: `unique:users,email,5`.
+    Table,
+    /// Nothing, or `allow_svg`: `image:allow_svg`.
+    Flag,
+}
+
+impl ParamKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Number => "number",
+            Self::Range => "range",
+            Self::Places => "places",
+            Self::List => "list",
+            Self::FieldValues => "field_values",
+            Self::Field => "field",
+            Self::Operand => "operand",
+            Self::Pattern => "pattern",
+            Self::DateFormat => "date_format",
+            Self::Table => "table",
+            Self::Flag => "flag",
+        }
+    }
+}
+
+/// Parameter kind of a built-in rule, `None` for unknown rules.
+pub fn builtin_param(name: &str) -> Option<ParamKind> {
+    let kind = match name {
+        "required" | "nullable" | "string" | "numeric" | "email" | "array" | "distinct"
+        | "confirmed" | "file" | "present" | "filled" | "sometimes" | "prohibited" | "accepted"
+        | "url" | "uuid" | "ulid" | "ip" | "ipv4" | "ipv6" | "alpha" | "alpha_num"
+        | "alpha_dash" | "json" | "lowercase" | "uppercase" | "date" | "timezone" | "integer"
+        | "boolean" | "list" | "declined" | "bail" => ParamKind::None,
+        "min" | "max" | "size" | "multiple_of" => ParamKind::Number,
+        "between" => ParamKind::Range,
+        "decimal" => ParamKind::Places,
+        "in"
+        | "mimes"
+        | "mimetypes"
+        | "required_with"
+        | "required_with_all"
+        | "required_without"
+        | "required_without_all"
+        | "starts_with"
+        | "ends_with" => ParamKind::List,
+        "required_if" | "required_unless" | "prohibited_if" | "prohibited_unless"
+        | "exclude_if" | "exclude_unless" | "accepted_if" | "declined_if" => ParamKind::FieldValues,
+        "same" | "different" => ParamKind::Field,
+        "gt" | "gte" | "lt" | "lte" | "before" | "before_or_equal" | "after" | "after_or_equal"
+        | "date_equals" => ParamKind::Operand,
+        "regex" | "not_regex" => ParamKind::Pattern,
+        "date_format" => ParamKind::DateFormat,
+        "unique" | "exists" => ParamKind::Table,
+        "image" => ParamKind::Flag,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// The parameters of a rule, as reported in errors: `in:a,b` has `a` and
+/// `b`, while a pattern or a date format is a single parameter.
+pub fn split_params(name: &str, param: Option<&str>) -> Vec<String> {
+    match (builtin_param(name), param) {
+        (_, None) => Vec::new(),
+        (Some(ParamKind::Pattern | ParamKind::DateFormat), Some(param)) => vec![param.to_string()],
+        (_, Some(param)) => param.split(',').map(|p| p.trim().to_string()).collect(),
+    }
+}
+
+/// Whether a built-in rule runs when the value is missing or blank.
+///
+/// Other rules are skipped then, so `email` does not report a missing
+/// field, and a failed implicit rule ends the field's validation.
+pub fn is_implicit(name: &str) -> bool {
+    matches!(
+        name,
+        "required"
+            | "required_if"
+            | "required_unless"
+            | "required_with"
+            | "required_with_all"
+            | "required_without"
+            | "required_without_all"
+            | "present"
+            | "filled"
+            | "accepted"
+            | "accepted_if"
+            | "declined"
+            | "declined_if"
+    )
+}
+
+/// Checks that `rule` names a registered rule with a valid parameter.
+pub fn check_rule(rule: &str) -> Result<(), String> {
+    if rule.is_empty() {
+        return Err("empty rule".to_string());
+    }
+
+    let (name, param) = split_rule(rule);
+
+    if custom::is_registered(name) {
+        return Ok(());
+    }
+
+    if !rules_registry().contains_key(name) && !async_rules_registry().contains_key(name) {
+        return Err(format!("unknown validation rule `{name}`"));
+    }
+
+    let Some(kind) = builtin_param(name) else {
+        return Ok(());
+    };
+
+    let param = match (kind, param) {
+        (ParamKind::None, None) => return Ok(()),
+        (ParamKind::None, Some(_)) => {
+            return Err(format!("rule `{name}` does not take a parameter"));
+        }
+        (ParamKind::Flag, None) => return Ok(()),
+        (ParamKind::Flag, Some(param)) if param.trim() == ALLOW_SVG => return Ok(()),
+        (ParamKind::Flag, Some(param)) => {
+            return Err(format!("rule `{name}` only takes `{ALLOW_SVG}`, got `{param}`"));
+        }
+        (_, None | Some("")) => return Err(format!("rule `{name}` requires a parameter")),
+        (_, Some(param)) => param,
+    };
+
+    if kind == ParamKind::Pattern {
+        return pattern_regex(param)
+            .map(|_| ())
+            .map_err(|e| format!("rule `{name}` has an invalid pattern: {e}"));
+    }
+
+    if kind == ParamKind::DateFormat {
+        return date::strftime(param)
+            .map(|_| ())
+            .map_err(|e| format!("rule `{name}` has an invalid format: {e}"));
+    }
+
+    let items: Vec<&str> = param.split(',').map(str::trim).collect();
+
+    match kind {
+        ParamKind::Number if number(param).is_none() => {
+            Err(format!("rule `{name}` expects a number, got `{param}`"))
+        }
+        ParamKind::Range => match items.as_slice() {
+            [low, high] => match (number(low), number(high)) {
+                (Some(low), Some(high)) if low <= high => Ok(()),
+                (Some(_), Some(_)) => Err(format!("rule `{name}` has a minimum above its maximum")),
+                _ => Err(format!("rule `{name}` expects two numbers, got `{param}`")),
+            },
+            _ => Err(format!("rule `{name}` expects two numbers, got `{param}`")),
+        },
+        ParamKind::Places => match items.as_slice() {
+            [places] | [places, _] if places.parse::<usize>().is_err() => {
+                Err(format!("rule `{name}` expects whole numbers, got `{param}`"))
+            }
+            [_] => Ok(()),
+            [low, high] => match (low.parse::<usize>(), high.parse::<usize>()) {
+                (Ok(low), Ok(high)) if low <= high => Ok(()),
+                (Ok(_), Ok(_)) => Err(format!("rule `{name}` has a minimum above its maximum")),
+                _ => Err(format!("rule `{name}` expects whole numbers, got `{param}`")),
+            },
+            _ => Err(format!("rule `{name}` expects one or two whole numbers, got `{param}`")),
+        },
+        ParamKind::List if items.iter().any(|item| item.is_empty()) => {
+            Err(format!("rule `{name}` has an empty item in `{param}`"))
+        }
+        ParamKind::FieldValues if items.len() < 2 || items[0].is_empty() => {
+            Err(format!("rule `{name}` expects a field and a value"))
+        }
+        ParamKind::Field | ParamKind::Operand if items.len() > 1 => {
+            Err(format!("rule `{name}` expects a single field or value, got `{param}`"))
+        }
+        ParamKind::Table if items[0].is_empty() => {
+            Err(format!("rule `{name}` expects a table, got `{param}`"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn number(param: &str) -> Option<f64> {
+    param.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+}
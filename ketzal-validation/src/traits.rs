@@ -1,7 +1,9 @@
-use serde_json::Value;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use serde_path_to_error::Segment;
 use std::collections::HashMap;
 
-use crate::errors::ValidationErrors;
+use crate::errors::{FieldError, ValidationErrors};
 use crate::validator::Validator;
 
 /// Trait for form request validation.
@@ -20,6 +22,11 @@ pub trait FormRequest: Sized {
         HashMap::new()
     }
 
+    /// Whether validation ends at the first failed rule.
+    fn stop_on_first_failure(&self) -> bool {
+        false
+    }
+
     fn prepare_for_validation(&self, _data: &mut HashMap<String, Value>) {}
 
     fn with_validator(&self, _validator: &mut Validator) {}
@@ -38,6 +45,7 @@ pub trait FormRequest: Sized {
         let mut validator = Validator::make(mutable_data.clone(), instance.rules());
         validator.set_custom_messages(instance.messages());
         validator.set_custom_attributes(instance.attributes());
+        validator.set_stop_on_first_failure(instance.stop_on_first_failure());
 
         instance.with_validator(&mut validator);
 
@@ -46,3 +54,96 @@ pub trait FormRequest: Sized {
         Ok(validator.validated_data())
     }
 }
+
+/// A type built from input that passed its own rules.
+///
+/// Implemented by `#[derive(FormRequest)]`, which deserializes the validated
+/// data into the struct carrying the rules.
+pub trait FromValidated: Sized {
+    fn from_validated(data: HashMap<String, Value>) -> Result<Self, ValidationErrors>;
+
+    /// Like [`FromValidated::from_validated`], with messages in `locale`,
+    /// see [`Validator::set_locale`].
+    fn from_validated_in(
+        data: HashMap<String, Value>,
+        _locale: &str,
+    ) -> Result<Self, ValidationErrors> {
+        Self::from_validated(data)
+    }
+}
+
+/// Validates `data` without a `FormRequest` instance.
+pub fn validate_with(
+    data: HashMap<String, Value>,
+    rules: HashMap<&'static str, &'static str>,
+    messages: HashMap<&'static str, &'static str>,
+    attributes: HashMap<&'static str, &'static str>,
+) -> Result<HashMap<String, Value>, ValidationErrors> {
+    validate_in(data, rules, messages, attributes, None)
+}
+
+/// Like [`validate_with`], with messages in `locale` when there is one.
+pub fn validate_in(
+    data: HashMap<String, Value>,
+    rules: HashMap<&'static str, &'static str>,
+    messages: HashMap<&'static str, &'static str>,
+    attributes: HashMap<&'static str, &'static str>,
+    locale: Option<&str>,
+) -> Result<HashMap<String, Value>, ValidationErrors> {
+    let mut validator = Validator::make(data, rules);
+    validator.set_custom_messages(messages);
+    validator.set_custom_attributes(attributes);
+    if let Some(locale) = locale {
+        validator.set_locale(locale);
+    }
+
+    validator.validate()?;
+
+    Ok(validator.validated_data())
+}
+
+/// Deserializes validated data into `T`.
+///
+/// A value that passed the rules but does not fit `T` (e.g. text where `T`
+/// has a number) is reported as a validation error on its field.
+pub fn deserialize_validated<T: DeserializeOwned>(
+    data: HashMap<String, Value>,
+) -> Result<T, ValidationErrors> {
+    let object: Map<String, Value> = data.into_iter().collect();
+
+    serde_path_to_error::deserialize(Value::Object(object)).map_err(|err| {
+        let field = err
+            .path()
+            .iter()
+            .map(|segment| match segment {
+                Segment::Seq { index } => index.to_string(),
+                other => other.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(".");
+        let message = err.inner().to_string();
+
+        // Reported as the rule the value would have needed: `required`, or
+        // `type` with the expected type as its parameter.
+        let error = match message.strip_prefix("missing field `") {
+            Some(rest) => {
+                let name = rest.split('`').next().unwrap_or_default();
+                let field =
+                    if field.is_empty() { name.to_string() } else { format!("{field}.{name}") };
+                let message = format!("The field {field} is required.");
+                FieldError { field, rule: "required".to_string(), params: Vec::new(), message }
+            }
+            None => {
+                let expected = message.rsplit_once("expected ").map(|(_, e)| e.trim());
+                let message = match expected {
+                    Some(expected) => format!("The field {field} must be {expected}."),
+                    None => format!("The field {field} is invalid."),
+                };
+                let params = expected.map(str::to_string).into_iter().collect();
+                FieldError { field, rule: "type".to_string(), params, message }
+            }
+        };
+
+        ValidationErrors::from_iter([error])
+    })
+}
@@ -0,0 +1,76 @@
+//! Reading form strings as the JSON types the type rules check.
+//!
+//! Form and multipart fields arrive as text; these functions accept both
+//! the JSON type and its text, so `"42"` and `42` are the same integer.
+
+use serde_json::{Number, Value};
+
+/// `true`, `false`, `1`, `0` and the strings `"1"`, `"0"`, `"true"` and
+/// `"false"`.
+pub fn as_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(b) => Some(*b),
+        Value::Number(n) => match n.as_u64() {
+            Some(1) => Some(true),
+            Some(0) => Some(false),
+            _ => None,
+        },
+        Value::String(s) => match s.as_str() {
+            "1" | "true" => Some(true),
+            "0" | "false" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// A whole JSON number, or a string of an optional sign and digits.
+pub fn as_integer(value: &Value) -> Option<Number> {
+    match value {
+        Value::Number(n) if n.is_i64() || n.is_u64() => Some(n.clone()),
+        Value::String(s) => {
+            let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            match s.parse::<i64>() {
+                Ok(n) => Some(n.into()),
+                Err(_) => s.strip_prefix('+').unwrap_or(s).parse::<u64>().ok().map(Number::from),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A JSON number, or a string holding a finite number.
+pub fn as_number(value: &Value) -> Option<Number> {
+    match value {
+        Value::Number(n) => Some(n.clone()),
+        Value::String(_) => as_integer(value).or_else(|| {
+            let n = value.as_str()?.parse::<f64>().ok()?;
+            Number::from_f64(n)
+        }),
+        _ => None,
+    }
+}
+
+/// The value converted to the type of the field's rules: `boolean` to a
+/// JSON boolean, `integer`, `numeric` and `decimal` to a number. `None`
+/// when no rule applies or the value is already of that type.
+pub fn cast(value: &Value, has_rule: impl Fn(&str) -> bool) -> Option<Value> {
+    if has_rule("boolean") {
+        return as_bool(value).map(Value::Bool).filter(|cast| cast != value);
+    }
+
+    if !value.is_string() {
+        return None;
+    }
+
+    if has_rule("integer") {
+        as_integer(value).map(Value::Number)
+    } else if has_rule("numeric") || has_rule("decimal") {
+        as_number(value).map(Value::Number)
+    } else {
+        None
+    }
+}
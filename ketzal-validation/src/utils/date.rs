@@ -0,0 +1,215 @@
+//! Dates as compared by the date rules.
+
+use chrono::{
+    DateTime, Days, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, Utc,
+};
+use serde_json::Value;
+
+const DATETIME_FORMATS: [&str; 4] =
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"];
+
+/// A parsed date, in UTC, and whether the input had a time of day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parsed {
+    pub at: NaiveDateTime,
+    pub has_time: bool,
+}
+
+impl Parsed {
+    /// ISO 8601: `2024-05-01` for dates, `2024-05-01T08:30:00Z` otherwise.
+    pub fn to_iso(self) -> String {
+        if self.has_time {
+            self.at.and_utc().to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        } else {
+            self.at.date().format("%Y-%m-%d").to_string()
+        }
+    }
+}
+
+/// Parses `value` as a date in UTC.
+///
+/// Accepts RFC 3339 (`2024-05-01T10:00:00+02:00`), `2024-05-01 10:00:00`,
+/// `2024-05-01T10:00`, a plain date (midnight), the keywords `now`, `today`,
+/// `tomorrow` and `yesterday`, and relative expressions such as `+7 days`,
+/// `tomorrow +2 hours` or `3 weeks ago`.
+pub fn parse(value: &str) -> Option<NaiveDateTime> {
+    read(value).map(|parsed| parsed.at)
+}
+
+/// Parses a JSON string as a date, see [`parse`].
+pub fn from_value(value: &Value) -> Option<NaiveDateTime> {
+    parse(value.as_str()?)
+}
+
+/// Like [`parse`], keeping whether the input had a time of day.
+pub fn read(value: &str) -> Option<Parsed> {
+    let value = value.trim();
+
+    if let Some(parsed) = relative(value) {
+        return Some(parsed);
+    }
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(Parsed { at: date.naive_utc(), has_time: true });
+    }
+
+    if let Some(at) =
+        DATETIME_FORMATS.iter().find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    {
+        return Some(Parsed { at, has_time: true });
+    }
+
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+    Some(Parsed { at: date.and_time(NaiveTime::MIN), has_time: false })
+}
+
+/// Parses `value` with a PHP-style format such as `d/m/Y H:i`.
+///
+/// Like PHP's `DateTime::createFromFormat` as used by Laravel, the value
+/// must match the format exactly: formatting the parsed date back gives the
+/// same text. A format with only a time of day reads today's date.
+pub fn read_format(value: &str, format: &str) -> Option<Parsed> {
+    let format = strftime(format).ok()?;
+
+    if let Ok(date) = DateTime::parse_from_str(value, &format) {
+        return (date.format(&format).to_string() == value)
+            .then(|| Parsed { at: date.naive_utc(), has_time: true });
+    }
+
+    if let Ok(at) = NaiveDateTime::parse_from_str(value, &format) {
+        return (at.format(&format).to_string() == value).then_some(Parsed { at, has_time: true });
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(value, &format) {
+        return (date.format(&format).to_string() == value)
+            .then(|| Parsed { at: date.and_time(NaiveTime::MIN), has_time: false });
+    }
+
+    let time = NaiveTime::parse_from_str(value, &format).ok()?;
+    (time.format(&format).to_string() == value)
+        .then(|| Parsed { at: Utc::now().date_naive().and_time(time), has_time: true })
+}
+
+/// Translates a PHP date format into a `chrono` strftime format.
+///
+/// Supported characters: `d j D l N w m n M F y Y a A g G h H i s u v O P U c
+/// r`. A backslash takes the next character literally; any other letter is
+/// an error, other characters are kept as they are.
+pub fn strftime(format: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(format.len() * 2);
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        let item = match c {
+            'd' => "%d",
+            'j' => "%-d",
+            'D' => "%a",
+            'l' => "%A",
+            'N' => "%u",
+            'w' => "%w",
+            'm' => "%m",
+            'n' => "%-m",
+            'M' => "%b",
+            'F' => "%B",
+            'y' => "%y",
+            'Y' => "%Y",
+            'a' => "%P",
+            'A' => "%p",
+            'g' => "%-I",
+            'G' => "%-H",
+            'h' => "%I",
+            'H' => "%H",
+            'i' => "%M",
+            's' => "%S",
+            'u' => "%6f",
+            'v' => "%3f",
+            'O' => "%z",
+            'P' => "%:z",
+            'U' => "%s",
+            'c' => "%Y-%m-%dT%H:%M:%S%:z",
+            'r' => "%a, %d %b %Y %H:%M:%S %z",
+            '\\' => match chars.next() {
+                Some('%') => "%%",
+                Some(literal) => {
+                    out.push(literal);
+                    continue;
+                }
+                None => return Err("date format ends with `\\`".to_string()),
+            },
+            '%' => "%%",
+            c if c.is_ascii_alphabetic() => {
+                return Err(format!("unsupported date format character `{c}`"));
+            }
+            literal => {
+                out.push(literal);
+                continue;
+            }
+        };
+        out.push_str(item);
+    }
+
+    Ok(out)
+}
+
+/// `now`, `today`, `tomorrow` and `yesterday`, optionally followed by
+/// offsets (`+7 days`, `-1 month`), or offsets alone from now. A trailing
+/// `ago` negates the offsets: `2 weeks ago`.
+fn relative(value: &str) -> Option<Parsed> {
+    let today = Utc::now().date_naive().and_time(NaiveTime::MIN);
+    let mut words: Vec<&str> = value.split_whitespace().collect();
+
+    let (mut parsed, offset_from) = match words.first().copied() {
+        Some("now") => (Parsed { at: Utc::now().naive_utc(), has_time: true }, 1),
+        Some("today") => (Parsed { at: today, has_time: false }, 1),
+        Some("tomorrow") => {
+            (Parsed { at: today.checked_add_days(Days::new(1))?, has_time: false }, 1)
+        }
+        Some("yesterday") => {
+            (Parsed { at: today.checked_sub_days(Days::new(1))?, has_time: false }, 1)
+        }
+        Some(word) if word.starts_with(['+', '-']) || word.parse::<i64>().is_ok() => {
+            (Parsed { at: Utc::now().naive_utc(), has_time: true }, 0)
+        }
+        _ => return None,
+    };
+
+    let ago = words.last() == Some(&"ago") && words.len() > offset_from;
+    if ago {
+        words.pop();
+    }
+
+    let offsets = &words[offset_from..];
+    if !offsets.len().is_multiple_of(2) || (ago && offsets.is_empty()) {
+        return None;
+    }
+
+    for pair in offsets.chunks(2) {
+        let amount: i64 = pair[0].strip_prefix('+').unwrap_or(pair[0]).parse().ok()?;
+        let amount = if ago { -amount } else { amount };
+        let unit = pair[1].strip_suffix('s').unwrap_or(pair[1]);
+
+        parsed.at = match unit {
+            "second" | "sec" => parsed.at.checked_add_signed(Duration::try_seconds(amount)?)?,
+            "minute" | "min" => parsed.at.checked_add_signed(Duration::try_minutes(amount)?)?,
+            "hour" => parsed.at.checked_add_signed(Duration::try_hours(amount)?)?,
+            "day" => parsed.at.checked_add_signed(Duration::try_days(amount)?)?,
+            "week" => parsed.at.checked_add_signed(Duration::try_weeks(amount)?)?,
+            "month" => add_months(parsed.at, amount)?,
+            "year" => add_months(parsed.at, amount.checked_mul(12)?)?,
+            _ => return None,
+        };
+        parsed.has_time |= matches!(unit, "second" | "sec" | "minute" | "min" | "hour");
+    }
+
+    Some(parsed)
+}
+
+fn add_months(at: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let count = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+
+    if months < 0 {
+        at.checked_sub_months(count)
+    } else {
+        at.checked_add_months(count)
+    }
+}
@@ -1,3 +1,6 @@
+pub mod cast;
+pub mod date;
+pub mod path;
 pub mod regex;
 
 pub use regex::*;
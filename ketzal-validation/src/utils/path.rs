@@ -0,0 +1,170 @@
+//! Dot-notation paths into the validated data.
+//!
+//! `address.zip` reads nested objects, `items.2.sku` indexes arrays and
+//! `items.*.sku` matches every element. A top-level key that literally
+//! contains dots wins over the nested lookup.
+
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// The value at `path`.
+pub fn get<'a>(data: &'a HashMap<String, Value>, path: &str) -> Option<&'a Value> {
+    if let Some(value) = data.get(path) {
+        return Some(value);
+    }
+
+    let mut segments = path.split('.');
+    let first = data.get(segments.next()?)?;
+    segments.try_fold(first, |value, segment| child(value, segment))
+}
+
+/// The value at `path`, mutably.
+pub fn get_mut<'a>(data: &'a mut HashMap<String, Value>, path: &str) -> Option<&'a mut Value> {
+    if data.contains_key(path) {
+        return data.get_mut(path);
+    }
+
+    let mut segments = path.split('.');
+    let first = data.get_mut(segments.next()?)?;
+    segments.try_fold(first, |value, segment| match value {
+        Value::Object(map) => map.get_mut(segment),
+        Value::Array(items) => items.get_mut(segment.parse::<usize>().ok()?),
+        _ => None,
+    })
+}
+
+/// The concrete paths matched by `pattern`, in data order.
+///
+/// A `*` expands to the indexes of an array or the keys of an object, and to
+/// nothing when there is no such container. Other segments are kept even if
+/// the data has no value there, so `required` can report them.
+pub fn expand(data: &HashMap<String, Value>, pattern: &str) -> Vec<String> {
+    if !pattern.contains('*') || data.contains_key(pattern) {
+        return vec![pattern.to_string()];
+    }
+
+    let mut paths: Vec<(String, Option<&Value>)> = vec![(String::new(), None)];
+
+    for (depth, segment) in pattern.split('.').enumerate() {
+        let mut next = Vec::new();
+
+        for (prefix, node) in paths {
+            let join =
+                |key: &str| if depth == 0 { key.to_string() } else { format!("{prefix}.{key}") };
+
+            if segment == "*" {
+                let entries: Vec<(String, &Value)> = match node {
+                    None if depth == 0 => {
+                        let mut entries: Vec<_> =
+                            data.iter().map(|(k, v)| (k.clone(), v)).collect();
+                        entries.sort_by(|a, b| a.0.cmp(&b.0));
+                        entries
+                    }
+                    Some(Value::Array(items)) => {
+                        items.iter().enumerate().map(|(i, v)| (i.to_string(), v)).collect()
+                    }
+                    Some(Value::Object(map)) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+                    _ => Vec::new(),
+                };
+                next.extend(entries.into_iter().map(|(key, value)| (join(&key), Some(value))));
+            } else {
+                let value = match node {
+                    None if depth == 0 => data.get(segment),
+                    Some(value) => child(value, segment),
+                    None => None,
+                };
+                next.push((join(segment), value));
+            }
+        }
+
+        paths = next;
+    }
+
+    paths.into_iter().map(|(path, _)| path).collect()
+}
+
+/// Replaces each `*` of `pattern` with the segment at the same position in
+/// `concrete`, so `items.*.type` next to `items.2.sku` reads `items.2.type`.
+pub fn substitute(pattern: &str, concrete: &str) -> String {
+    if !pattern.contains('*') {
+        return pattern.to_string();
+    }
+
+    let concrete: Vec<&str> = concrete.split('.').collect();
+
+    pattern
+        .split('.')
+        .enumerate()
+        .map(|(i, segment)| match (segment, concrete.get(i)) {
+            ("*", Some(actual)) => *actual,
+            (segment, _) => segment,
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Copies the value at `path` from `data` into `out`, creating the objects
+/// and arrays on the way with the shape they have in `data`.
+pub fn copy(data: &HashMap<String, Value>, out: &mut HashMap<String, Value>, path: &str) {
+    if let Some(value) = data.get(path) {
+        out.insert(path.to_string(), value.clone());
+        return;
+    }
+
+    let Some(value) = get(data, path) else {
+        return;
+    };
+
+    let segments: Vec<&str> = path.split('.').collect();
+    let (first, rest) = segments.split_first().expect("split yields a segment");
+
+    let source = &data[*first];
+    let slot = out.entry(first.to_string()).or_insert_with(|| empty_like(source));
+    insert(slot, source, rest, value);
+}
+
+fn insert(slot: &mut Value, source: &Value, segments: &[&str], value: &Value) {
+    let Some((segment, rest)) = segments.split_first() else {
+        *slot = value.clone();
+        return;
+    };
+
+    let Some(source) = child(source, segment) else {
+        return;
+    };
+
+    let next = match slot {
+        Value::Object(map) => map.entry(segment.to_string()).or_insert_with(|| empty_like(source)),
+        Value::Array(items) => {
+            let Ok(index) = segment.parse::<usize>() else {
+                return;
+            };
+            if items.len() <= index {
+                items.resize(index + 1, Value::Null);
+            }
+            if items[index].is_null() {
+                items[index] = empty_like(source);
+            }
+            &mut items[index]
+        }
+        // A whole value copied earlier already holds this path.
+        _ => return,
+    };
+
+    insert(next, source, rest, value);
+}
+
+fn child<'a>(value: &'a Value, segment: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(map) => map.get(segment),
+        Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+        _ => None,
+    }
+}
+
+fn empty_like(value: &Value) -> Value {
+    match value {
+        Value::Array(_) => Value::Array(Vec::new()),
+        _ => Value::Object(Map::new()),
+    }
+}
@@ -1,9 +1,83 @@
-use regex::Regex;
-use std::sync::OnceLock;
+use regex::{Regex, RegexBuilder};
+use std::collections::HashMap;
+use std::sync::{LazyLock, OnceLock, RwLock};
 
 static EMAIL_REGEX: OnceLock<Regex> = OnceLock::new();
+static URL_REGEX: OnceLock<Regex> = OnceLock::new();
+static UUID_REGEX: OnceLock<Regex> = OnceLock::new();
+static ULID_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Patterns of `regex:` and `not_regex:` rules, compiled once.
+static PATTERNS: LazyLock<RwLock<HashMap<String, Regex>>> = LazyLock::new(Default::default);
 
 pub fn get_email_regex() -> &'static Regex {
     EMAIL_REGEX
         .get_or_init(|| Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap())
 }
+
+/// A scheme, `://`, a host and an optional port, path, query and fragment.
+pub fn get_url_regex() -> &'static Regex {
+    URL_REGEX.get_or_init(|| {
+        Regex::new(
+            r"^[a-zA-Z][a-zA-Z0-9+.-]*://([^\s/?#@]+@)?(\[[0-9a-fA-F:.]+\]|[^\s/?#:@\[\]]+)(:\d{1,5})?([/?#]\S*)?$",
+        )
+        .unwrap()
+    })
+}
+
+pub fn get_uuid_regex() -> &'static Regex {
+    UUID_REGEX.get_or_init(|| {
+        Regex::new(r"^(?i)[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$").unwrap()
+    })
+}
+
+/// 26 characters of Crockford's base32; the first one keeps the timestamp
+/// within 48 bits.
+pub fn get_ulid_regex() -> &'static Regex {
+    ULID_REGEX.get_or_init(|| Regex::new(r"^(?i)[0-7][0-9A-HJKMNP-TV-Z]{25}$").unwrap())
+}
+
+/// Compiles the parameter of a `regex:` rule, such as `/^[a-z]+$/i`.
+///
+/// The first character is the delimiter; flags after the closing delimiter
+/// may be `i`, `m`, `s`, `x` and `u` (always on). Compiled patterns are
+/// cached.
+pub fn pattern_regex(param: &str) -> Result<Regex, String> {
+    if let Some(regex) = PATTERNS.read().unwrap_or_else(|e| e.into_inner()).get(param) {
+        return Ok(regex.clone());
+    }
+
+    let (pattern, flags) = split_pattern(param)?;
+    let mut builder = RegexBuilder::new(pattern);
+
+    for flag in flags.chars() {
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'm' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'x' => builder.ignore_whitespace(true),
+            'u' => builder.unicode(true),
+            other => return Err(format!("unknown regex flag `{other}`")),
+        };
+    }
+
+    let regex = builder.build().map_err(|e| e.to_string())?;
+    PATTERNS.write().unwrap_or_else(|e| e.into_inner()).insert(param.to_string(), regex.clone());
+
+    Ok(regex)
+}
+
+/// Splits `/pattern/flags` into the pattern and its flags.
+pub fn split_pattern(param: &str) -> Result<(&str, &str), String> {
+    let mut chars = param.chars();
+    let delimiter = chars
+        .next()
+        .filter(|d| !d.is_alphanumeric() && !d.is_whitespace() && *d != '\\')
+        .ok_or_else(|| "pattern must start with a delimiter, like `/^[a-z]+$/`".to_string())?;
+
+    let body = &param[delimiter.len_utf8()..];
+    let end =
+        body.rfind(delimiter).ok_or_else(|| format!("pattern has no closing `{delimiter}`"))?;
+
+    Ok((&body[..end], &body[end + delimiter.len_utf8()..]))
+}
@@ -1,33 +1,189 @@
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::sync::Arc;
 
-use crate::errors::ValidationErrors;
-use crate::rules::{rules_registry, split_rule};
+use crate::errors::{FieldError, RuleError, ValidationErrors};
+use crate::file::FileMeta;
+use crate::messages::{self, Catalog};
+use crate::presence::PresenceVerifier;
+use crate::rules::async_rule::{self, AsyncRuleMap, AsyncValidationRule};
+use crate::rules::custom::{self, RuleMap, ValidationRule};
+use crate::rules::{
+    async_rules_registry, conditional, rules_registry, spec, split_rule, split_rules,
+};
+use crate::utils::{cast, date, path};
 
+/// Validates `data` against rules keyed by field.
+///
+/// Keys use dot notation: `address.zip` reaches into objects and
+/// `items.*.sku` applies to every element, with errors keyed by the concrete
+/// path (`items.2.sku`). Messages and attributes set for a wildcard key apply
+/// to all the paths it matches.
+///
+/// Custom messages are templates with placeholders such as `:attribute` and
+/// `:min`, see [`messages`](crate::messages). With a locale set, rules
+/// without a custom message take theirs from the locale's catalog.
+///
+/// Fields are validated in the order of their keys, and wildcard matches in
+/// the order of the data, so errors come out in the same order every run.
 pub struct Validator {
     pub data: HashMap<String, Value>,
-    rules: HashMap<String, Vec<String>>,
+    /// Uploaded files, by field path.
+    files: HashMap<String, FileMeta>,
+    rules: HashMap<String, Vec<FieldRule>>,
+    local_rules: RuleMap,
+    local_async_rules: AsyncRuleMap,
+    presence_verifier: Option<Arc<dyn PresenceVerifier>>,
+    /// The wildcard key each concrete path being validated came from.
+    patterns: HashMap<String, String>,
+    /// Paths dropped by `exclude_if` / `exclude_unless`.
+    excluded: HashSet<String>,
+    /// Whether validated dates are rewritten as ISO 8601.
+    normalize_dates: bool,
+    /// Whether validated text is converted to the type of its rules.
+    cast_types: bool,
+    /// Whether validation ends at the first failed rule.
+    stop_on_first_failure: bool,
     pub custom_messages: HashMap<String, String>,
     pub custom_attributes: HashMap<String, String>,
+    locale: Option<String>,
+    /// The catalog of `locale`, looked up when validation starts.
+    catalog: Option<Arc<Catalog>>,
     errors: ValidationErrors,
 }
 
+/// One rule of a field: a name from a rule string, or a rule object.
+#[derive(Clone)]
+enum FieldRule {
+    Named(String),
+    Inline(Arc<dyn ValidationRule>),
+}
+
+impl FieldRule {
+    fn name(&self) -> Option<&str> {
+        self.rule().map(|rule| split_rule(rule).0)
+    }
+
+    /// The rule string, parameters included.
+    fn rule(&self) -> Option<&str> {
+        match self {
+            Self::Named(rule) => Some(rule),
+            Self::Inline(_) => None,
+        }
+    }
+}
+
 impl Validator {
     pub fn make(data: HashMap<String, Value>, rules: HashMap<&'static str, &'static str>) -> Self {
         let parsed_rules = rules
             .into_iter()
-            .map(|(k, v)| (k.to_string(), v.split('|').map(|s| s.trim().to_string()).collect()))
+            .map(|(k, v)| {
+                let rules =
+                    split_rules(v).into_iter().map(|s| FieldRule::Named(s.to_string())).collect();
+                (k.to_string(), rules)
+            })
             .collect();
 
         Self {
             data,
+            files: HashMap::new(),
             rules: parsed_rules,
+            local_rules: RuleMap::new(),
+            local_async_rules: AsyncRuleMap::new(),
+            presence_verifier: None,
+            patterns: HashMap::new(),
+            excluded: HashSet::new(),
+            normalize_dates: false,
+            cast_types: false,
+            stop_on_first_failure: false,
             custom_messages: HashMap::new(),
             custom_attributes: HashMap::new(),
-            errors: HashMap::new(),
+            locale: None,
+            catalog: None,
+            errors: ValidationErrors::new(),
         }
     }
 
+    /// Like [`Validator::make`], but rejects rules that cannot be evaluated:
+    /// unknown names, missing parameters and parameters of the wrong type
+    /// (`min:abc`). [`Validator::make`] skips unknown rules instead.
+    ///
+    /// Rules registered with [`register_rule`](crate::register_rule) are
+    /// known; their parameters are not checked.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let err = Validator::make_strict(data, [("email", "requird")].into()).unwrap_err();
+    /// assert_eq!(err.rule, "requird");
+    /// ```
+    pub fn make_strict(
+        data: HashMap<String, Value>,
+        rules: HashMap<&'static str, &'static str>,
+    ) -> Result<Self, RuleError> {
+        for (field, rule_set) in &rules {
+            for rule in split_rules(rule_set) {
+                spec::check_rule(rule).map_err(|reason| RuleError {
+                    field: field.to_string(),
+                    rule: rule.to_string(),
+                    reason,
+                })?;
+            }
+        }
+
+        Ok(Self::make(data, rules))
+    }
+
+    /// Registers `rule` under `name` for this validator only. It takes
+    /// precedence over global and built-in rules of the same name.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// v.add_rule("even", RuleFn::new("The field :attribute must be even.", |_, v, _, _| {
+    ///     v.and_then(Value::as_u64).is_some_and(|n| n % 2 == 0)
+    /// }));
+    /// ```
+    pub fn add_rule(&mut self, name: &str, rule: impl ValidationRule + 'static) {
+        self.local_rules.insert(name.to_string(), Arc::new(rule));
+    }
+
+    /// Registers an async rule under `name` for this validator, run by
+    /// [`Validator::validate_async`]. It takes precedence over every other
+    /// rule of the same name.
+    pub fn add_async_rule(&mut self, name: &str, rule: impl AsyncValidationRule + 'static) {
+        self.local_async_rules.insert(name.to_string(), Arc::new(rule));
+    }
+
+    /// Sets where `unique` and `exists` look values up.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let users = MemoryVerifier::new().with_rows("users", [json!({ "email": "ada@example.com" })]);
+    /// v.set_presence_verifier(Arc::new(users));
+    /// ```
+    pub fn set_presence_verifier(&mut self, verifier: Arc<dyn PresenceVerifier>) {
+        self.presence_verifier = Some(verifier);
+    }
+
+    pub fn presence_verifier(&self) -> Option<&dyn PresenceVerifier> {
+        self.presence_verifier.as_deref()
+    }
+
+    /// Appends a rule object to the rules of `field`, after its rule string.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut v = Validator::make(data, [("name", "required")].into());
+    /// v.rule("name", Uppercase);
+    /// ```
+    pub fn rule(&mut self, field: &str, rule: impl ValidationRule + 'static) {
+        self.rules.entry(field.to_string()).or_default().push(FieldRule::Inline(Arc::new(rule)));
+    }
+
     pub fn set_custom_messages(&mut self, messages: HashMap<&'static str, &'static str>) {
         self.custom_messages =
             messages.into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
@@ -38,34 +194,379 @@ impl Validator {
             attributes.into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
     }
 
+    /// Takes messages and attribute names from the catalog registered for
+    /// `locale` (or its language, `fr` for `fr-CA`), see
+    /// [`register_catalog`](crate::messages::register_catalog). Custom
+    /// messages and attributes still come first; rules missing from the
+    /// catalog keep their English message.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// messages::load_catalogs("lang")?;
+    /// let mut v = Validator::make(data, [("email", "required")].into());
+    /// v.set_locale("fr");
+    /// ```
+    pub fn set_locale(&mut self, locale: &str) {
+        self.locale = Some(locale.to_string());
+        self.catalog = messages::catalog(locale);
+    }
+
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Rewrites the values of fields with a `date` or `date_format` rule as
+    /// ISO 8601 in [`Validator::validated_data`]: `2024-05-01` for dates,
+    /// `2024-05-01T08:30:00Z` (UTC) for date-times.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut v = Validator::make(data, [("due", "date_format:d/m/Y")].into());
+    /// v.set_normalize_dates(true);
+    /// v.validate()?;
+    /// assert_eq!(v.validated_data()["due"], "2024-05-01"); // from "01/05/2024"
+    /// ```
+    pub fn set_normalize_dates(&mut self, normalize: bool) {
+        self.normalize_dates = normalize;
+    }
+
+    /// Converts text in [`Validator::validated_data`] to the type its rules
+    /// check: `"1"` becomes `true` with `boolean`, `"42"` becomes `42` with
+    /// `integer`, `numeric` or `decimal`. Form input then reads like JSON.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut v = Validator::make(form, [("age", "integer"), ("terms", "boolean")].into());
+    /// v.set_cast_types(true);
+    /// v.validate()?;
+    /// assert_eq!(v.validated_data()["age"], 42); // from "42"
+    /// ```
+    pub fn set_cast_types(&mut self, cast: bool) {
+        self.cast_types = cast;
+    }
+
+    /// Ends validation at the first failed rule, so at most one field has
+    /// errors. [`Validator::validate`] checks every field by default; `bail`
+    /// does the same for the rules of one field.
+    pub fn set_stop_on_first_failure(&mut self, stop: bool) {
+        self.stop_on_first_failure = stop;
+    }
+
+    /// Sets the uploaded files, keyed by field path (`avatar`,
+    /// `photos.0`). The data keeps a value at each path, such as
+    /// [`FileMeta::to_value`], for rules like `required`; `file`, `image`,
+    /// `mimes`, `mimetypes` and file sizes only trust this table.
+    pub fn set_files(&mut self, files: HashMap<String, FileMeta>) {
+        self.files = files;
+    }
+
+    /// The uploaded file at `field`.
+    pub fn file(&self, field: &str) -> Option<&FileMeta> {
+        self.files.get(field)
+    }
+
+    /// The value at `field`, in dot notation.
+    pub fn value(&self, field: &str) -> Option<&Value> {
+        path::get(&self.data, field)
+    }
+
+    /// The wildcard key `field` was expanded from, or `field` itself.
+    pub fn pattern_of<'a>(&'a self, field: &'a str) -> &'a str {
+        self.patterns.get(field).map_or(field, String::as_str)
+    }
+
+    /// Whether the key of `field` has a rule named `rule`.
+    pub fn has_rule(&self, field: &str, rule: &str) -> bool {
+        let rules = self.rules.get(self.pattern_of(field));
+        rules.is_some_and(|rules| rules.iter().any(|r| r.name() == Some(rule)))
+    }
+
+    /// The parameter of the first rule named `rule` on the key of `field`.
+    pub fn rule_param(&self, field: &str, rule: &str) -> Option<&str> {
+        let rules = self.rules.get(self.pattern_of(field))?;
+        rules.iter().filter_map(FieldRule::rule).map(split_rule).find(|(name, _)| *name == rule)?.1
+    }
+
+    /// The name of `field` in messages: its custom attribute, the attribute
+    /// set on its wildcard key, the attribute of the locale's catalog, or
+    /// the field itself.
+    pub fn attribute(&self, field: &str) -> String {
+        let pattern = self.patterns.get(field);
+        let attribute = |attributes: &HashMap<String, String>| {
+            attributes.get(field).or_else(|| attributes.get(pattern?)).cloned()
+        };
+
+        attribute(&self.custom_attributes)
+            .or_else(|| attribute(&self.catalog.as_ref()?.attributes))
+            .unwrap_or_else(|| field.to_string())
+    }
+
+    /// The custom message template of `rule` for `field`, as set: keyed
+    /// `field.rule`, then by its wildcard key (`items.*.sku.rule`), then by
+    /// the most specific key with a `*` matching it (`*.rule`), then by the
+    /// rule alone.
+    pub fn custom_message(&self, field: &str, rule: &str) -> Option<&str> {
+        let message = |key: &str| self.custom_messages.get(&format!("{key}.{rule}"));
+
+        let wildcard = || {
+            let key = format!("{field}.{rule}");
+            self.custom_messages
+                .iter()
+                .filter(|(pattern, _)| {
+                    pattern.contains('*') && messages::key_matches(pattern, &key)
+                })
+                .max_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+                .map(|(_, message)| message)
+        };
+
+        message(field)
+            .or_else(|| message(self.patterns.get(field)?))
+            .or_else(wildcard)
+            .or_else(|| self.custom_messages.get(rule))
+            .map(String::as_str)
+    }
+
+    /// The message of `rule` failing on `field`, from its custom message or
+    /// the locale's catalog, with placeholders replaced. `None` leaves the
+    /// rule's default message.
+    pub fn message(&self, field: &str, rule: &str) -> Option<String> {
+        let template = self.custom_message(field, rule).or_else(|| {
+            let kind = messages::kind(self, field, self.value(field));
+            self.catalog.as_ref()?.message(rule, kind)
+        })?;
+
+        let placeholders = messages::placeholders(self, field, rule, self.rule_param(field, rule));
+        Some(messages::render(template, &placeholders))
+    }
+
+    /// Checks the data against the rules. Async rules, such as `unique`,
+    /// only run in [`Validator::validate_async`].
     pub fn validate(&mut self) -> Result<(), ValidationErrors> {
+        let targets = self.prepare();
+        self.check_rules(&targets);
+        self.result()
+    }
+
+    /// Like [`Validator::validate`], then runs the async rules of the fields
+    /// that passed their other rules, so invalid input is never looked up.
+    ///
+    /// An error of an async rule or of the presence verifier ends
+    /// validation and is returned as the outer error.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut v = Validator::make(data, [("email", "required|email|unique:users")].into());
+    /// v.set_presence_verifier(verifier);
+    /// v.validate_async().await??;
+    /// ```
+    pub async fn validate_async(&mut self) -> io::Result<Result<(), ValidationErrors>> {
+        let targets = self.prepare();
+        self.check_rules(&targets);
+
+        if !self.stop_on_first_failure || self.errors.is_empty() {
+            let failures = self.check_async_rules(&targets).await?;
+
+            if !failures.is_empty() {
+                // Keep the errors in field order, as if checked together.
+                let order: HashMap<&str, usize> =
+                    targets.iter().enumerate().map(|(i, (_, field))| (field.as_str(), i)).collect();
+                let mut errors: Vec<FieldError> =
+                    self.errors.errors().iter().cloned().chain(failures).collect();
+                errors.sort_by_key(|error| order.get(error.field.as_str()).copied());
+                self.errors = errors.into_iter().collect();
+            }
+        }
+
+        Ok(self.result())
+    }
+
+    /// Resets the errors and expands the rule keys into the fields to check,
+    /// as `(key, field)` pairs in order.
+    fn prepare(&mut self) -> Vec<(String, String)> {
         self.errors.clear();
+        self.catalog = self.locale.as_deref().and_then(messages::catalog);
+
+        let mut keys: Vec<&String> = self.rules.keys().collect();
+        keys.sort();
+
+        let targets: Vec<(String, String)> = keys
+            .into_iter()
+            .flat_map(|key| path::expand(&self.data, key).into_iter().map(|f| (key.clone(), f)))
+            .collect();
+        self.patterns = targets
+            .iter()
+            .filter(|(key, field)| key != field)
+            .map(|(key, field)| (field.clone(), key.clone()))
+            .collect();
+        self.excluded = targets
+            .iter()
+            .filter(|(key, field)| {
+                conditional::is_excluded(
+                    field,
+                    self.rules[key].iter().filter_map(FieldRule::rule),
+                    self,
+                )
+            })
+            .map(|(_, field)| field.clone())
+            .collect();
+
+        targets
+    }
+
+    fn check_rules(&mut self, targets: &[(String, String)]) {
         let registry = rules_registry();
+        let custom_rules = custom::custom_rules();
+
+        'fields: for (key, field) in targets {
+            if self.excluded.contains(field) {
+                continue;
+            }
 
-        for (field, rules) in &self.rules {
-            let value = self.data.get(field);
-            let field_name = self.custom_attributes.get(field).unwrap_or(field);
+            let rules = &self.rules[key];
+            let value = path::get(&self.data, field);
+            let field_name = &self.attribute(field);
 
-            // evitar allocation innecesaria
-            if value.is_none() && rules.iter().any(|r| r == "nullable") {
+            let marked = |marker: &str| rules.iter().any(|r| r.name() == Some(marker));
+            if value.is_none() && marked("sometimes") {
                 continue;
             }
 
+            // Only implicit rules see a missing or blank value, or a null
+            // one the field accepts with `nullable`.
+            let absent = is_absent(value) || is_null_allowed(value, marked("nullable"));
+            let bail = marked("bail");
+
             for rule in rules {
-                let (rule_name, param) = split_rule(rule);
+                let (result, implicit, rule_name, param) = match rule {
+                    FieldRule::Inline(rule) => {
+                        if absent && !rule.implicit() {
+                            continue;
+                        }
+                        let result =
+                            custom::run(rule.as_ref(), None, field, field_name, value, self, None);
+                        (result, rule.implicit(), "custom", None)
+                    }
+                    FieldRule::Named(rule) => {
+                        let (rule_name, param) = split_rule(rule);
 
-                if rule_name == "nullable" {
-                    continue;
+                        if rule_name == "nullable"
+                            || self.async_rule(rule_name, &custom_rules).is_some()
+                        {
+                            continue;
+                        }
+
+                        let custom_rule =
+                            self.local_rules.get(rule_name).or_else(|| custom_rules.get(rule_name));
+                        let implicit = custom_rule
+                            .map_or_else(|| spec::is_implicit(rule_name), |rule| rule.implicit());
+
+                        if absent && !implicit {
+                            continue;
+                        }
+
+                        let result = if let Some(rule) = custom_rule {
+                            let name = Some(rule_name);
+                            custom::run(rule.as_ref(), name, field, field_name, value, self, param)
+                        } else if let Some(handler) = registry.get(rule_name) {
+                            handler(field, field_name, value, self, param)
+                        } else {
+                            continue;
+                        };
+                        (result, implicit, rule_name, param)
+                    }
+                };
+
+                if let Err(message) = result {
+                    self.errors.add(FieldError {
+                        field: field.clone(),
+                        rule: rule_name.to_string(),
+                        params: spec::split_params(rule_name, param),
+                        message,
+                    });
+
+                    if self.stop_on_first_failure {
+                        break 'fields;
+                    }
+                    // Later rules of a field that failed `required` would
+                    // only repeat the problem.
+                    if bail || implicit {
+                        break;
+                    }
                 }
+            }
+        }
+    }
+
+    /// Runs the async rules of the fields without errors.
+    async fn check_async_rules(&self, targets: &[(String, String)]) -> io::Result<Vec<FieldError>> {
+        let custom_rules = custom::custom_rules();
+        let mut failures = Vec::new();
+
+        for (key, field) in targets {
+            if self.excluded.contains(field) || self.errors.contains_key(field) {
+                continue;
+            }
+
+            let rules = &self.rules[key];
+            let value = path::get(&self.data, field);
+            let marked = |marker: &str| rules.iter().any(|r| r.name() == Some(marker));
+            if is_absent(value) || is_null_allowed(value, marked("nullable")) {
+                continue;
+            }
+
+            let field_name = self.attribute(field);
+            for (name, param) in rules.iter().filter_map(FieldRule::rule).map(split_rule) {
+                let Some(rule) = self.async_rule(name, &custom_rules) else {
+                    continue;
+                };
+
+                let result =
+                    async_rule::run(rule.as_ref(), name, field, &field_name, value, self, param)
+                        .await?;
+
+                if let Err(message) = result {
+                    failures.push(FieldError {
+                        field: field.clone(),
+                        rule: name.to_string(),
+                        params: spec::split_params(name, param),
+                        message,
+                    });
 
-                if let Some(handler) = registry.get(rule_name) {
-                    if let Err(msg) = handler(field, field_name, value, self, param) {
-                        self.errors.entry(field.clone()).or_default().push(msg);
+                    if self.stop_on_first_failure {
+                        return Ok(failures);
+                    }
+                    if marked("bail") {
+                        break;
                     }
                 }
             }
         }
 
+        Ok(failures)
+    }
+
+    /// The async rule named `name`: one added to this validator, or a
+    /// built-in one unless a sync rule was registered under its name.
+    fn async_rule(
+        &self,
+        name: &str,
+        custom_rules: &RuleMap,
+    ) -> Option<&Arc<dyn AsyncValidationRule>> {
+        if let Some(rule) = self.local_async_rules.get(name) {
+            return Some(rule);
+        }
+        if self.local_rules.contains_key(name) || custom_rules.contains_key(name) {
+            return None;
+        }
+
+        async_rules_registry().get(name)
+    }
+
+    fn result(&self) -> Result<(), ValidationErrors> {
         if self.errors.is_empty() {
             Ok(())
         } else {
@@ -73,7 +574,77 @@ impl Validator {
         }
     }
 
+    /// The data of the validated fields, keeping their nesting: with rules
+    /// on `items.*.sku` only, each item keeps only its `sku`. Fields dropped
+    /// by `exclude_if` / `exclude_unless` are left out.
+    ///
+    /// Dates are normalized if [`Validator::set_normalize_dates`] is on, and
+    /// text is converted if [`Validator::set_cast_types`] is.
     pub fn validated_data(self) -> HashMap<String, Value> {
-        self.data.into_iter().filter(|(k, _)| self.rules.contains_key(k)).collect()
+        let mut validated = HashMap::new();
+
+        for key in self.rules.keys() {
+            for field in path::expand(&self.data, key) {
+                if self.excluded.contains(&field) {
+                    continue;
+                }
+                path::copy(&self.data, &mut validated, &field);
+
+                let converted = self.normalized_date(key, &field).map(Value::String);
+                if let Some(converted) = converted.or_else(|| self.cast_value(key, &field)) {
+                    if let Some(value) = path::get_mut(&mut validated, &field) {
+                        *value = converted;
+                    }
+                }
+            }
+        }
+
+        validated
     }
+
+    /// The value of `field` converted to the type of its rules, when types
+    /// are cast.
+    fn cast_value(&self, key: &str, field: &str) -> Option<Value> {
+        if !self.cast_types {
+            return None;
+        }
+
+        let rules = self.rules.get(key)?;
+        let value = path::get(&self.data, field)?;
+
+        cast::cast(value, |rule| rules.iter().any(|r| r.name() == Some(rule)))
+    }
+
+    /// The ISO 8601 form of a date field, when dates are normalized.
+    fn normalized_date(&self, key: &str, field: &str) -> Option<String> {
+        if !self.normalize_dates {
+            return None;
+        }
+
+        let text = path::get(&self.data, field)?.as_str()?;
+        let rules: Vec<(&str, Option<&str>)> =
+            self.rules.get(key)?.iter().filter_map(FieldRule::rule).map(split_rule).collect();
+
+        let parsed = match rules.iter().find(|(name, _)| *name == "date_format") {
+            Some((_, Some(format))) => date::read_format(text, format)?,
+            _ if rules.iter().any(|(name, _)| *name == "date") => date::read(text)?,
+            _ => return None,
+        };
+
+        Some(parsed.to_iso())
+    }
+}
+
+/// Missing, or blank text: only implicit rules check such values.
+fn is_absent(value: Option<&Value>) -> bool {
+    match value {
+        None => true,
+        Some(Value::String(s)) => s.trim().is_empty(),
+        Some(_) => false,
+    }
+}
+
+/// Whether `value` is `null` on a field marked `nullable`.
+fn is_null_allowed(value: Option<&Value>, nullable: bool) -> bool {
+    nullable && value.is_some_and(Value::is_null)
 }
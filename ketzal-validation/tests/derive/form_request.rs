@@ -0,0 +1,138 @@
+use ketzal_validation::{FormRequest, FromValidated, RuleFn};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for #[derive(FormRequest)]
+
+#[derive(Debug, Deserialize, FormRequest)]
+struct Register {
+    #[rule("required|string")]
+    #[rule("min:3")]
+    #[message(min = "Pick a longer name.")]
+    name: String,
+
+    #[rule("required|email")]
+    #[attribute("e-mail address")]
+    #[serde(rename = "emailAddress")]
+    email: String,
+
+    #[rule("nullable|numeric")]
+    age: Option<u32>,
+
+    #[serde(default)]
+    newsletter: bool,
+}
+
+#[test]
+fn derives_rules_messages_and_attributes() {
+    let form = Register { name: String::new(), email: String::new(), age: None, newsletter: false };
+
+    assert_eq!(form.rules()["name"], "required|string|min:3");
+    assert_eq!(form.rules()["emailAddress"], "required|email");
+    assert!(!form.rules().contains_key("newsletter"));
+    assert_eq!(form.messages()["name.min"], "Pick a longer name.");
+    assert_eq!(form.attributes()["emailAddress"], "e-mail address");
+}
+
+#[test]
+fn from_validated_deserializes_into_the_struct() {
+    let data = make(json!({"name": "Ada", "emailAddress": "ada@example.com", "age": 36}));
+
+    let form = Register::from_validated(data).unwrap();
+
+    assert_eq!(form.name, "Ada");
+    assert_eq!(form.email, "ada@example.com");
+    assert_eq!(form.age, Some(36));
+    assert!(!form.newsletter);
+}
+
+#[test]
+fn unvalidated_fields_are_dropped() {
+    let data = make(json!({"name": "Ada", "emailAddress": "ada@example.com", "newsletter": true}));
+
+    let form = Register::from_validated(data).unwrap();
+
+    assert!(!form.newsletter);
+}
+
+#[test]
+fn failed_rules_use_custom_messages_and_attributes() {
+    let data = make(json!({"name": "Al", "emailAddress": "nope"}));
+
+    let errors = Register::from_validated(data).unwrap_err();
+
+    assert_eq!(errors["name"], ["Pick a longer name."]);
+    assert!(errors["emailAddress"][0].contains("e-mail address"));
+}
+
+#[test]
+fn values_that_do_not_fit_the_type_become_field_errors() {
+    let data = make(json!({"name": "Ada", "emailAddress": "ada@example.com", "age": -4}));
+
+    let errors = Register::from_validated(data).unwrap_err();
+
+    assert_eq!(errors["age"], ["The field age must be u32."]);
+}
+
+#[test]
+fn messages_accept_keyword_rule_names() {
+    #[derive(Debug, Deserialize, FormRequest)]
+    struct Choice {
+        #[rule("required|in:red,blue")]
+        #[message(r#in = "Pick red or blue.")]
+        color: String,
+    }
+
+    let choice = Choice::from_validated(make(json!({"color": "red"}))).unwrap();
+    let errors = Choice::from_validated(make(json!({"color": "green"}))).unwrap_err();
+
+    assert_eq!(choice.color, "red");
+    assert_eq!(errors["color"], ["Pick red or blue."]);
+}
+
+#[test]
+fn custom_names_close_to_built_in_ones_are_declared() {
+    ketzal_validation::check_rules!(custom_rules = ["zip", "iban"]; "required|zip", "iban");
+
+    #[derive(Debug, Deserialize, FormRequest)]
+    #[custom_rules("zip")]
+    struct Address {
+        #[rule("required|zip")]
+        code: String,
+    }
+
+    ketzal_validation::register_rule(
+        "zip",
+        RuleFn::new("The field :attribute must be a zip code.", |_, v, _, _| {
+            v.and_then(|v| v.as_str()).is_some_and(|s| s.len() == 5)
+        }),
+    );
+
+    assert_eq!(Address::from_validated(make(json!({"code": "75001"}))).unwrap().code, "75001");
+    assert!(Address::from_validated(make(json!({"code": "750"}))).is_err());
+}
+
+#[test]
+fn custom_rule_names_are_checked_at_runtime() {
+    #[derive(Debug, Deserialize, FormRequest)]
+    struct Coupon {
+        #[rule("required|derive_shouting")]
+        #[message(derive_shouting = "Codes are uppercase.")]
+        code: String,
+    }
+
+    ketzal_validation::register_rule(
+        "derive_shouting",
+        RuleFn::new("unused", |_, v, _, _| {
+            v.and_then(|v| v.as_str()).is_some_and(|s| s == s.to_uppercase())
+        }),
+    );
+
+    let coupon = Coupon::from_validated(make(json!({"code": "SAVE10"}))).unwrap();
+    let errors = Coupon::from_validated(make(json!({"code": "save10"}))).unwrap_err();
+
+    assert_eq!(coupon.code, "SAVE10");
+    assert_eq!(errors["code"], ["Codes are uppercase."]);
+}
@@ -0,0 +1 @@
+pub mod form_request;
@@ -0,0 +1,175 @@
+use ketzal_validation::messages::{self, CatalogError};
+use ketzal_validation::{Catalog, FormRequest, FromValidated, Validator};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for locale catalogs
+
+#[test]
+fn catalog_messages_replace_the_english_defaults() {
+    ketzal_validation::register_catalog("fr", french());
+
+    let errors = validate_in("fr", json!({ "email": "" }), [("email", "required")]);
+
+    assert_eq!(errors["email"], ["Le champ adresse e-mail est obligatoire."]);
+}
+
+#[test]
+fn catalog_messages_can_depend_on_the_kind_of_value() {
+    ketzal_validation::register_catalog("fr", french());
+    let rules = [("name", "min:3"), ("age", "integer|min:18"), ("tags", "array|min:2")];
+
+    let errors = validate_in("fr", json!({ "name": "Al", "age": 12, "tags": ["a"] }), rules);
+
+    assert_eq!(errors["name"], ["Le champ name doit contenir au moins 3 caractères."]);
+    assert_eq!(errors["age"], ["Le champ age doit être au moins 18."]);
+    assert_eq!(errors["tags"], ["The field tags must have at least 2 items."]);
+}
+
+#[test]
+fn rules_missing_from_the_catalog_stay_in_english() {
+    ketzal_validation::register_catalog("fr", french());
+
+    let errors = validate_in("fr", json!({ "email": "nope" }), [("email", "email")]);
+
+    assert_eq!(errors["email"], ["The field adresse e-mail must be a valid email."]);
+}
+
+#[test]
+fn custom_messages_and_attributes_come_first() {
+    ketzal_validation::register_catalog("fr", french());
+    let mut v = Validator::make(make(json!({})), [("email", "required")].into());
+    v.set_custom_messages([("email.required", "Il faut :attribute.")].into());
+    v.set_custom_attributes([("email", "un courriel")].into());
+    v.set_locale("fr");
+
+    assert_eq!(v.validate().unwrap_err()["email"], ["Il faut un courriel."]);
+}
+
+#[test]
+fn regions_fall_back_to_their_language() {
+    ketzal_validation::register_catalog(
+        "es",
+        Catalog::from_json(r#"{ "required": "Falta :attribute." }"#).unwrap(),
+    );
+
+    assert_eq!(messages::resolve_locale("es_MX").as_deref(), Some("es"));
+    assert_eq!(validate_in("es-MX", json!({}), [("name", "required")])["name"], ["Falta name."]);
+}
+
+#[test]
+fn unknown_locales_keep_english() {
+    assert_eq!(messages::resolve_locale("tlh"), None);
+
+    let errors = validate_in("tlh", json!({}), [("name", "required")]);
+
+    assert_eq!(errors["name"], ["The field name is required."]);
+}
+
+#[test]
+fn toml_catalogs_read_like_json() {
+    let catalog = Catalog::from_toml(
+        r#"
+        required = "Feld :attribute fehlt."
+
+        [max]
+        string = ":attribute darf höchstens :max Zeichen haben."
+
+        [attributes]
+        name = "Name"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(catalog.message("required", "string"), Some("Feld :attribute fehlt."));
+    assert_eq!(
+        catalog.message("max", "string"),
+        Some(":attribute darf höchstens :max Zeichen haben.")
+    );
+    assert_eq!(catalog.message("max", "numeric"), None);
+    assert_eq!(catalog.attributes["name"], "Name");
+}
+
+#[test]
+fn catalogs_load_from_a_directory() {
+    let dir = std::env::temp_dir().join(format!("ketzal-catalogs-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("nl.json"), r#"{ "required": ":attribute is verplicht." }"#).unwrap();
+    std::fs::write(dir.join("pt_BR.toml"), r#"required = "O campo :attribute é obrigatório.""#)
+        .unwrap();
+    std::fs::write(dir.join("README.md"), "not a catalog").unwrap();
+
+    let locales = ketzal_validation::load_catalogs(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(locales, ["nl", "pt-br"]);
+    assert_eq!(
+        validate_in("nl", json!({}), [("name", "required")])["name"],
+        ["name is verplicht."]
+    );
+    assert_eq!(
+        validate_in("pt-BR", json!({}), [("name", "required")])["name"],
+        ["O campo name é obrigatório."]
+    );
+}
+
+#[test]
+fn invalid_catalogs_are_errors() {
+    assert!(matches!(Catalog::from_json(r#"{ "required": 3 }"#), Err(CatalogError::Parse(_))));
+    assert!(matches!(Catalog::from_file("lang/fr.yaml"), Err(CatalogError::Io(_))));
+
+    let path = std::env::temp_dir().join(format!("ketzal-catalog-{}.yaml", std::process::id()));
+    std::fs::write(&path, "required: x").unwrap();
+    let result = Catalog::from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(result, Err(CatalogError::Extension(_))));
+}
+
+#[derive(Debug, Deserialize, FormRequest)]
+struct Signup {
+    #[rule("required")]
+    email: String,
+}
+
+#[test]
+fn derived_requests_validate_in_a_locale() {
+    ketzal_validation::register_catalog("fr", french());
+
+    let errors = Signup::from_validated_in(make(json!({})), "fr").unwrap_err();
+    let signup = Signup::from_validated_in(make(json!({ "email": "ada@example.com" })), "fr");
+
+    assert_eq!(errors["email"], ["Le champ adresse e-mail est obligatoire."]);
+    assert_eq!(signup.unwrap().email, "ada@example.com");
+    assert_eq!(
+        Signup::from_validated(make(json!({}))).unwrap_err()["email"],
+        ["The field email is required."]
+    );
+}
+
+fn french() -> Catalog {
+    Catalog::from_json(
+        r#"{
+            "required": "Le champ :attribute est obligatoire.",
+            "min": {
+                "string": "Le champ :attribute doit contenir au moins :min caractères.",
+                "numeric": "Le champ :attribute doit être au moins :min."
+            },
+            "attributes": { "email": "adresse e-mail" }
+        }"#,
+    )
+    .unwrap()
+}
+
+fn validate_in<const N: usize>(
+    locale: &str,
+    data: Value,
+    rules: [(&'static str, &'static str); N],
+) -> ketzal_validation::ValidationErrors {
+    let mut v = Validator::make(make(data), rules.into());
+    v.set_locale(locale);
+
+    v.validate().unwrap_err()
+}
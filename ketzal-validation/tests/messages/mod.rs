@@ -0,0 +1,2 @@
+pub mod catalogs;
+pub mod templates;
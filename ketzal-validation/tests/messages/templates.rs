@@ -0,0 +1,172 @@
+use ketzal_validation::{RuleFn, Validator};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+use crate::helpers::make;
+
+/// Tests for placeholders and wildcard keys in custom messages
+
+#[test]
+fn attribute_and_parameters_are_replaced() {
+    let messages = [("name.min", ":Attribute needs :min characters, got \":input\".")];
+
+    let errors = check(json!({ "name": "Al" }), [("name", "min:3")], messages);
+
+    assert_eq!(errors["name"], ["Name needs 3 characters, got \"Al\"."]);
+}
+
+#[test]
+fn values_are_not_read_as_placeholders() {
+    let mut v = Validator::make(make(json!({ "code": "x" })), [("code", "in:a,b")].into());
+    v.set_custom_messages([("code.in", ":attribute got :input.")].into());
+    v.set_custom_attributes([("code", "code (not :input)")].into());
+
+    assert_eq!(v.validate().unwrap_err()["code"], ["code (not :input) got x."]);
+}
+
+#[test]
+fn custom_attributes_name_the_field() {
+    let mut v = Validator::make(make(json!({ "email": "" })), [("email", "required")].into());
+    v.set_custom_messages([("email.required", ":ATTRIBUTE / :attribute")].into());
+    v.set_custom_attributes([("email", "e-mail address")].into());
+
+    assert_eq!(v.validate().unwrap_err()["email"], ["E-MAIL ADDRESS / e-mail address"]);
+}
+
+#[test]
+fn between_has_min_and_max() {
+    let messages = [("age.between", ":attribute must be from :min to :max.")];
+
+    let errors = check(json!({ "age": 70 }), [("age", "numeric|between:18,65")], messages);
+
+    assert_eq!(errors["age"], ["age must be from 18 to 65."]);
+}
+
+#[test]
+fn lists_are_values() {
+    let messages = [("role.in", "Pick one of :values.")];
+
+    let errors = check(json!({ "role": "root" }), [("role", "in:admin,user")], messages);
+
+    assert_eq!(errors["role"], ["Pick one of admin, user."]);
+}
+
+#[test]
+fn other_fields_use_their_attribute() {
+    let mut v = Validator::make(
+        make(json!({ "password": "a", "password_again": "b" })),
+        [("password", "same:password_again")].into(),
+    );
+    v.set_custom_messages([("password.same", ":attribute must match :other.")].into());
+    v.set_custom_attributes([("password_again", "the confirmation")].into());
+
+    assert_eq!(v.validate().unwrap_err()["password"], ["password must match the confirmation."]);
+}
+
+#[test]
+fn conditional_rules_have_other_and_values() {
+    let messages = [
+        ("phone.required_if", ":attribute is needed when :other is :value."),
+        ("fax.required_if", ":other in [:values]"),
+    ];
+    let rules = [("phone", "required_if:contact,phone"), ("fax", "required_if:contact,fax,phone")];
+
+    let errors = check(json!({ "contact": "phone" }), rules, messages);
+
+    assert_eq!(errors["phone"], ["phone is needed when contact is phone."]);
+    assert_eq!(errors["fax"], ["contact in [fax, phone]"]);
+}
+
+#[test]
+fn date_rules_have_date_and_format() {
+    let messages = [("end.after", "After :date."), ("day.date_format", "Use :format.")];
+    let rules = [("end", "after:start"), ("day", "date_format:d/m/Y")];
+
+    let errors =
+        check(json!({ "start": "2024-05-02", "end": "2024-05-01", "day": "x" }), rules, messages);
+
+    assert_eq!(errors["end"], ["After start."]);
+    assert_eq!(errors["day"], ["Use d/m/Y."]);
+}
+
+#[test]
+fn registered_rules_have_values() {
+    let mut v = Validator::make(make(json!({ "n": 7 })), [("n", "divisible_by:3")].into());
+    v.add_rule(
+        "divisible_by",
+        RuleFn::new("unused", |_, value, params, _| {
+            let by: u64 = params[0].parse().unwrap();
+            value.and_then(Value::as_u64).is_some_and(|n| n % by == 0)
+        }),
+    );
+    v.set_custom_messages([("n.divisible_by", ":attribute is not divisible by :values.")].into());
+
+    assert_eq!(v.validate().unwrap_err()["n"], ["n is not divisible by 3."]);
+}
+
+#[test]
+fn wildcard_keys_cover_every_field() {
+    let messages = [("*.required", "Fill in :attribute.")];
+
+    let errors = check(json!({}), [("name", "required"), ("address.zip", "required")], messages);
+
+    assert_eq!(errors["name"], ["Fill in name."]);
+    assert_eq!(errors["address.zip"], ["Fill in address.zip."]);
+}
+
+#[test]
+fn wildcard_keys_match_array_elements() {
+    let data = json!({ "items": [{ "sku": "" }, { "sku": "A1" }, { "sku": "" }] });
+    let messages = [("items.*.sku.required", "Item :attribute needs a SKU.")];
+
+    let errors = check(data, [("items.*.sku", "required")], messages);
+
+    assert_eq!(errors["items.0.sku"], ["Item items.0.sku needs a SKU."]);
+    assert_eq!(errors["items.2.sku"], ["Item items.2.sku needs a SKU."]);
+}
+
+#[test]
+fn the_most_specific_key_wins() {
+    let messages = [
+        ("required", "Rule."),
+        ("*.required", "Any field."),
+        ("items.*.required", "Any item."),
+        ("items.0.sku.required", "First SKU."),
+    ];
+    let rules = [("items.*.sku", "required"), ("items.*.name", "required"), ("title", "required")];
+
+    let errors = check(json!({ "items": [{}, {}] }), rules, messages);
+
+    assert_eq!(errors["items.0.sku"], ["First SKU."]);
+    assert_eq!(errors["items.1.sku"], ["Any item."]);
+    assert_eq!(errors["title"], ["Any field."]);
+}
+
+#[test]
+fn rule_keys_apply_to_all_fields() {
+    let errors = check(json!({}), [("name", "required")], [("required", ":attribute?")]);
+
+    assert_eq!(errors["name"], ["name?"]);
+}
+
+#[test]
+fn default_messages_are_left_as_they_are() {
+    let errors = check(json!({ "day": "x" }), [("day", "date_format:H:i")], []);
+
+    assert_eq!(errors["day"], ["The field day must match the format H:i."]);
+}
+
+fn check<const R: usize, const M: usize>(
+    value: Value,
+    rules: [(&'static str, &'static str); R],
+    messages: [(&'static str, &'static str); M],
+) -> HashMap<String, Vec<String>> {
+    let mut v = Validator::make(make(value), rules.into());
+    v.set_custom_messages(messages.into());
+
+    v.validate()
+        .unwrap_err()
+        .iter()
+        .map(|(field, messages)| (field.clone(), messages.clone()))
+        .collect()
+}
@@ -1,4 +1,7 @@
+pub mod derive;
 pub mod helpers;
+pub mod messages;
+pub mod presence;
 pub mod registry;
 pub mod rules;
 pub mod validator;
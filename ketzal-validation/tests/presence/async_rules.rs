@@ -0,0 +1,165 @@
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use ketzal_validation::presence::{Except, MemoryVerifier, PresenceFuture};
+use ketzal_validation::rules::RuleFuture;
+use ketzal_validation::{AsyncValidationRule, PresenceVerifier, Validator};
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for async rules and `Validator::validate_async`
+
+#[tokio::test]
+async fn custom_async_rule_runs() {
+    let mut v = Validator::make(make(json!({ "code": "ABC" })), [("code", "reserved")].into());
+    v.add_async_rule("reserved", Reserved);
+
+    let errors = v.validate_async().await.unwrap().unwrap_err();
+
+    assert_eq!(errors["code"], ["The code is reserved."]);
+}
+
+#[tokio::test]
+async fn async_rules_wait_for_sync_rules() {
+    let verifier = Counting::default();
+    let mut v = Validator::make(
+        make(json!({ "email": "not an email" })),
+        [("email", "email|unique:users")].into(),
+    );
+    v.set_presence_verifier(Arc::new(verifier.clone()));
+
+    let errors = v.validate_async().await.unwrap().unwrap_err();
+
+    assert_eq!(errors.errors().iter().map(|e| e.rule.as_str()).collect::<Vec<_>>(), ["email"]);
+    assert_eq!(verifier.calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn errors_keep_field_order() {
+    let users = MemoryVerifier::new().with_rows("users", [json!({ "email": "ada@example.com" })]);
+    let data = json!({ "age": "old", "email": "ada@example.com", "name": "" });
+    let rules = [("age", "integer"), ("email", "unique:users"), ("name", "required")];
+    let mut v = Validator::make(make(data), rules.into());
+    v.set_presence_verifier(Arc::new(users));
+
+    let errors = v.validate_async().await.unwrap().unwrap_err();
+
+    assert_eq!(errors.keys().collect::<Vec<_>>(), ["age", "email", "name"]);
+}
+
+#[tokio::test]
+async fn stop_on_first_failure_skips_async_rules() {
+    let verifier = Counting::default();
+    let data = json!({ "age": "old", "email": "ada@example.com" });
+    let mut v = Validator::make(make(data), [("age", "integer"), ("email", "unique:users")].into());
+    v.set_presence_verifier(Arc::new(verifier.clone()));
+    v.set_stop_on_first_failure(true);
+
+    let errors = v.validate_async().await.unwrap().unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(verifier.calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn missing_verifier_is_an_error() {
+    let mut v = Validator::make(
+        make(json!({ "email": "ada@example.com" })),
+        [("email", "unique:users")].into(),
+    );
+
+    let error = v.validate_async().await.err().unwrap();
+
+    assert!(error.to_string().contains("presence verifier"));
+}
+
+#[tokio::test]
+async fn verifier_errors_are_returned() {
+    let mut v = Validator::make(make(json!({ "id": 1 })), [("id", "exists:users")].into());
+    v.set_presence_verifier(Arc::new(Offline));
+
+    let error = v.validate_async().await.err().unwrap();
+
+    assert_eq!(error.kind(), io::ErrorKind::NotConnected);
+}
+
+#[tokio::test]
+async fn validated_data_is_kept() {
+    let mut v = Validator::make(
+        make(json!({ "email": "grace@example.com" })),
+        [("email", "unique:users")].into(),
+    );
+    v.set_presence_verifier(Arc::new(MemoryVerifier::new()));
+
+    v.validate_async().await.unwrap().unwrap();
+
+    assert_eq!(v.validated_data()["email"], "grace@example.com");
+}
+
+#[test]
+fn validate_async_is_send() {
+    fn assert_send<T: Send>(_: T) {}
+
+    let mut v = Validator::make(make(json!({})), [("email", "unique:users")].into());
+    assert_send(v.validate_async());
+}
+
+#[test]
+fn strict_mode_accepts_table_rules() {
+    let rules = [("email", "unique:users,email,5,id"), ("role", "exists:roles")];
+
+    assert!(Validator::make_strict(make(json!({})), rules.into()).is_ok());
+    assert!(Validator::make_strict(make(json!({})), [("email", "unique:,email")].into()).is_err());
+}
+
+struct Reserved;
+
+impl AsyncValidationRule for Reserved {
+    fn passes<'a>(
+        &'a self,
+        _: &'a str,
+        value: Option<&'a Value>,
+        _: &'a [&'a str],
+        _: &'a Validator,
+    ) -> RuleFuture<'a> {
+        Box::pin(async move { Ok(value != Some(&json!("ABC"))) })
+    }
+
+    fn message(&self, field_name: &str, _params: &[&str]) -> String {
+        format!("The {field_name} is reserved.")
+    }
+}
+
+#[derive(Clone, Default)]
+struct Counting {
+    calls: Arc<AtomicUsize>,
+}
+
+impl PresenceVerifier for Counting {
+    fn count<'a>(
+        &'a self,
+        _: &'a str,
+        _: &'a str,
+        _: &'a Value,
+        _: Option<Except<'a>>,
+    ) -> PresenceFuture<'a> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async { Ok(0) })
+    }
+}
+
+struct Offline;
+
+impl PresenceVerifier for Offline {
+    fn count<'a>(
+        &'a self,
+        _: &'a str,
+        _: &'a str,
+        _: &'a Value,
+        _: Option<Except<'a>>,
+    ) -> PresenceFuture<'a> {
+        Box::pin(async { Err(io::Error::new(io::ErrorKind::NotConnected, "database is down")) })
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use ketzal_validation::presence::MemoryVerifier;
+use ketzal_validation::{ValidationErrors, Validator};
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the `exists` rule
+
+#[tokio::test]
+async fn known_value_passes() {
+    let result = check(json!({ "role_id": 2 }), "exists:roles,id").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn unknown_value_fails() {
+    let errors = check(json!({ "role_id": 9 }), "exists:roles,id").await.unwrap_err();
+
+    assert_eq!(errors["role_id"], ["The selected role_id is invalid."]);
+}
+
+#[tokio::test]
+async fn numbers_match_text() {
+    let result = check(json!({ "role_id": "1" }), "exists:roles,id").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn every_array_element_must_exist() {
+    assert!(check(json!({ "role_id": [1, 2] }), "array|exists:roles,id").await.is_ok());
+    assert!(check(json!({ "role_id": [1, 9] }), "array|exists:roles,id").await.is_err());
+}
+
+#[tokio::test]
+async fn missing_optional_value_is_not_looked_up() {
+    let result = check(json!({}), "exists:roles,id").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn null_is_not_looked_up_when_nullable() {
+    let result = check(json!({ "role_id": null }), "nullable|exists:roles,id").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn missing_table_fails() {
+    let result = check(json!({ "role_id": 1 }), "exists:groups,id").await;
+
+    assert!(result.is_err());
+}
+
+async fn check(data: Value, rule: &'static str) -> Result<(), ValidationErrors> {
+    let roles = MemoryVerifier::new().with_rows(
+        "roles",
+        [json!({ "id": 1, "name": "admin" }), json!({ "id": 2, "name": "user" })],
+    );
+
+    let mut v = Validator::make(make(data), [("role_id", rule)].into());
+    v.set_presence_verifier(Arc::new(roles));
+    v.validate_async().await.unwrap()
+}
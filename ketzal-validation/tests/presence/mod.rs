@@ -0,0 +1,4 @@
+pub mod async_rules;
+pub mod exists;
+pub mod sql;
+pub mod unique;
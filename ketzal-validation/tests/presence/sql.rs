@@ -0,0 +1,90 @@
+#![cfg(feature = "sql")]
+
+use std::sync::Arc;
+
+use ketzal_validation::presence::SqlVerifier;
+use ketzal_validation::{ValidationErrors, Validator};
+use serde_json::{json, Value};
+use sqlx::any::AnyPoolOptions;
+use sqlx::AnyPool;
+
+use crate::helpers::make;
+
+/// Tests for `unique` and `exists` against SQLite
+
+#[tokio::test]
+async fn unique_counts_rows() {
+    let pool = pool().await;
+
+    assert!(check(&pool, json!({ "email": "grace@example.com" }), "unique:users").await.is_ok());
+    assert!(check(&pool, json!({ "email": "ada@example.com" }), "unique:users").await.is_err());
+}
+
+#[tokio::test]
+async fn unique_leaves_out_the_except_row() {
+    let pool = pool().await;
+    let data = json!({ "email": "ada@example.com" });
+
+    assert!(check(&pool, data.clone(), "unique:users,email,1").await.is_ok());
+    assert!(check(&pool, data, "unique:users,email,2").await.is_err());
+}
+
+#[tokio::test]
+async fn unique_counts_rows_with_a_null_except_column() {
+    let pool = pool().await;
+    let data = json!({ "email": "ada@example.com" });
+
+    assert!(check(&pool, data, "unique:users,email,alan,username").await.is_err());
+}
+
+#[tokio::test]
+async fn except_values_are_compared_as_text() {
+    let pool = pool().await;
+    let data = json!({ "email": "joan@example.com" });
+
+    assert!(check(&pool, data.clone(), "unique:users,email,007,username").await.is_ok());
+    assert!(check(&pool, data, "unique:users,email,7,username").await.is_err());
+}
+
+#[tokio::test]
+async fn exists_binds_numbers() {
+    let pool = pool().await;
+
+    assert!(check(&pool, json!({ "email": [1, 2] }), "array|exists:users,id").await.is_ok());
+    assert!(check(&pool, json!({ "email": 3 }), "exists:users,id").await.is_err());
+}
+
+#[tokio::test]
+async fn bad_identifiers_are_refused() {
+    let pool = pool().await;
+    let mut v =
+        Validator::make(make(json!({ "email": "x" })), [("email", "unique:users;drop")].into());
+    v.set_presence_verifier(Arc::new(SqlVerifier::new(pool)));
+
+    assert!(v.validate_async().await.is_err());
+}
+
+async fn pool() -> AnyPool {
+    sqlx::any::install_default_drivers();
+    let pool = AnyPoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT NOT NULL, username TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO users (id, email, username) \
+         VALUES (1, 'ada@example.com', NULL), (2, 'alan@example.com', 'alan'), \
+         (7, 'joan@example.com', '007')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    pool
+}
+
+async fn check(pool: &AnyPool, data: Value, rule: &'static str) -> Result<(), ValidationErrors> {
+    let mut v = Validator::make(make(data), [("email", rule)].into());
+    v.set_presence_verifier(Arc::new(SqlVerifier::new(pool.clone())));
+    v.validate_async().await.unwrap()
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use ketzal_validation::presence::MemoryVerifier;
+use ketzal_validation::{ValidationErrors, Validator};
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the `unique` rule
+
+#[tokio::test]
+async fn new_value_passes() {
+    let result = check(json!({ "email": "grace@example.com" }), [("email", "unique:users")]).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn taken_value_fails() {
+    let errors = check(json!({ "email": "ada@example.com" }), [("email", "unique:users")])
+        .await
+        .unwrap_err();
+
+    assert_eq!(errors["email"], ["The field email has already been taken."]);
+    assert_eq!(errors.errors()[0].rule, "unique");
+    assert_eq!(errors.errors()[0].params, ["users"]);
+}
+
+#[tokio::test]
+async fn column_can_be_named() {
+    let data = json!({ "contact": "ada@example.com" });
+
+    let result = check(data, [("contact", "unique:users,email")]).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn column_defaults_to_last_segment_of_field() {
+    let data =
+        json!({ "people": [{ "email": "grace@example.com" }, { "email": "ada@example.com" }] });
+
+    let errors = check(data, [("people.*.email", "unique:users")]).await.unwrap_err();
+
+    assert_eq!(errors.keys().collect::<Vec<_>>(), ["people.1.email"]);
+}
+
+#[tokio::test]
+async fn except_ignores_the_row_being_updated() {
+    let data = json!({ "email": "ada@example.com" });
+
+    assert!(check(data.clone(), [("email", "unique:users,email,1")]).await.is_ok());
+    assert!(check(data, [("email", "unique:users,email,2")]).await.is_err());
+}
+
+#[tokio::test]
+async fn except_can_use_another_column() {
+    let data = json!({ "email": "ada@example.com" });
+
+    let result = check(data, [("email", "unique:users,email,ada,username")]).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn rows_without_the_except_column_are_counted() {
+    let users = MemoryVerifier::new().with_rows(
+        "users",
+        [
+            json!({ "email": "ada@example.com" }),
+            json!({ "email": "bob@example.com", "username": null }),
+        ],
+    );
+
+    for email in ["ada@example.com", "bob@example.com"] {
+        let mut v = Validator::make(
+            make(json!({ "email": email })),
+            [("email", "unique:users,email,ada,username")].into(),
+        );
+        v.set_presence_verifier(Arc::new(users.clone()));
+
+        assert!(v.validate_async().await.unwrap().is_err(), "{email}");
+    }
+}
+
+#[tokio::test]
+async fn null_except_is_ignored() {
+    let data = json!({ "email": "ada@example.com" });
+
+    let result = check(data, [("email", "unique:users,email,NULL")]).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn custom_message_is_used() {
+    let mut v = validator(json!({ "email": "ada@example.com" }), [("email", "unique:users")]);
+    v.set_custom_messages([("email.unique", ":attribute :input is in use.")].into());
+
+    let errors = v.validate_async().await.unwrap().unwrap_err();
+
+    assert_eq!(errors["email"], ["email ada@example.com is in use."]);
+}
+
+#[tokio::test]
+async fn rows_added_later_are_seen() {
+    let users = users();
+    let mut v = Validator::make(
+        make(json!({ "email": "alan@example.com" })),
+        [("email", "unique:users")].into(),
+    );
+    v.set_presence_verifier(Arc::new(users.clone()));
+    assert!(v.validate_async().await.unwrap().is_ok());
+
+    users.insert("users", json!({ "id": 2, "email": "alan@example.com" }));
+
+    assert!(v.validate_async().await.unwrap().is_err());
+}
+
+#[test]
+fn sync_validation_skips_it() {
+    let mut v = validator(json!({ "email": "ada@example.com" }), [("email", "unique:users")]);
+
+    assert!(v.validate().is_ok());
+}
+
+fn users() -> MemoryVerifier {
+    MemoryVerifier::new()
+        .with_rows("users", [json!({ "id": 1, "email": "ada@example.com", "username": "ada" })])
+}
+
+fn validator<const N: usize>(data: Value, rules: [(&'static str, &'static str); N]) -> Validator {
+    let mut v = Validator::make(make(data), HashMap::from(rules));
+    v.set_presence_verifier(Arc::new(users()));
+    v
+}
+
+async fn check<const N: usize>(
+    data: Value,
+    rules: [(&'static str, &'static str); N],
+) -> Result<(), ValidationErrors> {
+    validator(data, rules).validate_async().await.unwrap()
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
-use ketzal_validation::Validator;
+use ketzal_validation::{ValidationErrors, Validator};
 use serde_json::json;
 
 use crate::helpers::make;
@@ -48,9 +48,7 @@ fn validator_clone_has_independent_errors() {
 
 #[test]
 fn concurrent_validation_same_rules() {
-    #[allow(clippy::type_complexity)]
-    let results: Arc<Mutex<Vec<Result<(), HashMap<String, Vec<String>>>>>> =
-        Arc::new(Mutex::new(Vec::new()));
+    let results: Arc<Mutex<Vec<Result<(), ValidationErrors>>>> = Arc::new(Mutex::new(Vec::new()));
 
     let mut handles = vec![];
 
@@ -5,8 +5,7 @@ use crate::helpers::make;
 
 /// Tests demonstrating custom rules usage patterns
 
-// Note: The current implementation uses a static registry with OnceLock.
-// Custom rules would need to be added at compile time or through internal APIs.
+// Rules registered at runtime are covered in `runtime_rules`.
 // These tests demonstrate how the validation works with the built-in rules.
 
 #[test]
@@ -122,8 +121,7 @@ fn payment_form_validation() {
 }
 
 #[test]
-fn nested_object_validation_not_supported() {
-    // Current implementation doesn't support nested objects
+fn nested_object_validation() {
     let data = make(json!({
         "user": {
             "name": "John",
@@ -131,16 +129,15 @@ fn nested_object_validation_not_supported() {
         }
     }));
 
-    let mut v = Validator::make(data, [("user.name", "required")].into());
+    let mut v = Validator::make(data, [("user.name", "required"), ("user.email", "email")].into());
 
-    // This will fail because user.name is not found as a top-level key
-    // and empty string is considered present (not null)
-    assert!(v.validate().is_err());
+    // Dot keys reach into nested objects
+    assert!(v.validate().is_ok());
 }
 
 #[test]
 fn optional_fields_with_nullable() {
-    // Nullable only skips when field is MISSING
+    // Nullable skips the other rules of a null field
     let data = make(json!({
         "required_field": "value",
         "optional_field": null,
@@ -157,8 +154,7 @@ fn optional_fields_with_nullable() {
         .into(),
     );
 
-    // Null values fail their respective rules
-    assert!(v.validate().is_err());
+    assert!(v.validate().is_ok());
 }
 
 #[test]
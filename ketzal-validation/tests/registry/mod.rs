@@ -1,3 +1,4 @@
 mod concurrency;
 mod custom_rules;
 mod default_rules;
+mod runtime_rules;
@@ -0,0 +1,151 @@
+use std::thread;
+
+use ketzal_validation::{register_rule, RuleFn, ValidationRule, Validator};
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for rules registered at runtime
+
+#[test]
+fn global_closure_rule() {
+    register_rule("all_caps", all_caps());
+
+    let mut ok = Validator::make(make(json!({ "code": "ABC" })), [("code", "all_caps")].into());
+    let mut bad = Validator::make(make(json!({ "code": "abc" })), [("code", "all_caps")].into());
+
+    assert!(ok.validate().is_ok());
+    assert_eq!(bad.validate().unwrap_err()["code"], ["The field code must be uppercase."]);
+}
+
+#[test]
+fn global_rule_object_with_params() {
+    register_rule("divisible_by", DivisibleBy);
+
+    let data = make(json!({ "a": 9, "b": 10 }));
+    let mut v = Validator::make(data, [("a", "divisible_by:3"), ("b", "divisible_by:3")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert!(!errors.contains_key("a"));
+    assert_eq!(errors["b"], ["The field b must be divisible by 3."]);
+}
+
+#[test]
+fn custom_message_and_attribute_apply() {
+    register_rule("all_caps", all_caps());
+
+    let mut v = Validator::make(make(json!({ "code": "abc" })), [("code", "all_caps")].into());
+    v.set_custom_attributes([("code", "promo code")].into());
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["code"], ["The field promo code must be uppercase."]);
+
+    let mut v = Validator::make(make(json!({ "code": "abc" })), [("code", "all_caps")].into());
+    v.set_custom_messages([("code.all_caps", "Shout it.")].into());
+    assert_eq!(v.validate().unwrap_err()["code"], ["Shout it."]);
+}
+
+#[test]
+fn local_rule_only_applies_to_its_validator() {
+    let data = make(json!({ "n": 3 }));
+
+    let mut local = Validator::make(data.clone(), [("n", "even_local")].into());
+    local.add_rule("even_local", even());
+    let mut other = Validator::make(data, [("n", "even_local")].into());
+
+    assert_eq!(local.validate().unwrap_err()["n"], ["The field n must be even."]);
+    assert!(other.validate().is_ok());
+}
+
+#[test]
+fn local_rule_overrides_builtin() {
+    let mut v =
+        Validator::make(make(json!({ "email": "not-an-email" })), [("email", "email")].into());
+    v.add_rule("email", RuleFn::new("unused", |_, _, _, _| true));
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn inline_rule_object_runs_after_rule_string() {
+    let mut v = Validator::make(make(json!({ "n": 3, "m": 5 })), [("n", "min:4")].into());
+    v.rule("n", even());
+    v.rule("m", even());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["n"], ["The field n must be at least 4.", "The field n must be even."]);
+    assert_eq!(errors["m"], ["The field m must be even."]);
+}
+
+#[test]
+fn inline_rule_fields_are_validated_data() {
+    let mut v = Validator::make(make(json!({ "n": 4, "other": 1 })), [].into());
+    v.rule("n", even());
+
+    assert!(v.validate().is_ok());
+    assert_eq!(v.validated_data(), make(json!({ "n": 4 })));
+}
+
+#[test]
+fn strict_mode_knows_global_rules() {
+    register_rule("strict_known", even());
+
+    let known = Validator::make_strict(make(json!({})), [("n", "strict_known:1")].into());
+    let unknown = Validator::make_strict(make(json!({})), [("n", "strict_unknown")].into());
+
+    assert!(known.is_ok());
+    assert!(unknown.is_err());
+}
+
+#[test]
+fn registration_while_validating_on_other_threads() {
+    const NAMES: [&str; 4] = ["threaded_0", "threaded_1", "threaded_2", "threaded_3"];
+    register_rule("threaded", even());
+
+    let handles: Vec<_> = NAMES
+        .into_iter()
+        .map(|name| {
+            thread::spawn(move || {
+                for _ in 0..50 {
+                    let mut v =
+                        Validator::make(make(json!({ "n": 2 })), [("n", "threaded")].into());
+                    assert!(v.validate().is_ok());
+                }
+                register_rule(name, even());
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    for name in NAMES {
+        let mut v = Validator::make(make(json!({ "n": 1 })), [("n", name)].into());
+        assert!(v.validate().is_err(), "`{name}` was lost");
+    }
+}
+
+fn all_caps() -> impl ValidationRule {
+    RuleFn::new("The field :attribute must be uppercase.", |_, value, _, _| {
+        value.and_then(Value::as_str).is_some_and(|s| s == s.to_uppercase())
+    })
+}
+
+fn even() -> impl ValidationRule {
+    RuleFn::new("The field :attribute must be even.", |_, value, _, _| {
+        value.and_then(Value::as_u64).is_some_and(|n| n % 2 == 0)
+    })
+}
+
+struct DivisibleBy;
+
+impl ValidationRule for DivisibleBy {
+    fn passes(&self, _: &str, value: Option<&Value>, params: &[&str], _: &Validator) -> bool {
+        let by: u64 = params.first().and_then(|p| p.parse().ok()).unwrap_or(1);
+        value.and_then(Value::as_u64).is_some_and(|n| n % by == 0)
+    }
+
+    fn message(&self, field_name: &str, params: &[&str]) -> String {
+        format!("The field {field_name} must be divisible by {}.", params.join(", "))
+    }
+}
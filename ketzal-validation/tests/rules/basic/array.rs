@@ -0,0 +1,72 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for the array, list and distinct rules
+
+#[test]
+fn array_accepts_lists_and_objects() {
+    let data = make(json!({ "tags": ["a"], "meta": { "k": 1 } }));
+    let mut v = Validator::make(data, [("tags", "array"), ("meta", "array")].into());
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn array_rejects_scalars() {
+    let data = make(json!({ "tags": "a,b" }));
+    let mut v = Validator::make(data, [("tags", "array")].into());
+
+    assert_eq!(v.validate().unwrap_err()["tags"], ["The field tags must be an array."]);
+}
+
+#[test]
+fn min_on_array_counts_items() {
+    let data = make(json!({ "items": [] }));
+    let mut v = Validator::make(data, [("items", "required|array|min:1")].into());
+
+    assert_eq!(v.validate().unwrap_err()["items"], ["The field items must have at least 1 items."]);
+}
+
+#[test]
+fn max_on_array_counts_items() {
+    let data = make(json!({ "items": [1, 2, 3] }));
+    let mut v = Validator::make(data, [("items", "array|max:2")].into());
+
+    assert_eq!(
+        v.validate().unwrap_err()["items"],
+        ["The field items may not have more than 2 items."]
+    );
+}
+
+#[test]
+fn distinct_on_array_elements() {
+    let ok = make(json!({ "tags": ["a", "b"] }));
+    let dup = make(json!({ "tags": ["a", "b", "a"] }));
+
+    assert!(Validator::make(ok, [("tags", "array|distinct")].into()).validate().is_ok());
+    assert!(Validator::make(dup, [("tags", "array|distinct")].into()).validate().is_err());
+}
+
+#[test]
+fn distinct_on_wildcard_marks_each_duplicate() {
+    let data = make(json!({ "items": [{ "sku": "A" }, { "sku": "B" }, { "sku": "A" }] }));
+    let mut v = Validator::make(data, [("items.*.sku", "distinct")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["items.0.sku"], ["The field items.0.sku has a duplicate value."]);
+    assert_eq!(errors["items.2.sku"], ["The field items.2.sku has a duplicate value."]);
+    assert!(!errors.contains_key("items.1.sku"));
+}
+
+#[test]
+fn list_rejects_objects() {
+    let data = make(json!({ "tags": ["a"], "meta": { "k": 1 }, "name": "a" }));
+    let rules = [("tags", "list"), ("meta", "list"), ("name", "list")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors["meta"], ["The field meta must be a list."]);
+}
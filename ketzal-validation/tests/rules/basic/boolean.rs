@@ -0,0 +1,28 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for the boolean rule
+
+#[test]
+fn boolean_accepts_booleans_and_their_form_text() {
+    for value in [json!(true), json!(false), json!(1), json!(0), json!("1"), json!("false")] {
+        let mut v =
+            Validator::make(make(json!({ "active": value })), [("active", "boolean")].into());
+        assert!(v.validate().is_ok(), "{value}");
+    }
+}
+
+#[test]
+fn boolean_rejects_other_values() {
+    for value in [json!("yes"), json!(2), json!("TRUE"), json!(null)] {
+        let mut v =
+            Validator::make(make(json!({ "active": value })), [("active", "boolean")].into());
+        assert_eq!(
+            v.validate().unwrap_err()["active"],
+            ["The field active must be true or false."],
+            "{value}"
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use ketzal_validation::Validator;
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the decimal rule
+
+#[test]
+fn decimal_requires_exact_places() {
+    assert!(check(json!("19.99"), "decimal:2").is_ok());
+    assert!(check(json!(19.99), "decimal:2").is_ok());
+    assert_eq!(
+        check(json!("19.9"), "decimal:2").unwrap_err(),
+        ["The field price must have 2 decimal places."]
+    );
+}
+
+#[test]
+fn decimal_accepts_a_range_of_places() {
+    for value in ["1.50", "1.505", "-1.5050"] {
+        assert!(check(json!(value), "decimal:2,4").is_ok(), "{value}");
+    }
+    assert_eq!(
+        check(json!("1.5"), "decimal:2,4").unwrap_err(),
+        ["The field price must have 2-4 decimal places."]
+    );
+}
+
+#[test]
+fn decimal_rejects_non_numbers() {
+    for value in [json!("1,50"), json!("abc"), json!("."), json!(true)] {
+        assert!(check(value.clone(), "decimal:0,2").is_err(), "{value}");
+    }
+}
+
+#[test]
+fn invalid_places_are_rejected_in_strict_mode() {
+    let data = make(json!({ "price": "1.00" }));
+
+    let err = Validator::make_strict(data, [("price", "decimal:4,2")].into()).err().unwrap();
+
+    assert_eq!(err.reason, "rule `decimal` has a minimum above its maximum");
+}
+
+fn check(value: Value, rules: &'static str) -> Result<(), Vec<String>> {
+    let data = make(json!({ "price": value }));
+
+    Validator::make(data, [("price", rules)].into())
+        .validate()
+        .map_err(|mut e| e.remove("price").unwrap_or_default())
+}
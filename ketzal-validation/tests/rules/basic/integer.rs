@@ -0,0 +1,41 @@
+use ketzal_validation::Validator;
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the integer rule
+
+#[test]
+fn integer_accepts_whole_numbers_and_their_text() {
+    for value in [json!(42), json!(-7), json!(u64::MAX), json!("42"), json!("-7"), json!("+3")] {
+        assert!(check(value.clone(), "integer").is_ok(), "{value}");
+    }
+}
+
+#[test]
+fn integer_rejects_fractions_and_text() {
+    for value in [json!(1.5), json!("1.0"), json!("1e3"), json!(" 4"), json!(true)] {
+        assert_eq!(
+            check(value.clone(), "integer").unwrap_err(),
+            ["The field qty must be an integer."],
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn integer_text_is_sized_as_a_number() {
+    assert!(check(json!("12"), "integer|min:10").is_ok());
+    assert_eq!(
+        check(json!("9"), "integer|min:10").unwrap_err(),
+        ["The field qty must be at least 10."]
+    );
+}
+
+fn check(value: Value, rules: &'static str) -> Result<(), Vec<String>> {
+    let data = make(json!({ "qty": value }));
+
+    Validator::make(data, [("qty", rules)].into())
+        .validate()
+        .map_err(|mut e| e.remove("qty").unwrap_or_default())
+}
@@ -136,9 +136,13 @@ fn min_null_field() {
 #[test]
 fn min_empty_string() {
     let data = make(json!({ "name": "" }));
-    let mut v = Validator::make(data, [("name", "min:1")].into());
+    let mut v = Validator::make(data.clone(), [("name", "min:1")].into());
 
-    assert!(v.validate().is_err());
+    // A blank value is only checked by implicit rules such as `required`.
+    assert!(v.validate().is_ok());
+
+    let mut v = Validator::make(data, [("name", "required|min:1")].into());
+    assert_eq!(v.validate().unwrap_err()["name"], ["The field name is required."]);
 }
 
 #[test]
@@ -1,7 +1,13 @@
+pub mod array;
+pub mod boolean;
 pub mod confirmed;
+pub mod decimal;
 pub mod email;
+pub mod integer;
 pub mod max;
 pub mod min;
+pub mod multiple_of;
 pub mod numeric;
 pub mod required;
+pub mod size;
 pub mod string;
@@ -0,0 +1,37 @@
+use ketzal_validation::Validator;
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the multiple_of rule
+
+#[test]
+fn multiple_of_whole_steps() {
+    assert!(check(json!(15), "multiple_of:5").is_ok());
+    assert!(check(json!("-10"), "multiple_of:5").is_ok());
+    assert_eq!(
+        check(json!(12), "multiple_of:5").unwrap_err(),
+        ["The field amount must be a multiple of 5."]
+    );
+}
+
+#[test]
+fn multiple_of_decimal_steps() {
+    assert!(check(json!(0.75), "multiple_of:0.25").is_ok());
+    assert!(check(json!(0.3), "multiple_of:0.1").is_ok());
+    assert!(check(json!(0.8), "multiple_of:0.25").is_err());
+}
+
+#[test]
+fn multiple_of_zero_or_text_fails() {
+    assert!(check(json!(0), "multiple_of:0").is_err());
+    assert!(check(json!("ten"), "multiple_of:5").is_err());
+}
+
+fn check(value: Value, rules: &'static str) -> Result<(), Vec<String>> {
+    let data = make(json!({ "amount": value }));
+
+    Validator::make(data, [("amount", rules)].into())
+        .validate()
+        .map_err(|mut e| e.remove("amount").unwrap_or_default())
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use ketzal_validation::{FileMeta, Validator};
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for type-aware sizes in min, max, between and size
+
+#[test]
+fn strings_count_characters_not_bytes() {
+    let data = make(json!({ "name": "ñandú" }));
+
+    assert!(Validator::make(data.clone(), [("name", "max:5")].into()).validate().is_ok());
+    assert!(Validator::make(data, [("name", "size:5")].into()).validate().is_ok());
+}
+
+#[test]
+fn numeric_strings_compare_by_value_with_numeric_rule() {
+    let data = make(json!({ "age": "20" }));
+
+    assert!(Validator::make(data.clone(), [("age", "numeric|min:18")].into()).validate().is_ok());
+    assert!(Validator::make(data, [("age", "min:18")].into()).validate().is_err());
+}
+
+#[test]
+fn numbers_keep_decimals_and_sign() {
+    let data = make(json!({ "price": 0.5, "delta": -2.5 }));
+    let mut v = Validator::make(data, [("price", "min:0.01|max:0.75"), ("delta", "min:-3")].into());
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn decimals_are_not_truncated() {
+    let data = make(json!({ "price": 0.5 }));
+    let mut v = Validator::make(data, [("price", "min:1")].into());
+
+    assert_eq!(v.validate().unwrap_err()["price"], ["The field price must be at least 1."]);
+}
+
+#[test]
+fn files_are_measured_in_kilobytes() {
+    let meta = FileMeta::new("a.png", "image/png", 3 * 1024);
+    let data = make(json!({ "avatar": meta.to_value() }));
+    let files = HashMap::from([("avatar".to_string(), meta)]);
+
+    let mut ok = Validator::make(data.clone(), [("avatar", "file|max:3")].into());
+    ok.set_files(files.clone());
+    let mut big = Validator::make(data, [("avatar", "file|max:2")].into());
+    big.set_files(files);
+
+    assert!(ok.validate().is_ok());
+    assert_eq!(
+        big.validate().unwrap_err()["avatar"],
+        ["The field avatar may not be greater than 2 kilobytes."]
+    );
+}
+
+#[test]
+fn messages_are_phrased_for_each_type() {
+    let data = make(json!({
+        "name": "ab",
+        "age": 15,
+        "tags": ["a"]
+    }));
+    let rules = [("name", "min:3"), ("age", "max:10"), ("tags", "size:2")];
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors["name"], ["The field name must be at least 3 characters."]);
+    assert_eq!(errors["age"], ["The field age may not be greater than 10."]);
+    assert_eq!(errors["tags"], ["The field tags must contain 2 items."]);
+}
+
+#[test]
+fn between_is_inclusive() {
+    let data = make(json!({ "a": 1, "b": 10, "c": 11, "name": "abc" }));
+    let rules = [("a", "between:1,10"), ("b", "between:1,10"), ("c", "between:1,10")];
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["c"], ["The field c must be between 1 and 10."]);
+}
+
+#[test]
+fn between_on_strings_and_arrays() {
+    let data = make(json!({ "name": "a", "tags": [] }));
+    let errors =
+        Validator::make(data, [("name", "between:2,4"), ("tags", "array|between:1,3")].into())
+            .validate()
+            .unwrap_err();
+
+    assert_eq!(errors["name"], ["The field name must be between 2 and 4 characters."]);
+    assert_eq!(errors["tags"], ["The field tags must have between 1 and 3 items."]);
+}
+
+#[test]
+fn size_on_numbers_and_numeric_strings() {
+    let data = make(json!({ "n": 5, "s": "5", "pin": "12345" }));
+    let rules = [("n", "size:5"), ("s", "numeric|size:5"), ("pin", "size:4")];
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["pin"], ["The field pin must be 4 characters."]);
+}
+
+#[test]
+fn size_applies_to_wildcard_fields() {
+    let data = make(json!({ "items": [{ "qty": "3" }, { "qty": "30" }] }));
+    let mut v = Validator::make(data, [("items.*.qty", "numeric|between:1,10")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["items.1.qty"], ["The field items.1.qty must be between 1 and 10."]);
+}
+
+#[test]
+fn strict_mode_checks_size_params() {
+    let ok = [("a", "min:-1.5|max:2|size:0.5|between:1,10")];
+    let inverted = Validator::make_strict(make(json!({})), [("a", "between:10,1")].into());
+    let single = Validator::make_strict(make(json!({})), [("a", "between:1")].into());
+
+    assert!(Validator::make_strict(make(json!({})), ok.into()).is_ok());
+    assert_eq!(inverted.err().unwrap().reason, "rule `between` has a minimum above its maximum");
+    assert_eq!(single.err().unwrap().reason, "rule `between` expects two numbers, got `1`");
+}
@@ -0,0 +1,118 @@
+use chrono::{Duration, Utc};
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for before, before_or_equal, after, after_or_equal and date_equals
+
+#[test]
+fn after_another_field() {
+    let ok = make(json!({ "start_date": "2024-05-01", "end_date": "2024-05-02" }));
+    let same = make(json!({ "start_date": "2024-05-01", "end_date": "2024-05-01" }));
+    let rules = [("end_date", "after:start_date")];
+
+    assert!(Validator::make(ok, rules.into()).validate().is_ok());
+    assert_eq!(
+        Validator::make(same, rules.into()).validate().unwrap_err()["end_date"],
+        ["The field end_date must be a date after start_date."]
+    );
+}
+
+#[test]
+fn after_or_equal_accepts_the_same_date() {
+    let data = make(json!({ "start_date": "2024-05-01", "end_date": "2024-05-01" }));
+
+    assert!(Validator::make(data, [("end_date", "after_or_equal:start_date")].into())
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn before_a_literal_date() {
+    let data = make(json!({ "born": "1990-01-01T10:00:00Z", "late": "2030-01-01 00:00:00" }));
+    let rules = [("born", "before:2000-01-01"), ("late", "before_or_equal:2029-12-31")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["late"], ["The field late must be a date before or equal to 2029-12-31."]);
+}
+
+#[test]
+fn compares_across_time_zones() {
+    let data = make(json!({
+        "start": "2024-05-01T10:00:00+02:00",
+        "end": "2024-05-01T09:00:00Z"
+    }));
+
+    assert!(Validator::make(data, [("end", "after:start")].into()).validate().is_ok());
+}
+
+#[test]
+fn relative_keywords() {
+    let data = make(json!({ "birthday": "2000-01-01", "appointment": "1999-01-01" }));
+    let rules = [("birthday", "before:today"), ("appointment", "after:tomorrow")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors.contains_key("appointment"));
+}
+
+#[test]
+fn invalid_or_missing_dates_fail() {
+    let data = make(json!({ "a": "not a date", "b": "2024-01-01" }));
+    let rules = [("a", "after:2020-01-01"), ("b", "after:start_date")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+    assert_eq!(errors["a"], ["The field a must be a date after 2020-01-01."]);
+    assert_eq!(errors["b"], ["The field b must be a date after start_date."]);
+}
+
+#[test]
+fn messages_use_attribute_names() {
+    let data = make(json!({ "start_date": "2024-05-02", "end_date": "2024-05-01" }));
+    let mut v = Validator::make(data, [("end_date", "after:start_date")].into());
+    v.set_custom_attributes([("end_date", "check-out"), ("start_date", "check-in")].into());
+
+    assert_eq!(
+        v.validate().unwrap_err()["end_date"],
+        ["The field check-out must be a date after check-in."]
+    );
+}
+
+#[test]
+fn date_equals_compares_instants() {
+    let data =
+        make(json!({ "a": "2024-05-01", "b": "2024-05-01 00:00:00", "c": "2024-05-01 10:00" }));
+    let rules = [("a", "date_equals:b"), ("c", "date_equals:2024-05-01")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["c"], ["The field c must be a date equal to 2024-05-01."]);
+}
+
+#[test]
+fn relative_expressions() {
+    let soon = (Utc::now() + Duration::days(3)).format("%Y-%m-%d").to_string();
+    let later = (Utc::now() + Duration::days(10)).format("%Y-%m-%d").to_string();
+    let data = make(json!({ "soon": soon, "later": later, "past": "2020-01-01" }));
+    let rules =
+        [("soon", "after:+7 days"), ("later", "after:+1 week"), ("past", "before:2 years ago")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["soon"], ["The field soon must be a date after +7 days."]);
+}
+
+#[test]
+fn comparisons_read_the_date_format() {
+    let data = make(json!({ "start": "30/04/2024", "end": "01/05/2024" }));
+    let rules = [
+        ("start", "date_format:d/m/Y|before:01/05/2024"),
+        ("end", "date_format:d/m/Y|after:start"),
+    ];
+
+    assert!(Validator::make(data, rules.into()).validate().is_ok());
+}
@@ -0,0 +1,49 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for the same and different rules
+
+#[test]
+fn same_requires_equal_values() {
+    let ok = make(json!({ "email": "a@b.c", "email_again": "a@b.c" }));
+    let bad = make(json!({ "email": "a@b.c", "email_again": "x@b.c" }));
+    let rules = [("email_again", "same:email")];
+
+    assert!(Validator::make(ok, rules.into()).validate().is_ok());
+    assert_eq!(
+        Validator::make(bad, rules.into()).validate().unwrap_err()["email_again"],
+        ["The field email_again must match email."]
+    );
+}
+
+#[test]
+fn same_compares_types() {
+    let data = make(json!({ "a": 1, "b": "1" }));
+
+    assert!(Validator::make(data, [("b", "same:a")].into()).validate().is_err());
+}
+
+#[test]
+fn different_uses_both_attribute_names() {
+    let data = make(json!({ "password": "secret", "old_password": "secret" }));
+    let mut v = Validator::make(data, [("password", "different:old_password")].into());
+    v.set_custom_attributes(
+        [("password", "new password"), ("old_password", "current password")].into(),
+    );
+
+    assert_eq!(
+        v.validate().unwrap_err()["password"],
+        ["The fields new password and current password must be different."]
+    );
+}
+
+#[test]
+fn different_passes_when_other_is_missing() {
+    let data = make(json!({ "password": "secret" }));
+
+    assert!(Validator::make(data, [("password", "different:old_password")].into())
+        .validate()
+        .is_ok());
+}
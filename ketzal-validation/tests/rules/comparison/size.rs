@@ -0,0 +1,73 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for gt, gte, lt and lte
+
+#[test]
+fn compares_with_literal_numbers() {
+    let data = make(json!({ "a": 5, "b": 5, "c": 5, "d": 5 }));
+    let rules = [("a", "gt:5"), ("b", "gte:5"), ("c", "lt:5"), ("d", "lte:5")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+    let mut fields: Vec<_> = errors.keys().cloned().collect();
+    fields.sort();
+    assert_eq!(fields, ["a", "c"]);
+    assert_eq!(errors["a"], ["The field a must be greater than 5."]);
+    assert_eq!(errors["c"], ["The field c must be less than 5."]);
+}
+
+#[test]
+fn compares_with_other_fields() {
+    let data = make(json!({ "min_price": 10, "max_price": 5 }));
+    let mut v = Validator::make(data, [("max_price", "gt:min_price")].into());
+    v.set_custom_attributes(
+        [("max_price", "maximum price"), ("min_price", "minimum price")].into(),
+    );
+
+    assert_eq!(
+        v.validate().unwrap_err()["max_price"],
+        ["The field maximum price must be greater than minimum price."]
+    );
+}
+
+#[test]
+fn fields_of_different_types_do_not_compare() {
+    let data = make(json!({ "a": "hello", "b": 3 }));
+
+    assert!(Validator::make(data, [("a", "gt:b")].into()).validate().is_err());
+}
+
+#[test]
+fn strings_and_arrays_compare_by_size() {
+    let data = make(json!({ "title": "abc", "subtitle": "abcdef", "tags": ["a", "b"] }));
+    let rules = [("subtitle", "gt:title"), ("tags", "lte:1")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+    assert!(!errors.contains_key("subtitle"));
+    assert_eq!(errors["tags"], ["The field tags must have less than or equal to 1 items."]);
+}
+
+#[test]
+fn numeric_strings_compare_by_value_with_numeric_rule() {
+    let data = make(json!({ "min": "9", "max": "10" }));
+    let rules = [("min", "numeric"), ("max", "numeric|gt:min")];
+
+    assert!(Validator::make(data, rules.into()).validate().is_ok());
+}
+
+#[test]
+fn wildcard_fields_compare_within_the_same_element() {
+    let data = make(json!({
+        "ranges": [{ "from": 1, "to": 5 }, { "from": 8, "to": 2 }]
+    }));
+    let mut v = Validator::make(data, [("ranges.*.to", "gte:ranges.*.from")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors["ranges.1.to"],
+        ["The field ranges.1.to must be greater than or equal to ranges.1.from."]
+    );
+}
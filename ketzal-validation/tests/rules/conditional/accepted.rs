@@ -0,0 +1,67 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for accepted and accepted_if
+
+#[test]
+fn accepted_values() {
+    for value in [json!(true), json!(1), json!("yes"), json!("on"), json!("1"), json!("true")] {
+        let mut v =
+            Validator::make(make(json!({ "terms": value })), [("terms", "accepted")].into());
+        assert!(v.validate().is_ok(), "{value} should be accepted");
+    }
+
+    for value in [json!(false), json!(0), json!("no"), json!("")] {
+        let mut v =
+            Validator::make(make(json!({ "terms": value })), [("terms", "accepted")].into());
+        assert!(v.validate().is_err(), "{value} should not be accepted");
+    }
+}
+
+#[test]
+fn accepted_fails_when_missing() {
+    let mut v = Validator::make(make(json!({})), [("terms", "accepted")].into());
+
+    assert_eq!(v.validate().unwrap_err()["terms"], ["The field terms must be accepted."]);
+}
+
+#[test]
+fn accepted_if() {
+    let rules = [("newsletter_terms", "accepted_if:newsletter,yes")];
+
+    let subscribed = make(json!({ "newsletter": "yes" }));
+    let not_subscribed = make(json!({ "newsletter": "no" }));
+
+    assert_eq!(
+        Validator::make(subscribed, rules.into()).validate().unwrap_err()["newsletter_terms"],
+        ["The field newsletter_terms must be accepted when newsletter is yes."]
+    );
+    assert!(Validator::make(not_subscribed, rules.into()).validate().is_ok());
+}
+
+#[test]
+fn declined_accepts_negative_answers() {
+    for value in [json!(false), json!(0), json!("no"), json!("off"), json!("0"), json!("false")] {
+        let mut v = Validator::make(make(json!({ "ads": value })), [("ads", "declined")].into());
+        assert!(v.validate().is_ok(), "{value} should be declined");
+    }
+
+    let mut v = Validator::make(make(json!({ "ads": "yes" })), [("ads", "declined")].into());
+    assert_eq!(v.validate().unwrap_err()["ads"], ["The field ads must be declined."]);
+}
+
+#[test]
+fn declined_if() {
+    let rules = [("tracking", "declined_if:age,16")];
+
+    let minor = make(json!({ "age": 16, "tracking": true }));
+    let adult = make(json!({ "age": 30, "tracking": true }));
+
+    assert_eq!(
+        Validator::make(minor, rules.into()).validate().unwrap_err()["tracking"],
+        ["The field tracking must be declined when age is 16."]
+    );
+    assert!(Validator::make(adult, rules.into()).validate().is_ok());
+}
@@ -0,0 +1,53 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for exclude_if and exclude_unless
+
+#[test]
+fn exclude_if_skips_rules_and_drops_the_field() {
+    let data = make(json!({ "has_company": "no", "company": "", "name": "Ada" }));
+    let rules = [
+        ("has_company", "required|in:yes,no"),
+        ("company", "exclude_if:has_company,no|required|min:2"),
+        ("name", "required"),
+    ];
+    let mut v = Validator::make(data, rules.into());
+
+    assert!(v.validate().is_ok());
+    assert_eq!(v.validated_data(), make(json!({ "has_company": "no", "name": "Ada" })));
+}
+
+#[test]
+fn exclude_if_keeps_the_field_when_not_matched() {
+    let data = make(json!({ "has_company": "yes", "company": "" }));
+    let mut v = Validator::make(data, [("company", "exclude_if:has_company,no|required")].into());
+
+    assert!(v.validate().unwrap_err().contains_key("company"));
+}
+
+#[test]
+fn exclude_unless() {
+    let rules = [("vat_id", "exclude_unless:type,business|required")];
+
+    let person = make(json!({ "type": "person", "vat_id": "X1" }));
+    let business = make(json!({ "type": "business" }));
+
+    let mut v = Validator::make(person, rules.into());
+    assert!(v.validate().is_ok());
+    assert!(v.validated_data().is_empty());
+    assert!(Validator::make(business, rules.into()).validate().is_err());
+}
+
+#[test]
+fn exclude_if_on_wildcard_fields() {
+    let data = make(json!({
+        "items": [{ "kind": "digital", "weight": "" }, { "kind": "physical", "weight": 2 }]
+    }));
+    let rules = [("items.*.weight", "exclude_if:items.*.kind,digital|required|numeric")];
+    let mut v = Validator::make(data, rules.into());
+
+    assert!(v.validate().is_ok());
+    assert_eq!(v.validated_data(), make(json!({ "items": [null, { "weight": 2 }] })));
+}
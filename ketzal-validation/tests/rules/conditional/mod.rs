@@ -1 +1,6 @@
+pub mod accepted;
+pub mod exclude;
+pub mod presence;
+pub mod prohibited;
 pub mod required_if;
+pub mod required_with;
@@ -0,0 +1,36 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for present, filled and sometimes
+
+#[test]
+fn present_allows_empty_values() {
+    let data = make(json!({ "a": "", "b": null }));
+    let mut v =
+        Validator::make(data, [("a", "present"), ("b", "present"), ("c", "present")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["c"], ["The field c must be present."]);
+}
+
+#[test]
+fn filled_allows_missing_fields() {
+    let data = make(json!({ "a": "", "b": "x" }));
+    let mut v = Validator::make(data, [("a", "filled"), ("b", "filled"), ("c", "filled")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["a"], ["The field a must have a value."]);
+}
+
+#[test]
+fn sometimes_skips_missing_fields() {
+    let rules = [("nickname", "sometimes|required|min:3")];
+
+    assert!(Validator::make(make(json!({})), rules.into()).validate().is_ok());
+    assert!(Validator::make(make(json!({ "nickname": "" })), rules.into()).validate().is_err());
+    assert!(Validator::make(make(json!({ "nickname": "al" })), rules.into()).validate().is_err());
+}
@@ -0,0 +1,44 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for prohibited, prohibited_if and prohibited_unless
+
+#[test]
+fn prohibited_allows_missing_or_empty() {
+    let data = make(json!({ "a": "", "b": "x" }));
+    let rules = [("a", "prohibited"), ("b", "prohibited"), ("c", "prohibited")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["b"], ["The field b is prohibited."]);
+}
+
+#[test]
+fn prohibited_if() {
+    let rules = [("discount", "prohibited_if:plan,free,trial")];
+
+    let free = make(json!({ "plan": "free", "discount": 10 }));
+    let pro = make(json!({ "plan": "pro", "discount": 10 }));
+
+    assert_eq!(
+        Validator::make(free, rules.into()).validate().unwrap_err()["discount"],
+        ["The field discount is prohibited when plan is free."]
+    );
+    assert!(Validator::make(pro, rules.into()).validate().is_ok());
+}
+
+#[test]
+fn prohibited_unless() {
+    let rules = [("vat_id", "prohibited_unless:type,business")];
+
+    let person = make(json!({ "type": "person", "vat_id": "X1" }));
+    let business = make(json!({ "type": "business", "vat_id": "X1" }));
+
+    assert_eq!(
+        Validator::make(person, rules.into()).validate().unwrap_err()["vat_id"],
+        ["The field vat_id is prohibited unless type is in business."]
+    );
+    assert!(Validator::make(business, rules.into()).validate().is_ok());
+}
@@ -211,3 +211,33 @@ fn required_if_only_confirmation_field() {
 
     assert!(v.validate().is_err());
 }
+
+#[test]
+fn required_if_accepts_multiple_values() {
+    let rules = [("company", "required_if:type,business,nonprofit")];
+
+    let business = make(json!({ "type": "business" }));
+    let nonprofit = make(json!({ "type": "Nonprofit" }));
+    let person = make(json!({ "type": "person" }));
+
+    let errors = Validator::make(nonprofit, rules.into()).validate().unwrap_err();
+    assert_eq!(errors["company"], ["The field company is required when type is nonprofit."]);
+    assert!(Validator::make(business, rules.into()).validate().is_err());
+    assert!(Validator::make(person, rules.into()).validate().is_ok());
+}
+
+#[test]
+fn required_unless() {
+    let rules = [("email", "required_unless:contact,phone,none")];
+
+    let phone = make(json!({ "contact": "phone" }));
+    let mail = make(json!({ "contact": "mail" }));
+    let missing = make(json!({}));
+
+    assert!(Validator::make(phone, rules.into()).validate().is_ok());
+    assert_eq!(
+        Validator::make(mail, rules.into()).validate().unwrap_err()["email"],
+        ["The field email is required unless contact is in phone, none."]
+    );
+    assert!(Validator::make(missing, rules.into()).validate().is_err());
+}
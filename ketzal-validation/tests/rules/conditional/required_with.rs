@@ -0,0 +1,85 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for required_with, required_with_all, required_without and required_without_all
+
+#[test]
+fn required_with_any_filled_field() {
+    let rules = [("zip", "required_with:street,city")];
+
+    let street = make(json!({ "street": "Main St" }));
+    let empty = make(json!({ "street": "", "city": null }));
+
+    let errors = Validator::make(street, rules.into()).validate().unwrap_err();
+    assert_eq!(errors["zip"], ["The field zip is required when street / city is present."]);
+    assert!(Validator::make(empty, rules.into()).validate().is_ok());
+}
+
+#[test]
+fn required_with_all_needs_every_field() {
+    let rules = [("zip", "required_with_all:street,city")];
+
+    let one = make(json!({ "street": "Main St" }));
+    let both = make(json!({ "street": "Main St", "city": "Springfield" }));
+
+    assert!(Validator::make(one, rules.into()).validate().is_ok());
+    assert_eq!(
+        Validator::make(both, rules.into()).validate().unwrap_err()["zip"],
+        ["The field zip is required when street / city are present."]
+    );
+}
+
+#[test]
+fn required_without_any_missing_field() {
+    let rules = [("email", "required_without:phone")];
+
+    let phone = make(json!({ "phone": "555-0100" }));
+    let nothing = make(json!({ "email": "" }));
+
+    assert!(Validator::make(phone, rules.into()).validate().is_ok());
+    assert_eq!(
+        Validator::make(nothing, rules.into()).validate().unwrap_err()["email"],
+        ["The field email is required when phone is not present."]
+    );
+}
+
+#[test]
+fn required_without_all_needs_every_field_missing() {
+    let rules = [("email", "required_without_all:phone,fax")];
+
+    let fax = make(json!({ "fax": "555-0101" }));
+    let nothing = make(json!({}));
+
+    assert!(Validator::make(fax, rules.into()).validate().is_ok());
+    assert_eq!(
+        Validator::make(nothing, rules.into()).validate().unwrap_err()["email"],
+        ["The field email is required when none of phone / fax are present."]
+    );
+}
+
+#[test]
+fn other_fields_use_custom_attributes() {
+    let mut v =
+        Validator::make(make(json!({ "tel": "1" })), [("email", "required_with:tel")].into());
+    v.set_custom_attributes([("tel", "phone number")].into());
+
+    assert_eq!(
+        v.validate().unwrap_err()["email"],
+        ["The field email is required when phone number is present."]
+    );
+}
+
+#[test]
+fn required_with_inside_wildcards() {
+    let data = make(json!({
+        "contacts": [{ "name": "Ada", "email": "ada@example.com" }, { "name": "Bob" }, {}]
+    }));
+    let mut v =
+        Validator::make(data, [("contacts.*.email", "required_with:contacts.*.name")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors.contains_key("contacts.1.email"));
+}
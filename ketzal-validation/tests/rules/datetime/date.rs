@@ -0,0 +1,85 @@
+use ketzal_validation::utils::date::strftime;
+use ketzal_validation::Validator;
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the date and date_format rules
+
+#[test]
+fn date_accepts_the_supported_formats() {
+    for value in ["2024-05-01", "2024-05-01 10:30", "2024-05-01T10:30:00+02:00", "tomorrow"] {
+        assert!(check(json!(value), "date").is_ok(), "{value}");
+    }
+}
+
+#[test]
+fn date_rejects_invalid_dates() {
+    for value in [json!("2024-02-30"), json!("01/05/2024"), json!("soon"), json!(20240501)] {
+        assert_eq!(
+            check(value.clone(), "date").unwrap_err(),
+            ["The field due must be a valid date."],
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn date_format_matches_exactly() {
+    assert!(check(json!("01/05/2024"), "date_format:d/m/Y").is_ok());
+    assert!(check(json!("1/5/2024"), "date_format:j/n/Y").is_ok());
+
+    for value in ["1/5/2024", "2024-05-01", "01/05/2024 10:00", "31/02/2024"] {
+        assert_eq!(
+            check(json!(value), "date_format:d/m/Y").unwrap_err(),
+            ["The field due must match the format d/m/Y."],
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn date_format_reads_times_names_and_offsets() {
+    assert!(check(json!("2024-05-01 10:30:15"), "date_format:Y-m-d H:i:s").is_ok());
+    assert!(check(json!("Wed, 01 May 2024"), "date_format:D, d M Y").is_ok());
+    assert!(check(json!("3:05 pm"), "date_format:g:i a").is_ok());
+    assert!(check(json!("2024-05-01T10:00:00+02:00"), "date_format:c").is_ok());
+    assert!(check(json!("Thu, 01 May 2024"), "date_format:D, d M Y").is_err());
+}
+
+#[test]
+fn date_format_escapes_letters() {
+    assert!(check(json!("2024-05-01T10:00"), r"date_format:Y-m-d\TH:i").is_ok());
+}
+
+#[test]
+fn date_uses_the_fields_format() {
+    assert!(check(json!("01/05/2024"), "date|date_format:d/m/Y").is_ok());
+}
+
+#[test]
+fn php_formats_translate_to_strftime() {
+    assert_eq!(strftime("d/m/Y H:i").unwrap(), "%d/%m/%Y %H:%M");
+    assert_eq!(strftime(r"\Y\m 100%").unwrap(), "Ym 100%%");
+    assert_eq!(strftime("Y-m-e").unwrap_err(), "unsupported date format character `e`");
+}
+
+#[test]
+fn invalid_format_is_rejected_in_strict_mode() {
+    let data = make(json!({ "due": "2024-05-01" }));
+
+    let err = Validator::make_strict(data, [("due", "date_format:Y-m-Q")].into()).err().unwrap();
+
+    assert_eq!(
+        err.reason,
+        "rule `date_format` has an invalid format: unsupported date format character `Q`"
+    );
+}
+
+fn check(value: Value, rules: &'static str) -> Result<(), Vec<String>> {
+    let data = make(json!({ "due": value }));
+
+    Validator::make(data, [("due", rules)].into())
+        .validate()
+        .map_err(|mut e| e.remove("due").unwrap_or_default())
+}
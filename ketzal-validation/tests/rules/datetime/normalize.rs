@@ -0,0 +1,59 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for ISO 8601 dates in validated data
+
+#[test]
+fn dates_are_kept_as_sent_by_default() {
+    let data = make(json!({ "due": "01/05/2024" }));
+    let mut v = Validator::make(data, [("due", "date_format:d/m/Y")].into());
+
+    v.validate().unwrap();
+
+    assert_eq!(v.validated_data()["due"], "01/05/2024");
+}
+
+#[test]
+fn normalized_dates_use_iso_8601() {
+    let data = make(json!({
+        "due": "01/05/2024",
+        "starts": "2024-05-01 10:30",
+        "sent": "2024-05-01T10:30:00+02:00",
+        "at": "01/05/2024 10:30",
+        "name": "Ada"
+    }));
+    let rules = [
+        ("due", "date_format:d/m/Y"),
+        ("starts", "date"),
+        ("sent", "date"),
+        ("at", "date_format:d/m/Y H:i"),
+        ("name", "string"),
+    ];
+    let mut v = Validator::make(data, rules.into());
+    v.set_normalize_dates(true);
+
+    v.validate().unwrap();
+    let validated = v.validated_data();
+
+    assert_eq!(validated["due"], "2024-05-01");
+    assert_eq!(validated["starts"], "2024-05-01T10:30:00Z");
+    assert_eq!(validated["sent"], "2024-05-01T08:30:00Z");
+    assert_eq!(validated["at"], "2024-05-01T10:30:00Z");
+    assert_eq!(validated["name"], "Ada");
+}
+
+#[test]
+fn normalizes_nested_dates() {
+    let data = make(json!({ "events": [{ "on": "02/01/2024" }, { "on": "03/01/2024" }] }));
+    let mut v = Validator::make(data, [("events.*.on", "date_format:d/m/Y")].into());
+    v.set_normalize_dates(true);
+
+    v.validate().unwrap();
+
+    assert_eq!(
+        v.validated_data()["events"],
+        json!([{ "on": "2024-01-02" }, { "on": "2024-01-03" }])
+    );
+}
@@ -0,0 +1,25 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for the timezone rule
+
+#[test]
+fn timezone_accepts_iana_names() {
+    let data = make(json!({ "a": "UTC", "b": "America/Mexico_City", "c": "Europe/Madrid" }));
+    let rules = [("a", "timezone"), ("b", "timezone"), ("c", "timezone")];
+
+    assert!(Validator::make(data, rules.into()).validate().is_ok());
+}
+
+#[test]
+fn timezone_rejects_unknown_names() {
+    let data = make(json!({ "a": "Mars/Olympus", "b": "+02:00", "c": 2 }));
+    let rules = [("a", "timezone"), ("b", "timezone"), ("c", "timezone")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors["a"], ["The field a must be a valid timezone."]);
+}
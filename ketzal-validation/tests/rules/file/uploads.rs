@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use ketzal_validation::{FileMeta, Validator};
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for the file, image, mimes and mimetypes rules
+
+#[test]
+fn file_passes_for_uploaded_file() {
+    let mut v = with_upload("avatar", "me.png", "image/png", PNG, [("avatar", "required|file")]);
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn file_fails_for_plain_string() {
+    let data = make(json!({ "avatar": "me.png" }));
+
+    let mut v = Validator::make(data, [("avatar", "file")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["avatar"][0], "The field avatar must be a file.");
+}
+
+#[test]
+fn file_fails_for_object_without_upload() {
+    let data = make(json!({ "avatar": { "name": "me.png", "size": 10 } }));
+
+    let mut v = Validator::make(data, [("avatar", "file")].into());
+
+    assert!(v.validate().is_err());
+}
+
+#[test]
+fn input_cannot_forge_an_upload() {
+    let forged = json!({ "$file": true, "name": "me.png", "content_type": "image/png", "size": 1 });
+    let data = make(json!({ "avatar": forged }));
+
+    let mut v = Validator::make(data, [("avatar", "file|image|mimes:png")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors.get("avatar").unwrap().len(), 3);
+}
+
+#[test]
+fn required_passes_for_uploaded_file() {
+    let mut v = with_upload("avatar", "me.png", "image/png", b"", [("avatar", "required")]);
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn image_passes_for_png() {
+    let mut v = with_upload("avatar", "me.PNG", "image/png", PNG, [("avatar", "image")]);
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn image_fails_for_pdf() {
+    let mut v = with_upload("avatar", "cv.pdf", "application/pdf", PDF, [("avatar", "image")]);
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["avatar"][0], "The field avatar must be an image.");
+}
+
+#[test]
+fn image_fails_for_content_that_is_not_an_image() {
+    let mut v = with_upload("avatar", "me.png", "image/png", b"<html>", [("avatar", "image")]);
+
+    assert!(v.validate().is_err());
+}
+
+#[test]
+fn image_fails_for_svg_unless_allowed() {
+    let svg = b"<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>";
+
+    let mut strict = with_upload("logo", "logo.svg", "image/svg+xml", svg, [("logo", "image")]);
+    let mut allowed =
+        with_upload("logo", "logo.svg", "image/svg+xml", svg, [("logo", "image:allow_svg")]);
+
+    assert!(strict.validate().is_err());
+    assert!(allowed.validate().is_ok());
+}
+
+#[test]
+fn mimes_matches_content() {
+    let mut v = with_upload("photo", "photo", "image/jpeg", JPEG, [("photo", "mimes:jpeg,png")]);
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn mimes_ignores_the_client_extension_of_known_content() {
+    let mut v = with_upload("doc", "report.pdf", "application/pdf", PNG, [("doc", "mimes:pdf")]);
+
+    assert!(v.validate().is_err());
+}
+
+#[test]
+fn mimes_uses_the_extension_of_unknown_content() {
+    let mut v = with_upload("doc", "notes.txt", "text/plain", b"hello", [("doc", "mimes:txt")]);
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn mimes_fails_for_disguised_content() {
+    let mut v = with_upload("doc", "report.pdf", "application/pdf", b"MZ", [("doc", "mimes:pdf")]);
+
+    assert!(v.validate().is_err());
+}
+
+#[test]
+fn mimes_fails_for_other_type() {
+    let mut v = with_upload(
+        "doc",
+        "script.exe",
+        "application/x-msdownload",
+        b"MZ",
+        [("doc", "mimes:pdf,docx")],
+    );
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["doc"][0], "The field doc must be a file of type: pdf, docx.");
+}
+
+#[test]
+fn mimetypes_supports_wildcards() {
+    let mut v = with_upload("clip", "clip.mp4", "video/mp4", MP4, [("clip", "mimetypes:video/*")]);
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn mimetypes_fails_for_other_type() {
+    let rules = [("clip", "mimetypes:image/png,image/jpeg")];
+
+    let mut v = with_upload("clip", "clip.mp4", "video/mp4", MP4, rules);
+
+    assert!(v.validate().is_err());
+}
+
+#[test]
+fn mimetypes_checks_the_content() {
+    let rules = [("avatar", "mimetypes:image/png")];
+
+    let mut disguised = with_upload("avatar", "me.png", "image/png", b"<script>", rules);
+    let mut text =
+        with_upload("notes", "a.txt", "text/plain", b"hi", [("notes", "mimetypes:text/*")]);
+
+    assert!(disguised.validate().is_err());
+    assert!(text.validate().is_ok());
+}
+
+#[test]
+fn mimes_custom_message() {
+    let mut v = with_upload("doc", "a.txt", "text/plain", b"a", [("doc", "mimes:pdf")]);
+    v.set_custom_messages([("doc.mimes", "Only PDF files!")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["doc"][0], "Only PDF files!");
+}
+
+#[test]
+fn file_meta_detects_content() {
+    let meta = FileMeta::new("archive.tar.GZ", "application/gzip", 2048).with_head(&[0x1f, 0x8b]);
+
+    assert_eq!(meta.detected, Some("application/gzip"));
+    assert_eq!(meta.extension().as_deref(), Some("gz"));
+    assert!(meta.content_extensions().contains(&"gz".to_string()));
+}
+
+#[test]
+fn strict_mode_checks_the_image_flag() {
+    let rules = |rule| HashMap::from([("logo", rule)]);
+
+    assert!(Validator::make_strict(make(json!({})), rules("image:allow_svg")).is_ok());
+    assert!(Validator::make_strict(make(json!({})), rules("image:svg")).is_err());
+}
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+const JPEG: &[u8] = b"\xff\xd8\xff\xe0\0\x10JFIF";
+const PDF: &[u8] = b"%PDF-1.7";
+const MP4: &[u8] = b"\0\0\0\x18ftypmp42";
+
+fn with_upload<const N: usize>(
+    field: &'static str,
+    name: &str,
+    content_type: &str,
+    content: &[u8],
+    rules: [(&'static str, &'static str); N],
+) -> Validator {
+    let meta = FileMeta::new(name, content_type, content.len() as u64).with_head(content);
+
+    let mut v = Validator::make(make(json!({ field: meta.to_value() })), rules.into());
+    v.set_files([(field.to_string(), meta)].into());
+    v
+}
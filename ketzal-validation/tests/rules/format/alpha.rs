@@ -0,0 +1,58 @@
+use ketzal_validation::Validator;
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the alpha, alpha_num and alpha_dash rules
+
+#[test]
+fn alpha_accepts_letters_in_any_script() {
+    for name in ["Ada", "José", "Ünal", "Ñandú", "東京", "Αθήνα"] {
+        assert!(check(json!(name), "alpha").is_ok(), "{name}");
+    }
+}
+
+#[test]
+fn alpha_rejects_digits_spaces_and_symbols() {
+    for name in ["Ada1", "Ada Lovelace", "Ada-L"] {
+        assert!(check(json!(name), "alpha").is_err(), "{name}");
+    }
+
+    assert_eq!(
+        check(json!("R2D2"), "alpha").unwrap_err(),
+        ["The field name must only contain letters."]
+    );
+}
+
+#[test]
+fn alpha_num_accepts_numbers() {
+    assert!(check(json!("Año2024"), "alpha_num").is_ok());
+    assert!(check(json!(42), "alpha_num").is_ok());
+    assert_eq!(
+        check(json!("a_b"), "alpha_num").unwrap_err(),
+        ["The field name must only contain letters and numbers."]
+    );
+}
+
+#[test]
+fn alpha_dash_accepts_slugs() {
+    assert!(check(json!("mi-artículo_2"), "alpha_dash").is_ok());
+    assert_eq!(
+        check(json!("mi artículo"), "alpha_dash").unwrap_err(),
+        ["The field name must only contain letters, numbers, dashes and underscores."]
+    );
+}
+
+#[test]
+fn alpha_rejects_non_strings() {
+    assert!(check(json!(true), "alpha").is_err());
+    assert!(check(json!(42), "alpha").is_err());
+}
+
+fn check(value: Value, rules: &'static str) -> Result<(), Vec<String>> {
+    let data = make(json!({ "name": value }));
+
+    Validator::make(data, [("name", rules)].into())
+        .validate()
+        .map_err(|mut e| e.remove("name").unwrap_or_default())
+}
@@ -0,0 +1,75 @@
+use ketzal_validation::Validator;
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the url, uuid, ulid and ip rules
+
+#[test]
+fn url_accepts_absolute_urls() {
+    for url in ["https://example.com", "http://localhost:8080/a/b?c=d#e", "ftp://files.example.org"]
+    {
+        assert!(check(json!(url), "url").is_ok(), "{url}");
+    }
+}
+
+#[test]
+fn url_rejects_relative_and_malformed_urls() {
+    for url in ["example.com", "/path", "https://", "http://exa mple.com"] {
+        assert!(check(json!(url), "url").is_err(), "{url}");
+    }
+
+    assert_eq!(check(json!("nope"), "url").unwrap_err(), ["The field site must be a valid URL."]);
+}
+
+#[test]
+fn uuid_accepts_any_case() {
+    assert!(check(json!("123e4567-e89b-12d3-a456-426614174000"), "uuid").is_ok());
+    assert!(check(json!("123E4567-E89B-12D3-A456-426614174000"), "uuid").is_ok());
+    assert_eq!(
+        check(json!("123e4567e89b12d3a456426614174000"), "uuid").unwrap_err(),
+        ["The field site must be a valid UUID."]
+    );
+}
+
+#[test]
+fn ulid_uses_crockford_base32() {
+    assert!(check(json!("01ARZ3NDEKTSV4RRFFQ69G5FAV"), "ulid").is_ok());
+    assert!(check(json!("01ARZ3NDEKTSV4RRFFQ69G5FAI"), "ulid").is_err());
+    assert!(check(json!("81ARZ3NDEKTSV4RRFFQ69G5FAV"), "ulid").is_err());
+}
+
+#[test]
+fn ip_rules_check_the_family() {
+    assert!(check(json!("192.168.0.1"), "ip").is_ok());
+    assert!(check(json!("::1"), "ip").is_ok());
+    assert!(check(json!("192.168.0.1"), "ipv4").is_ok());
+    assert!(check(json!("2001:db8::1"), "ipv6").is_ok());
+
+    assert_eq!(
+        check(json!("::1"), "ipv4").unwrap_err(),
+        ["The field site must be a valid IPv4 address."]
+    );
+    assert_eq!(
+        check(json!("10.0.0.1"), "ipv6").unwrap_err(),
+        ["The field site must be a valid IPv6 address."]
+    );
+    assert_eq!(
+        check(json!("256.0.0.1"), "ip").unwrap_err(),
+        ["The field site must be a valid IP address."]
+    );
+}
+
+#[test]
+fn non_strings_fail() {
+    assert!(check(json!(42), "ip").is_err());
+    assert!(check(json!(["https://example.com"]), "url").is_err());
+}
+
+fn check(value: Value, rules: &'static str) -> Result<(), Vec<String>> {
+    let data = make(json!({ "site": value }));
+
+    Validator::make(data, [("site", rules)].into())
+        .validate()
+        .map_err(|mut e| e.remove("site").unwrap_or_default())
+}
@@ -0,0 +1,4 @@
+pub mod alpha;
+pub mod identifier;
+pub mod pattern;
+pub mod text;
@@ -0,0 +1,89 @@
+use ketzal_validation::{split_rules, Validator};
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the regex and not_regex rules and pattern-aware rule parsing
+
+#[test]
+fn regex_matches_the_pattern() {
+    assert!(check(json!("ABC-123"), r"regex:/^[A-Z]{3}-\d+$/").is_ok());
+    assert_eq!(
+        check(json!("abc-123"), r"regex:/^[A-Z]{3}-\d+$/").unwrap_err(),
+        ["The field code format is invalid."]
+    );
+}
+
+#[test]
+fn regex_applies_flags() {
+    assert!(check(json!("abc-123"), r"regex:/^[A-Z]{3}-\d+$/i").is_ok());
+}
+
+#[test]
+fn regex_keeps_pipes_and_colons_in_the_pattern() {
+    let rules = r"required|regex:/^(red|green):\d+$/|max:12";
+
+    assert!(check(json!("red:10"), rules).is_ok());
+    assert!(check(json!("blue:10"), rules).is_err());
+    assert_eq!(
+        check(json!("green:1234567890"), rules).unwrap_err(),
+        ["The field code may not be greater than 12 characters."]
+    );
+}
+
+#[test]
+fn regex_accepts_other_delimiters() {
+    assert!(check(json!("a/b"), "regex:#^a/b$#").is_ok());
+    assert!(check(json!("a|b"), r"regex:/^a\|b$/|string").is_ok());
+}
+
+#[test]
+fn not_regex_rejects_matches() {
+    assert!(check(json!("hello"), "not_regex:/[<>]/").is_ok());
+    assert_eq!(
+        check(json!("<script>"), "not_regex:/[<>]/").unwrap_err(),
+        ["The field code format is invalid."]
+    );
+}
+
+#[test]
+fn invalid_pattern_fails_validation() {
+    assert!(check(json!("a"), "regex:/[a-/").is_err());
+}
+
+#[test]
+fn invalid_pattern_is_rejected_in_strict_mode() {
+    let data = make(json!({ "code": "a" }));
+
+    let err = Validator::make_strict(data, [("code", "regex:/[a-/")].into()).err().unwrap();
+
+    assert_eq!(err.rule, "regex:/[a-/");
+    assert!(err.reason.starts_with("rule `regex` has an invalid pattern"), "{}", err.reason);
+}
+
+#[test]
+fn unknown_flag_is_rejected_in_strict_mode() {
+    let data = make(json!({ "code": "a" }));
+
+    let err = Validator::make_strict(data, [("code", "regex:/a/q")].into()).err().unwrap();
+
+    assert_eq!(err.reason, "rule `regex` has an invalid pattern: unknown regex flag `q`");
+}
+
+#[test]
+fn split_rules_keeps_patterns_whole() {
+    assert_eq!(split_rules("required|min:3"), ["required", "min:3"]);
+    assert_eq!(
+        split_rules(r"required | regex:/^(a|b)$/i | not_regex:#\#|x# | max:3"),
+        ["required", "regex:/^(a|b)$/i", r"not_regex:#\#|x#", "max:3"]
+    );
+    assert_eq!(split_rules("in:a,b|regex:/a|b"), ["in:a,b", "regex:/a", "b"]);
+}
+
+fn check(value: Value, rules: &'static str) -> Result<(), Vec<String>> {
+    let data = make(json!({ "code": value }));
+
+    Validator::make(data, [("code", rules)].into())
+        .validate()
+        .map_err(|mut e| e.remove("code").unwrap_or_default())
+}
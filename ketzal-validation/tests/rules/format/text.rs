@@ -0,0 +1,66 @@
+use ketzal_validation::Validator;
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the starts_with, ends_with, json, lowercase and uppercase rules
+
+#[test]
+fn starts_with_accepts_any_prefix() {
+    assert!(check(json!("+52 55 1234"), "starts_with:+1,+52").is_ok());
+    assert_eq!(
+        check(json!("0044 20"), "starts_with:+1,+52").unwrap_err(),
+        ["The field value must start with one of the following: +1, +52."]
+    );
+}
+
+#[test]
+fn ends_with_accepts_any_suffix() {
+    assert!(check(json!("example.org"), "ends_with:.com,.org").is_ok());
+    assert_eq!(
+        check(json!("example.net"), "ends_with:.com,.org").unwrap_err(),
+        ["The field value must end with one of the following: .com, .org."]
+    );
+}
+
+#[test]
+fn json_requires_a_json_string() {
+    assert!(check(json!(r#"{"a": [1, 2]}"#), "json").is_ok());
+    assert!(check(json!("42"), "json").is_ok());
+    assert!(check(json!(42), "json").is_err());
+    assert_eq!(
+        check(json!("{a: 1}"), "json").unwrap_err(),
+        ["The field value must be a valid JSON string."]
+    );
+}
+
+#[test]
+fn case_rules_compare_with_unicode_case() {
+    assert!(check(json!("ñandú 2"), "lowercase").is_ok());
+    assert!(check(json!("ÑANDÚ 2"), "uppercase").is_ok());
+    assert_eq!(
+        check(json!("Ñandú"), "lowercase").unwrap_err(),
+        ["The field value must be lowercase."]
+    );
+    assert_eq!(
+        check(json!("Ñandú"), "uppercase").unwrap_err(),
+        ["The field value must be uppercase."]
+    );
+}
+
+#[test]
+fn format_rules_use_custom_messages() {
+    let data = make(json!({ "value": "ABC" }));
+    let mut v = Validator::make(data, [("value", "lowercase")].into());
+    v.set_custom_messages([("value.lowercase", "Use lowercase letters.")].into());
+
+    assert_eq!(v.validate().unwrap_err()["value"], ["Use lowercase letters."]);
+}
+
+fn check(value: Value, rules: &'static str) -> Result<(), Vec<String>> {
+    let data = make(json!({ "value": value }));
+
+    Validator::make(data, [("value", rules)].into())
+        .validate()
+        .map_err(|mut e| e.remove("value").unwrap_or_default())
+}
@@ -1,2 +1,6 @@
 pub mod basic;
+pub mod comparison;
 pub mod conditional;
+pub mod datetime;
+pub mod file;
+pub mod format;
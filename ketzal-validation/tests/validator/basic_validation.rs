@@ -257,28 +257,28 @@ fn custom_attributes_used_in_messages() {
 
 #[test]
 fn nullable_rule_skips_validation() {
-    // Nullable only skips validation when field is MISSING, not when it's null
+    // Nullable skips the other rules when the field is null
     let data = make(json!({
         "nickname": null
     }));
 
     let mut v = Validator::make(data, [("nickname", "nullable|email")].into());
 
-    // null value fails email validation because it's converted to "null" string
-    assert!(v.validate().is_err());
+    assert!(v.validate().is_ok());
 }
 
 #[test]
 fn nullable_with_other_rules() {
-    // Nullable only skips when field is missing
+    // Without nullable, null fails string validation
     let data = make(json!({
         "nickname": null
     }));
 
-    let mut v = Validator::make(data, [("nickname", "nullable|string|min:3")].into());
+    let mut v = Validator::make(data.clone(), [("nickname", "nullable|string|min:3")].into());
+    let mut strict = Validator::make(data, [("nickname", "string|min:3")].into());
 
-    // null fails string validation
-    assert!(v.validate().is_err());
+    assert!(v.validate().is_ok());
+    assert!(strict.validate().is_err());
 }
 
 #[test]
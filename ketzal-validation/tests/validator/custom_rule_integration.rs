@@ -7,8 +7,8 @@ use crate::helpers::make;
 
 #[test]
 fn custom_rule_registration() {
-    // This test would require access to internal registry to add custom rules
-    // For now, we test that the existing rules work correctly together
+    // Registration itself is covered in `registry::runtime_rules`; here the
+    // built-in rules are combined
     let data = make(json!({
         "username": "john_doe",
         "email": "john@example.com",
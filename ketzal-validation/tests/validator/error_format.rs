@@ -0,0 +1,163 @@
+use ketzal_validation::{FieldError, RuleFn, ValidationErrors, Validator};
+use serde_json::{json, Value};
+
+use crate::helpers::make;
+
+/// Tests for the structured error list and its response bodies
+
+#[test]
+fn fields_fail_in_key_order_on_every_run() {
+    let data = make(json!({ "zeta": "", "alpha": "", "mid": "x" }));
+    let rules = [("zeta", "required"), ("mid", "min:3"), ("alpha", "required")];
+
+    for _ in 0..10 {
+        let errors = Validator::make(data.clone(), rules.into()).validate().unwrap_err();
+
+        assert_eq!(errors.keys().collect::<Vec<_>>(), ["alpha", "mid", "zeta"]);
+    }
+}
+
+#[test]
+fn wildcard_fields_fail_in_data_order() {
+    let data = make(json!({ "items": [{ "sku": "" }, { "sku": "" }, { "sku": "" }] }));
+
+    let errors =
+        Validator::make(data, [("items.*.sku", "required")].into()).validate().unwrap_err();
+
+    assert_eq!(errors.keys().collect::<Vec<_>>(), ["items.0.sku", "items.1.sku", "items.2.sku"]);
+}
+
+#[test]
+fn errors_record_rule_and_params() {
+    let data = make(json!({ "name": "ab", "code": "x,y" }));
+    let rules = [("name", "string|min:3"), ("code", "regex:/^[a-z]{1,2},[0-9]$/")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(
+        errors.errors(),
+        [
+            FieldError {
+                field: "code".into(),
+                rule: "regex".into(),
+                params: vec!["/^[a-z]{1,2},[0-9]$/".into()],
+                message: errors.first("code").unwrap().into(),
+            },
+            FieldError {
+                field: "name".into(),
+                rule: "min".into(),
+                params: vec!["3".into()],
+                message: errors.first("name").unwrap().into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn list_params_are_split() {
+    let errors =
+        Validator::make(make(json!({ "role": "root" })), [("role", "in:admin, user")].into())
+            .validate()
+            .unwrap_err();
+
+    assert_eq!(errors.errors()[0].params, ["admin", "user"]);
+}
+
+#[test]
+fn inline_rule_objects_are_reported_as_custom() {
+    let mut v = Validator::make(make(json!({ "n": 3 })), [("n", "integer")].into());
+    v.rule(
+        "n",
+        RuleFn::new("No threes.", |_, value, _, _| value.and_then(Value::as_i64) != Some(3)),
+    );
+
+    let errors = v.validate().unwrap_err();
+
+    assert_eq!(errors.errors()[0].rule, "custom");
+    assert!(errors.errors()[0].params.is_empty());
+}
+
+#[test]
+fn summary_counts_the_other_errors() {
+    let mut errors = ValidationErrors::new();
+    assert_eq!(errors.summary(), "");
+
+    errors.add(error("name", "required", "The field name is required."));
+    assert_eq!(errors.summary(), "The field name is required.");
+
+    errors.add(error("name", "string", "The field name must be a string."));
+    assert_eq!(errors.summary(), "The field name is required. (and 1 more error)");
+
+    errors.add(error("age", "integer", "The field age must be an integer."));
+    assert_eq!(errors.summary(), "The field name is required. (and 2 more errors)");
+}
+
+#[test]
+fn laravel_body_groups_messages_by_field() {
+    let errors: ValidationErrors = [
+        error("name", "required", "Name is required."),
+        error("age", "integer", "Age must be an integer."),
+        error("name", "string", "Name must be a string."),
+    ]
+    .into_iter()
+    .collect();
+
+    let body = errors.to_laravel();
+
+    assert_eq!(
+        body,
+        json!({
+            "message": "Name is required. (and 2 more errors)",
+            "errors": {
+                "name": ["Name is required.", "Name must be a string."],
+                "age": ["Age must be an integer."],
+            },
+        })
+    );
+}
+
+#[test]
+fn problem_body_lists_each_failed_rule() {
+    let errors: ValidationErrors = [error("age", "min", "Age is too small.")].into_iter().collect();
+
+    assert_eq!(
+        errors.to_problem(),
+        json!({
+            "type": "about:blank",
+            "title": "Unprocessable Content",
+            "status": 422,
+            "detail": "Age is too small.",
+            "errors": [{ "field": "age", "rule": "min", "params": [], "message": "Age is too small." }],
+        })
+    );
+}
+
+#[test]
+fn serialized_errors_keep_field_order() {
+    let errors: ValidationErrors =
+        [error("zip", "required", "Zip."), error("city", "required", "City.")]
+            .into_iter()
+            .collect();
+
+    assert_eq!(serde_json::to_string(&errors).unwrap(), r#"{"zip":["Zip."],"city":["City."]}"#);
+}
+
+#[test]
+fn removing_a_field_drops_its_rules() {
+    let mut errors: ValidationErrors =
+        [error("a", "required", "A."), error("b", "required", "B.")].into_iter().collect();
+
+    assert_eq!(errors.remove("a"), Some(vec!["A.".to_string()]));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors.errors().len(), 1);
+    assert_eq!(errors.summary(), "B.");
+}
+
+fn error(field: &str, rule: &str, message: &str) -> FieldError {
+    FieldError {
+        field: field.into(),
+        rule: rule.into(),
+        params: Vec::new(),
+        message: message.into(),
+    }
+}
@@ -1,3 +1,8 @@
 pub mod basic_validation;
 pub mod conditional_validation;
 pub mod custom_rule_integration;
+pub mod error_format;
+pub mod nested_validation;
+pub mod short_circuit;
+pub mod strict_mode;
+pub mod type_casting;
@@ -0,0 +1,136 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for dot-notation and wildcard field keys
+
+#[test]
+fn nested_object_field() {
+    let data = make(json!({ "address": { "zip": "1234" } }));
+    let mut v = Validator::make(data, [("address.zip", "required|min:5")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["address.zip"], ["The field address.zip must be at least 5 characters."]);
+}
+
+#[test]
+fn missing_nested_field_is_required() {
+    let data = make(json!({ "address": {} }));
+    let mut v = Validator::make(data, [("address.zip", "required")].into());
+
+    assert!(v.validate().unwrap_err().contains_key("address.zip"));
+}
+
+#[test]
+fn wildcard_errors_use_concrete_paths() {
+    let data = make(json!({
+        "items": [{ "sku": "A-1" }, { "sku": "B-2" }, { "qty": 3 }]
+    }));
+    let mut v = Validator::make(data, [("items.*.sku", "required|string")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["items.2.sku"], ["The field items.2.sku is required."]);
+}
+
+#[test]
+fn wildcard_over_missing_array_matches_nothing() {
+    let data = make(json!({}));
+    let mut v = Validator::make(data, [("items.*.sku", "required")].into());
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn nested_wildcards() {
+    let data = make(json!({
+        "orders": [
+            { "lines": [{ "qty": 1 }, { "qty": 0 }] },
+            { "lines": [{ "qty": 0 }] }
+        ]
+    }));
+    let mut v = Validator::make(data, [("orders.*.lines.*.qty", "numeric|min:1")].into());
+
+    let errors = v.validate().unwrap_err();
+    let mut fields: Vec<_> = errors.keys().cloned().collect();
+    fields.sort();
+    assert_eq!(fields, ["orders.0.lines.1.qty", "orders.1.lines.0.qty"]);
+}
+
+#[test]
+fn wildcard_messages_and_attributes_apply_to_each_path() {
+    let data = make(json!({ "items": [{ "sku": "" }, { "sku": "" }] }));
+    let mut v = Validator::make(data.clone(), [("items.*.sku", "required")].into());
+    v.set_custom_messages([("items.*.sku.required", "Every item needs a SKU.")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors["items.0.sku"], ["Every item needs a SKU."]);
+    assert_eq!(errors["items.1.sku"], ["Every item needs a SKU."]);
+
+    let mut v = Validator::make(data, [("items.*.sku", "required")].into());
+    v.set_custom_attributes([("items.*.sku", "SKU")].into());
+    assert_eq!(v.validate().unwrap_err()["items.1.sku"], ["The field SKU is required."]);
+}
+
+#[test]
+fn required_if_resolves_wildcards_against_the_same_element() {
+    let data = make(json!({
+        "items": [{ "type": "digital" }, { "type": "physical" }]
+    }));
+    let mut v =
+        Validator::make(data, [("items.*.weight", "required_if:items.*.type,physical")].into());
+
+    let errors = v.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors.contains_key("items.1.weight"));
+}
+
+#[test]
+fn confirmed_on_nested_field() {
+    let data = make(json!({
+        "account": { "password": "secret1", "password_confirmation": "secret2" }
+    }));
+    let mut v = Validator::make(data, [("account.password", "confirmed")].into());
+
+    assert!(v.validate().unwrap_err().contains_key("account.password"));
+}
+
+#[test]
+fn top_level_key_with_dots_wins() {
+    let data = make(json!({ "user.name": "Ada", "user": { "name": "" } }));
+    let mut v = Validator::make(data, [("user.name", "required")].into());
+
+    assert!(v.validate().is_ok());
+    assert_eq!(v.validated_data(), make(json!({ "user.name": "Ada" })));
+}
+
+#[test]
+fn validated_data_keeps_only_validated_paths() {
+    let data = make(json!({
+        "address": { "zip": "12345", "note": "ring twice" },
+        "items": [{ "sku": "A", "price": 1 }, { "sku": "B", "price": 2 }],
+        "extra": true
+    }));
+    let rules =
+        [("address.zip", "required"), ("items", "array|min:1"), ("items.*.sku", "required")];
+    let mut v = Validator::make(data, rules.into());
+
+    assert!(v.validate().is_ok());
+    assert_eq!(
+        v.validated_data(),
+        make(json!({
+            "address": { "zip": "12345" },
+            "items": [{ "sku": "A", "price": 1 }, { "sku": "B", "price": 2 }]
+        }))
+    );
+}
+
+#[test]
+fn validated_data_rebuilds_arrays_from_wildcards() {
+    let data = make(json!({ "items": [{ "sku": "A", "price": 1 }, { "sku": "B", "price": 2 }] }));
+    let mut v = Validator::make(data, [("items.*.sku", "required")].into());
+
+    assert!(v.validate().is_ok());
+    assert_eq!(v.validated_data(), make(json!({ "items": [{ "sku": "A" }, { "sku": "B" }] })));
+}
@@ -0,0 +1,160 @@
+use ketzal_validation::{FormRequest, RuleFn, ValidationRule, Validator};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::helpers::make;
+
+/// Tests for bail, stop_on_first_failure and implicit rules
+
+#[test]
+fn failed_required_stops_the_field() {
+    let mut v = Validator::make(make(json!({})), [("email", "required|email|min:5")].into());
+
+    assert_eq!(v.validate().unwrap_err()["email"], ["The field email is required."]);
+}
+
+#[test]
+fn missing_optional_fields_skip_their_rules() {
+    let data = make(json!({ "website": "", "bio": "   " }));
+    let rules = [("email", "email|min:5"), ("website", "url"), ("bio", "string|min:10")];
+
+    assert!(Validator::make(data, rules.into()).validate().is_ok());
+}
+
+#[test]
+fn implicit_rules_run_on_missing_values() {
+    let data = make(json!({ "role": "admin" }));
+    let rules = [
+        ("terms", "accepted"),
+        ("team", "required_if:role,admin|string"),
+        ("nickname", "nullable|required"),
+    ];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors["team"], ["The field team is required when role is admin."]);
+    assert_eq!(errors["nickname"], ["The field nickname is required."]);
+}
+
+#[test]
+fn null_skips_the_rules_of_nullable_fields() {
+    let data = make(json!({ "age": null, "email": null, "born": null }));
+    let rules =
+        [("age", "nullable|integer"), ("email", "nullable|email|min:3"), ("born", "nullable|date")];
+
+    assert!(Validator::make(data, rules.into()).validate().is_ok());
+}
+
+#[test]
+fn null_is_checked_without_nullable() {
+    let data = make(json!({ "age": null, "born": null }));
+    let rules = [("age", "integer"), ("born", "nullable|required|date")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors["age"], ["The field age must be an integer."]);
+    assert_eq!(errors["born"], ["The field born is required."]);
+}
+
+#[test]
+fn without_bail_every_rule_reports() {
+    let data = make(json!({ "code": "a1" }));
+    let mut v = Validator::make(data, [("code", "alpha|min:5|uppercase")].into());
+
+    assert_eq!(v.validate().unwrap_err()["code"].len(), 3);
+}
+
+#[test]
+fn bail_stops_at_the_first_failure_of_the_field() {
+    let data = make(json!({ "code": "a1", "name": "x" }));
+    let rules = [("code", "bail|alpha|min:5|uppercase"), ("name", "min:3|uppercase")];
+
+    let errors = Validator::make(data, rules.into()).validate().unwrap_err();
+
+    assert_eq!(errors["code"], ["The field code must only contain letters."]);
+    assert_eq!(errors["name"].len(), 2);
+}
+
+#[test]
+fn bail_skips_expensive_rules() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+
+    let mut v = Validator::make(make(json!({ "n": "abc" })), [("n", "bail|numeric")].into());
+    v.rule(
+        "n",
+        RuleFn::new("unused", move |_, _, _, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            true
+        }),
+    );
+
+    assert!(v.validate().is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn stop_on_first_failure_reports_one_error() {
+    let data = make(json!({ "a": "x", "b": "y", "c": "z" }));
+    let rules = [("a", "numeric|min:5"), ("b", "numeric"), ("c", "numeric")];
+    let mut v = Validator::make(data, rules.into());
+    v.set_stop_on_first_failure(true);
+
+    let errors = v.validate().unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors.values().next().unwrap().len(), 1);
+}
+
+#[test]
+fn custom_rules_can_be_implicit() {
+    let mut v = Validator::make(make(json!({})), HashMap::new());
+    v.rule("token", Present);
+    v.rule("other", RuleFn::new("skipped", |_, value, _, _| value.is_some()));
+
+    let errors = v.validate().unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors["token"], ["The field token must be sent."]);
+}
+
+#[test]
+fn form_requests_can_stop_on_first_failure() {
+    let data = make(json!({ "name": "", "email": "nope" }));
+
+    let errors = Signup::validate_data(data).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+}
+
+struct Present;
+
+impl ValidationRule for Present {
+    fn passes(&self, _: &str, value: Option<&Value>, _: &[&str], _: &Validator) -> bool {
+        value.is_some()
+    }
+
+    fn message(&self, field_name: &str, _: &[&str]) -> String {
+        format!("The field {field_name} must be sent.")
+    }
+
+    fn implicit(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Default)]
+struct Signup;
+
+impl FormRequest for Signup {
+    fn rules(&self) -> HashMap<&'static str, &'static str> {
+        [("name", "required"), ("email", "required|email")].into()
+    }
+
+    fn stop_on_first_failure(&self) -> bool {
+        true
+    }
+}
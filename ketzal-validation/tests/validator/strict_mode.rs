@@ -0,0 +1,84 @@
+use ketzal_validation::rules::spec::builtin_param;
+use ketzal_validation::{async_rules_registry, rules_registry, Validator};
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for strict mode and the compile-time rule table
+
+#[test]
+fn strict_mode_accepts_valid_rules() {
+    let data = make(json!({ "name": "Ada", "role": "admin" }));
+    let rules =
+        [("name", "required|string|min:3|max:20"), ("role", "in:admin,user|required_if:name,Ada")];
+
+    let mut v = Validator::make_strict(data, rules.into()).unwrap();
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn strict_mode_rejects_unknown_rule() {
+    let data = make(json!({ "email": "ada@example.com" }));
+
+    let err = Validator::make_strict(data, [("email", "requird|email")].into()).err().unwrap();
+
+    assert_eq!(err.field, "email");
+    assert_eq!(err.rule, "requird");
+    assert_eq!(err.reason, "unknown validation rule `requird`");
+}
+
+#[test]
+fn strict_mode_rejects_non_numeric_size() {
+    let data = make(json!({ "name": "Ada" }));
+
+    let err = Validator::make_strict(data, [("name", "min:abc")].into()).err().unwrap();
+
+    assert_eq!(
+        err.to_string(),
+        "invalid rule `min:abc` for field `name`: rule `min` expects a number, got `abc`"
+    );
+}
+
+#[test]
+fn strict_mode_rejects_missing_and_unexpected_params() {
+    let missing = Validator::make_strict(make(json!({})), [("a", "max")].into()).err().unwrap();
+    let unexpected =
+        Validator::make_strict(make(json!({})), [("a", "email:rfc")].into()).err().unwrap();
+    let field_only =
+        Validator::make_strict(make(json!({})), [("a", "required_if:role")].into()).err().unwrap();
+
+    assert_eq!(missing.reason, "rule `max` requires a parameter");
+    assert_eq!(unexpected.reason, "rule `email` does not take a parameter");
+    assert_eq!(field_only.reason, "rule `required_if` expects a field and a value");
+}
+
+#[test]
+fn strict_mode_rejects_empty_rule() {
+    let err = Validator::make_strict(make(json!({})), [("a", "required||email")].into());
+
+    assert_eq!(err.err().unwrap().reason, "empty rule");
+}
+
+#[test]
+fn lenient_mode_still_skips_unknown_rules() {
+    let mut v = Validator::make(make(json!({ "a": "x" })), [("a", "requird")].into());
+
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn compile_time_table_matches_registry() {
+    let table: &[(&str, &str)] = ketzal_validation::__builtin_rules!();
+
+    for (name, kind) in table {
+        let registered =
+            rules_registry().contains_key(name) || async_rules_registry().contains_key(name);
+        assert!(registered, "`{name}` is not registered");
+        assert_eq!(builtin_param(name).map(|k| k.name()), Some(*kind), "param of `{name}`");
+    }
+
+    for name in rules_registry().keys().chain(async_rules_registry().keys()) {
+        assert!(table.iter().any(|(known, _)| known == name), "`{name}` is not checked");
+    }
+}
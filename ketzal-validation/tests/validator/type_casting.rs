@@ -0,0 +1,77 @@
+use ketzal_validation::Validator;
+use serde_json::json;
+
+use crate::helpers::make;
+
+/// Tests for casting validated form text to JSON types
+
+#[test]
+fn text_is_kept_by_default() {
+    let data = make(json!({ "age": "42" }));
+    let mut v = Validator::make(data, [("age", "integer")].into());
+
+    v.validate().unwrap();
+
+    assert_eq!(v.validated_data()["age"], "42");
+}
+
+#[test]
+fn form_text_reads_like_json() {
+    let form = make(json!({
+        "age": "42",
+        "price": "19.90",
+        "ratio": "0.5",
+        "active": "1",
+        "admin": "false",
+        "name": "Ada",
+        "code": "007"
+    }));
+    let rules = [
+        ("age", "required|integer"),
+        ("price", "decimal:2"),
+        ("ratio", "numeric"),
+        ("active", "boolean"),
+        ("admin", "boolean"),
+        ("name", "string"),
+        ("code", "string"),
+    ];
+    let mut v = Validator::make(form, rules.into());
+    v.set_cast_types(true);
+
+    v.validate().unwrap();
+    let validated = v.validated_data();
+
+    assert_eq!(validated["age"], json!(42));
+    assert_eq!(validated["price"], json!(19.9));
+    assert_eq!(validated["ratio"], json!(0.5));
+    assert_eq!(validated["active"], json!(true));
+    assert_eq!(validated["admin"], json!(false));
+    assert_eq!(validated["name"], json!("Ada"));
+    assert_eq!(validated["code"], json!("007"));
+}
+
+#[test]
+fn json_input_is_unchanged() {
+    let data = make(json!({ "age": 42, "active": true, "flag": 1 }));
+    let rules = [("age", "integer"), ("active", "boolean"), ("flag", "boolean")];
+    let mut v = Validator::make(data, rules.into());
+    v.set_cast_types(true);
+
+    v.validate().unwrap();
+    let validated = v.validated_data();
+
+    assert_eq!(validated["age"], json!(42));
+    assert_eq!(validated["active"], json!(true));
+    assert_eq!(validated["flag"], json!(true));
+}
+
+#[test]
+fn casts_nested_fields() {
+    let data = make(json!({ "items": [{ "qty": "2" }, { "qty": "5" }] }));
+    let mut v = Validator::make(data, [("items.*.qty", "integer")].into());
+    v.set_cast_types(true);
+
+    v.validate().unwrap();
+
+    assert_eq!(v.validated_data()["items"], json!([{ "qty": 2 }, { "qty": 5 }]));
+}
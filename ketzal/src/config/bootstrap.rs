@@ -1,5 +1,7 @@
+use crate::routes::register_web_middleware;
 use crate::server::http_server::Server;
 use ketzal_http::config::ServerConfig;
+use ketzal_http::middleware::Middleware;
 
 pub struct Bootstrap {
     server_config: ServerConfig,
@@ -15,6 +17,12 @@ impl Bootstrap {
         self
     }
 
+    /// Adds a middleware to the web stack, e.g. sessions.
+    pub fn with_middleware(self, middleware: impl Middleware) -> Self {
+        register_web_middleware(middleware);
+        self
+    }
+
     pub async fn create(self) -> std::io::Result<()> {
         let server = Server::web(self.server_config).await?;
         server.run().await
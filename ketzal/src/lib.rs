@@ -2,7 +2,10 @@ pub mod config;
 pub mod routes;
 pub mod server;
 pub use ketzal_http::{Request, Response};
-pub use ketzal_router::{Route, Router};
+pub use ketzal_router::{Form, Json, Route, RouteGroup, Router, Valid};
+
+#[doc(hidden)]
+pub use ketzal_validation as __validation;
 
 // macro validator
 #[macro_export]
@@ -56,13 +59,25 @@ macro_rules! form_request {
 }
 
 // validate macros
+//
+// Rule literals are checked at compile time: `"min:abc"` or `"requird"` fail
+// the build instead of being skipped at runtime. Custom rules whose names are
+// close to built-in ones are listed with `custom_rules = [...]`:
+//
+//     validate_json!(req, custom_rules = ["zip"] => { "code" => "required|zip" })
 
 /// application/json
 #[macro_export]
 macro_rules! validate_json {
     ($req:expr => {
         $($field:literal => $rule:literal),* $(,)?
+    }) => {
+        $crate::validate_json!($req, custom_rules = [] => { $($field => $rule),* })
+    };
+    ($req:expr, custom_rules = [$($custom:literal),* $(,)?] => {
+        $($field:literal => $rule:literal),* $(,)?
     }) => {{
+        $crate::__validation::check_rules!(custom_rules = [$($custom),*]; $($rule),*);
         let __req = &$req;
 
         match __req.validate_json([
@@ -80,7 +95,13 @@ macro_rules! validate_json {
 macro_rules! validate_form {
     ($req:expr => {
         $($field:literal => $rule:literal),* $(,)?
+    }) => {
+        $crate::validate_form!($req, custom_rules = [] => { $($field => $rule),* })
+    };
+    ($req:expr, custom_rules = [$($custom:literal),* $(,)?] => {
+        $($field:literal => $rule:literal),* $(,)?
     }) => {{
+        $crate::__validation::check_rules!(custom_rules = [$($custom),*]; $($rule),*);
         let __req = &$req;
 
         match __req.validate_form([
@@ -93,6 +114,30 @@ macro_rules! validate_form {
         }
     }};
 }
+// multipart/form-data
+#[macro_export]
+macro_rules! validate_multipart {
+    ($req:expr => {
+        $($field:literal => $rule:literal),* $(,)?
+    }) => {
+        $crate::validate_multipart!($req, custom_rules = [] => { $($field => $rule),* })
+    };
+    ($req:expr, custom_rules = [$($custom:literal),* $(,)?] => {
+        $($field:literal => $rule:literal),* $(,)?
+    }) => {{
+        $crate::__validation::check_rules!(custom_rules = [$($custom),*]; $($rule),*);
+        let __req = &$req;
+
+        match __req.validate_multipart([
+            $(
+                ($field, $rule),
+            )*
+        ]) {
+            ::std::ops::ControlFlow::Continue(val) => val,
+            ::std::ops::ControlFlow::Break(resp) => return resp,
+        }
+    }};
+}
 
 #[macro_export]
 macro_rules! routes_web {
@@ -1,3 +1,6 @@
 pub mod registry;
 
-pub use registry::{get_api_router, get_web_router, register_api, register_web};
+pub use registry::{
+    get_api_router, get_web_router, register_api, register_api_group, register_api_middleware,
+    register_web, register_web_group, register_web_middleware,
+};
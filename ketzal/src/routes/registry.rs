@@ -1,9 +1,15 @@
-use crate::{Route, Router};
+use crate::{Route, RouteGroup, Router};
+use ketzal_http::middleware::Middleware;
 use std::sync::{Arc, OnceLock, RwLock};
 
+type Stack = RwLock<Vec<Arc<dyn Middleware>>>;
+
 static ROUTES_WEB: OnceLock<RwLock<Vec<Route>>> = OnceLock::new();
 static ROUTES_API: OnceLock<RwLock<Vec<Route>>> = OnceLock::new();
 
+static MIDDLEWARE_WEB: OnceLock<Stack> = OnceLock::new();
+static MIDDLEWARE_API: OnceLock<Stack> = OnceLock::new();
+
 static ROUTER_WEB: OnceLock<RwLock<Option<Arc<Router>>>> = OnceLock::new();
 static ROUTER_API: OnceLock<RwLock<Option<Arc<Router>>>> = OnceLock::new();
 
@@ -15,6 +21,14 @@ fn api_routes() -> &'static RwLock<Vec<Route>> {
     ROUTES_API.get_or_init(|| RwLock::new(Vec::new()))
 }
 
+fn web_middleware() -> &'static Stack {
+    MIDDLEWARE_WEB.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+fn api_middleware() -> &'static Stack {
+    MIDDLEWARE_API.get_or_init(|| RwLock::new(Vec::new()))
+}
+
 fn web_cache() -> &'static RwLock<Option<Arc<Router>>> {
     ROUTER_WEB.get_or_init(|| RwLock::new(None))
 }
@@ -33,13 +47,35 @@ pub fn register_api(route: Route) {
     *api_cache().write().unwrap() = None;
 }
 
+pub fn register_web_group(group: RouteGroup) {
+    web_routes().write().unwrap().extend(group.into_routes());
+    *web_cache().write().unwrap() = None;
+}
+
+pub fn register_api_group(group: RouteGroup) {
+    api_routes().write().unwrap().extend(group.into_routes());
+    *api_cache().write().unwrap() = None;
+}
+
+/// Adds a middleware to the web stack. The first registered is outermost.
+pub fn register_web_middleware(middleware: impl Middleware) {
+    web_middleware().write().unwrap().push(Arc::new(middleware));
+    *web_cache().write().unwrap() = None;
+}
+
+/// Adds a middleware to the API stack. The first registered is outermost.
+pub fn register_api_middleware(middleware: impl Middleware) {
+    api_middleware().write().unwrap().push(Arc::new(middleware));
+    *api_cache().write().unwrap() = None;
+}
+
 pub fn get_web_router() -> Arc<Router> {
     if let Some(router) = web_cache().read().unwrap().as_ref() {
         return router.clone();
     }
     let mut cache = web_cache().write().unwrap();
     if cache.is_none() {
-        *cache = Some(Arc::new(build(web_routes())));
+        *cache = Some(Arc::new(build(web_routes(), web_middleware())));
     }
     cache.as_ref().unwrap().clone()
 }
@@ -50,15 +86,18 @@ pub fn get_api_router() -> Arc<Router> {
     }
     let mut cache = api_cache().write().unwrap();
     if cache.is_none() {
-        *cache = Some(Arc::new(build(api_routes())));
+        *cache = Some(Arc::new(build(api_routes(), api_middleware())));
     }
     cache.as_ref().unwrap().clone()
 }
 
-fn build(routes: &RwLock<Vec<Route>>) -> Router {
+fn build(routes: &RwLock<Vec<Route>>, middleware: &Stack) -> Router {
     let mut router = Router::new();
     for route in routes.read().unwrap().iter() {
         router.register(route.clone());
     }
+    for layer in middleware.read().unwrap().iter() {
+        router.layer(layer.clone());
+    }
     router
 }
@@ -1,13 +1,15 @@
 use crate::routes::registry;
-use ketzal_http::protocol::h1;
-use ketzal_http::Response;
+use ketzal_http::protocol::{h1, proxy};
 use std::io;
+use std::net::SocketAddr;
 use tokio::io::AsyncWriteExt;
 use tokio::net::TcpStream;
 
 pub struct Connection {
     stream: TcpStream,
+    peer: SocketAddr,
     kind: RouterKind,
+    proxy_protocol: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -17,29 +19,43 @@ pub enum RouterKind {
 }
 
 impl Connection {
-    pub fn new(stream: TcpStream, kind: RouterKind) -> Self {
-        Self { stream, kind }
+    pub fn new(stream: TcpStream, peer: SocketAddr, kind: RouterKind) -> Self {
+        Self { stream, peer, kind, proxy_protocol: false }
+    }
+
+    /// Reads a PROXY protocol header before the request and reports the
+    /// client it announces as the peer.
+    pub fn with_proxy_protocol(mut self, enabled: bool) -> Self {
+        self.proxy_protocol = enabled;
+        self
     }
 
     pub async fn handle(&mut self) -> io::Result<()> {
-        let request = match h1::decode(&mut self.stream).await? {
+        if self.proxy_protocol {
+            let header = proxy::read_header(&mut self.stream).await?;
+            if let Some(source) = header.source {
+                self.peer = source;
+            }
+        }
+
+        let mut request = match h1::decode(&mut self.stream).await? {
             Some(req) => req,
             None => return Ok(()),
         };
+        request.remote_addr = Some(self.peer);
 
         let router = match self.kind {
             RouterKind::Web => registry::get_web_router(),
             RouterKind::Api => registry::get_api_router(),
         };
 
-        let response = match router.handle(&request.method.clone(), &request.path.clone(), request)
-        {
-            Some(future) => future.await,
-            None => Response::not_found(),
-        };
+        let response = router.dispatch(request).await;
 
         let bytes = h1::encode(&response);
         self.stream.write_all(&bytes).await?;
+        if let Some(file) = &response.file {
+            file.write_to(&mut self.stream).await?;
+        }
         self.stream.flush().await?;
 
         Ok(())
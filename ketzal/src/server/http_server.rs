@@ -13,6 +13,7 @@ impl Server {
     pub async fn new(config: ServerConfig, kind: RouterKind) -> io::Result<Self> {
         let addr = config.socket_addr();
         let listener = Listener::bind(&addr.to_string()).await?;
+        config.error_format.set_global();
         Ok(Self { config, listener, kind })
     }
 
@@ -28,11 +29,13 @@ impl Server {
         println!("🚀 Server running on {}", self.config.socket_addr());
 
         let kind = self.kind;
+        let proxy_protocol = self.config.proxy_protocol;
         loop {
-            let stream = self.listener.accept().await?;
+            let (stream, peer) = self.listener.accept().await?;
 
             tokio::spawn(async move {
-                let mut conn = Connection::new(stream, kind);
+                let mut conn =
+                    Connection::new(stream, peer, kind).with_proxy_protocol(proxy_protocol);
                 if let Err(e) = conn.handle().await {
                     eprintln!("❌ connection error: {e}");
                 }
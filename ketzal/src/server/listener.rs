@@ -1,4 +1,5 @@
 use std::io;
+use std::net::SocketAddr;
 use tokio::net::{TcpListener, TcpStream};
 
 pub struct Listener {
@@ -11,8 +12,7 @@ impl Listener {
         Ok(Self { inner })
     }
 
-    pub async fn accept(&self) -> io::Result<TcpStream> {
-        let (stream, _) = self.inner.accept().await?;
-        Ok(stream)
+    pub async fn accept(&self) -> io::Result<(TcpStream, SocketAddr)> {
+        self.inner.accept().await
     }
 }